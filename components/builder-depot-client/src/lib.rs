@@ -757,6 +757,42 @@ impl Client {
         }
     }
 
+    /// Returns a page of the releases of a package in a channel, starting from the given offset.
+    /// The package identifier must not be fully qualified.
+    ///
+    /// # Failures
+    ///
+    /// * Remote depot unavailable
+    pub fn list_channel_packages<I>(
+        &self,
+        package: &I,
+        channel: &str,
+        range: isize,
+        token: Option<&str>,
+    ) -> Result<PackageResults<hab_core::package::PackageIdent>>
+    where
+        I: Identifiable,
+    {
+        let path = channel_package_path(channel, package);
+        let range = range.to_string();
+        let mut res = self.maybe_add_authz(
+            self.0.get_with_custom_url(&path, |url| {
+                url.query_pairs_mut().append_pair("range", &range);
+            }),
+            token,
+        ).send()?;
+        match res.status {
+            StatusCode::Ok |
+            StatusCode::PartialContent => {
+                let mut encoded = String::new();
+                res.read_to_string(&mut encoded)?;
+                let package_results = serde_json::from_str(&encoded)?;
+                Ok(package_results)
+            }
+            _ => Err(err_from_response(res)),
+        }
+    }
+
     fn maybe_add_authz<'a>(
         &'a self,
         rb: RequestBuilder<'a>,
@@ -0,0 +1,450 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Schemas which a package can ship to describe the configuration of its service.
+//!
+//! A schema lives next to a package's `default.toml` in a `config_schema.toml` file. Every key of
+//! the configuration is described in a `keys` table, with its `type`, whether it is `required`,
+//! and optionally the values it is `allowed` to take. Keys of type `array` may give the type of
+//! their `items`, and keys of type `table` describe their own `keys` in turn. Keys which are not
+//! described are accepted, unless the enclosing table sets `additional_keys = false`.
+//!
+//! ```toml
+//! additional_keys = false
+//!
+//! [keys.port]
+//! type = "integer"
+//! required = true
+//!
+//! [keys.log_level]
+//! type = "string"
+//! allowed = ["debug", "info", "warn", "error"]
+//!
+//! [keys.tls]
+//! type = "table"
+//!
+//! [keys.tls.keys.ciphers]
+//! type = "array"
+//! items = "string"
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::result;
+
+use toml;
+
+use error::{Error, Result};
+
+pub const CONFIG_SCHEMA_FILENAME: &'static str = "config_schema.toml";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigSchema(TableSchema);
+
+impl ConfigSchema {
+    /// Load the schema shipped in the given package directory, if there is one.
+    pub fn load<P: AsRef<Path>>(dir: P) -> Result<Option<Self>> {
+        let path = dir.as_ref().join(CONFIG_SCHEMA_FILENAME);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        Self::from_file(path).map(Some)
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let mut content = String::new();
+        File::open(path.as_ref())?.read_to_string(&mut content)?;
+        Self::from_str(&content)
+    }
+
+    pub fn from_str(content: &str) -> Result<Self> {
+        let table: toml::value::Table = toml::de::from_str(content).map_err(|e| {
+            Error::ConfigSchemaInvalid(e.to_string())
+        })?;
+        let schema = TableSchema::parse("", &table).map_err(Error::ConfigSchemaInvalid)?;
+        Ok(ConfigSchema(schema))
+    }
+
+    /// Validate a configuration against the schema.
+    ///
+    /// A partial configuration, such as one which is merged over a package's defaults, is not
+    /// required to hold every required key.
+    pub fn validate(&self, config: &toml::value::Table, partial: bool) -> Result<()> {
+        let mut violations = Vec::new();
+        self.0.check("", config, partial, &mut violations);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::ConfigInvalid(violations))
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct TableSchema {
+    keys: BTreeMap<String, KeySchema>,
+    additional_keys: bool,
+}
+
+impl TableSchema {
+    fn parse(path: &str, table: &toml::value::Table) -> result::Result<Self, String> {
+        let mut schema = TableSchema {
+            keys: BTreeMap::new(),
+            additional_keys: true,
+        };
+        for (field, value) in table.iter() {
+            match field.as_ref() {
+                "keys" => {
+                    let keys = value.as_table().ok_or(
+                        format!("`{}` must be a table", join(path, "keys")),
+                    )?;
+                    for (name, key) in keys.iter() {
+                        let key_path = join(path, &format!("keys.{}", name));
+                        let key = key.as_table().ok_or(
+                            format!("`{}` must be a table", key_path),
+                        )?;
+                        schema.keys.insert(
+                            name.clone(),
+                            KeySchema::parse(&key_path, key)?,
+                        );
+                    }
+                }
+                "additional_keys" => {
+                    schema.additional_keys = value.as_bool().ok_or(format!(
+                        "`{}` must be a boolean",
+                        join(path, "additional_keys")
+                    ))?;
+                }
+                _ => return Err(format!("Unknown field `{}`", join(path, field))),
+            }
+        }
+        Ok(schema)
+    }
+
+    fn check(
+        &self,
+        path: &str,
+        table: &toml::value::Table,
+        partial: bool,
+        violations: &mut Vec<String>,
+    ) {
+        for (name, key) in self.keys.iter() {
+            let key_path = join(path, name);
+            match table.get(name) {
+                Some(value) => key.check(&key_path, value, partial, violations),
+                None if key.required && !partial => {
+                    violations.push(format!("Missing required key `{}`", key_path))
+                }
+                None => (),
+            }
+        }
+        if !self.additional_keys {
+            for name in table.keys().filter(|name| !self.keys.contains_key(*name)) {
+                violations.push(format!("Unknown key `{}`", join(path, name)));
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct KeySchema {
+    value_type: ValueType,
+    required: bool,
+    allowed: Vec<toml::Value>,
+    items: Option<ValueType>,
+    table: Option<TableSchema>,
+}
+
+impl KeySchema {
+    fn parse(path: &str, table: &toml::value::Table) -> result::Result<Self, String> {
+        let value_type = match table.get("type") {
+            Some(value) => ValueType::parse(&join(path, "type"), value)?,
+            None => return Err(format!("`{}` must have a type", path)),
+        };
+        let mut key = KeySchema {
+            value_type: value_type,
+            required: false,
+            allowed: Vec::new(),
+            items: None,
+            table: None,
+        };
+        let mut nested = toml::value::Table::new();
+        for (field, value) in table.iter() {
+            let field_path = join(path, field);
+            match field.as_ref() {
+                "type" => (),
+                "required" => {
+                    key.required = value.as_bool().ok_or(
+                        format!("`{}` must be a boolean", field_path),
+                    )?;
+                }
+                "allowed" => {
+                    let allowed = value.as_array().ok_or(
+                        format!("`{}` must be an array", field_path),
+                    )?;
+                    if let Some(v) = allowed.iter().find(|v| !value_type.matches(v)) {
+                        return Err(format!(
+                            "`{}` holds {} which is not of type {}",
+                            field_path,
+                            v,
+                            value_type
+                        ));
+                    }
+                    key.allowed = allowed.clone();
+                }
+                "items" if value_type == ValueType::Array => {
+                    key.items = Some(ValueType::parse(&field_path, value)?);
+                }
+                "keys" | "additional_keys" if value_type == ValueType::Table => {
+                    nested.insert(field.clone(), value.clone());
+                }
+                _ => return Err(format!("Unknown field `{}`", field_path)),
+            }
+        }
+        if value_type == ValueType::Table {
+            key.table = Some(TableSchema::parse(path, &nested)?);
+        }
+        Ok(key)
+    }
+
+    fn check(&self, path: &str, value: &toml::Value, partial: bool, violations: &mut Vec<String>) {
+        if !self.value_type.matches(value) {
+            violations.push(format!(
+                "`{}` must be of type {}, not {}",
+                path,
+                self.value_type,
+                value.type_str()
+            ));
+            return;
+        }
+        if !self.allowed.is_empty() && !self.allowed.contains(value) {
+            let allowed: Vec<String> = self.allowed.iter().map(|v| v.to_string()).collect();
+            violations.push(format!(
+                "`{}` must be one of {}, not {}",
+                path,
+                allowed.join(", "),
+                value
+            ));
+        }
+        match *value {
+            toml::Value::Array(ref items) => {
+                if let Some(items_type) = self.items {
+                    for (i, item) in items.iter().enumerate() {
+                        if !items_type.matches(item) {
+                            violations.push(format!(
+                                "`{}[{}]` must be of type {}, not {}",
+                                path,
+                                i,
+                                items_type,
+                                item.type_str()
+                            ));
+                        }
+                    }
+                }
+            }
+            toml::Value::Table(ref table) => {
+                if let Some(ref schema) = self.table {
+                    schema.check(path, table, partial, violations);
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ValueType {
+    Any,
+    Array,
+    Boolean,
+    Datetime,
+    Float,
+    Integer,
+    String,
+    Table,
+}
+
+impl ValueType {
+    fn parse(path: &str, value: &toml::Value) -> result::Result<Self, String> {
+        match value.as_str() {
+            Some("any") => Ok(ValueType::Any),
+            Some("array") => Ok(ValueType::Array),
+            Some("boolean") => Ok(ValueType::Boolean),
+            Some("datetime") => Ok(ValueType::Datetime),
+            Some("float") => Ok(ValueType::Float),
+            Some("integer") => Ok(ValueType::Integer),
+            Some("string") => Ok(ValueType::String),
+            Some("table") => Ok(ValueType::Table),
+            _ => Err(format!(
+                "`{}` must be one of any, array, boolean, datetime, float, integer, string \
+                 or table",
+                path
+            )),
+        }
+    }
+
+    fn matches(&self, value: &toml::Value) -> bool {
+        match (*self, value) {
+            (ValueType::Any, _) => true,
+            (ValueType::Array, &toml::Value::Array(_)) => true,
+            (ValueType::Boolean, &toml::Value::Boolean(_)) => true,
+            (ValueType::Datetime, &toml::Value::Datetime(_)) => true,
+            // Whole numbers are fine wherever a float is expected
+            (ValueType::Float, &toml::Value::Float(_)) |
+            (ValueType::Float, &toml::Value::Integer(_)) => true,
+            (ValueType::Integer, &toml::Value::Integer(_)) => true,
+            (ValueType::String, &toml::Value::String(_)) => true,
+            (ValueType::Table, &toml::Value::Table(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            ValueType::Any => "value",
+            ValueType::Array => "array",
+            ValueType::Boolean => "boolean",
+            ValueType::Datetime => "datetime",
+            ValueType::Float => "float",
+            ValueType::Integer => "integer",
+            ValueType::String => "string",
+            ValueType::Table => "table",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+fn join(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", path, name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SCHEMA: &'static str = r#"
+        additional_keys = false
+
+        [keys.port]
+        type = "integer"
+        required = true
+
+        [keys.ratio]
+        type = "float"
+
+        [keys.log_level]
+        type = "string"
+        allowed = ["debug", "info"]
+
+        [keys.tls]
+        type = "table"
+
+        [keys.tls.keys.ciphers]
+        type = "array"
+        items = "string"
+
+        [keys.tls.keys.cert]
+        type = "string"
+        required = true
+        "#;
+
+    fn config(content: &str) -> toml::value::Table {
+        toml::de::from_str(content).unwrap()
+    }
+
+    fn violations(content: &str, partial: bool) -> Vec<String> {
+        let schema = ConfigSchema::from_str(SCHEMA).unwrap();
+        match schema.validate(&config(content), partial) {
+            Ok(()) => vec![],
+            Err(Error::ConfigInvalid(violations)) => violations,
+            Err(e) => panic!("Unexpected error returned: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn valid_config() {
+        let content = r#"
+            port = 8080
+            ratio = 1
+            log_level = "info"
+            [tls]
+            cert = "/hab/svc/web/files/cert.pem"
+            ciphers = ["AES256"]
+            "#;
+        assert!(violations(content, false).is_empty());
+    }
+
+    #[test]
+    fn invalid_config() {
+        let content = r#"
+            port = "8080"
+            log_level = "loud"
+            prot = 80
+            [tls]
+            ciphers = ["AES256", 3]
+            "#;
+        assert_eq!(
+            violations(content, false),
+            vec![
+                "`log_level` must be one of \"debug\", \"info\", not \"loud\"",
+                "`port` must be of type integer, not string",
+                "Missing required key `tls.cert`",
+                "`tls.ciphers[1]` must be of type string, not integer",
+                "Unknown key `prot`",
+            ]
+        );
+    }
+
+    #[test]
+    fn partial_config() {
+        assert!(violations(r#"log_level = "debug""#, true).is_empty());
+        assert_eq!(
+            violations(r#"log_level = "debug""#, false),
+            vec!["Missing required key `port`"]
+        );
+        assert_eq!(
+            violations("ratio = true", true),
+            vec!["`ratio` must be of type float, not boolean"]
+        );
+    }
+
+    #[test]
+    fn invalid_schema() {
+        for content in &[
+            "[keys.port]\nrequired = true",
+            "[keys.port]\ntype = \"number\"",
+            "[keys.port]\ntype = \"integer\"\nallowed = [80, \"443\"]",
+            "[keys.port]\ntype = \"integer\"\nitems = \"string\"",
+            "[keys.port]\ntype = \"integer\"\nrequird = true",
+            "additional_keys = \"no\"",
+            "keys = 3",
+        ]
+        {
+            match ConfigSchema::from_str(content) {
+                Err(Error::ConfigSchemaInvalid(_)) => (),
+                Err(e) => panic!("Unexpected error returned: {:?}", e),
+                Ok(_) => panic!("'{}' should fail to parse", content),
+            }
+        }
+    }
+}
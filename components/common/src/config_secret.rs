@@ -0,0 +1,232 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Secret values in service configuration.
+//!
+//! A secret is a string value encrypted by a user for a service group with
+//! `BoxKeyPair::encrypt`, stored as `SECRET_PREFIX` followed by the base64-encoded payload:
+//!
+//! ```toml
+//! [db]
+//! user = "app"
+//! password = "hab-secret:Qk9YLTEKYm9iLTIwMTcw..."
+//! ```
+//!
+//! Secrets stay encrypted in `user.toml` and in gossiped configuration. The Supervisor decrypts
+//! them with the service group's key only to render templates and hooks, and replaces them with
+//! `REDACTED` anywhere else the configuration is shown.
+
+use std::path::Path;
+
+use base64;
+use hcore::crypto::BoxKeyPair;
+use toml;
+
+use error::{Error, Result};
+
+/// Prefix marking a configuration value as an encrypted secret.
+pub const SECRET_PREFIX: &'static str = "hab-secret:";
+
+/// Value shown in place of a secret outside of rendered templates and hooks.
+pub const REDACTED: &'static str = "<redacted>";
+
+/// Encrypt a value from the given user for the given service, returning it in the form expected
+/// in configuration.
+pub fn encrypt(value: &str, user_pair: &BoxKeyPair, service_pair: &BoxKeyPair) -> Result<String> {
+    let payload = user_pair.encrypt(value.as_bytes(), Some(service_pair))?;
+    Ok(format!("{}{}", SECRET_PREFIX, base64::encode(&payload)))
+}
+
+/// Decrypt a secret with the keys found in the given key cache.
+pub fn decrypt<P>(secret: &str, cache_key_path: P) -> Result<String>
+where
+    P: AsRef<Path>,
+{
+    if !secret.starts_with(SECRET_PREFIX) {
+        return Err(Error::ConfigSecretInvalid(
+            format!("value doesn't start with {}", SECRET_PREFIX),
+        ));
+    }
+    let payload = base64::decode(&secret[SECRET_PREFIX.len()..]).map_err(|e| {
+        Error::ConfigSecretInvalid(format!("can't decode payload, {}", e))
+    })?;
+    let value = BoxKeyPair::decrypt(&payload, cache_key_path.as_ref())?;
+    Ok(String::from_utf8(value)?)
+}
+
+/// Whether the value is an encrypted secret.
+pub fn is_secret(value: &toml::Value) -> bool {
+    value.as_str().map_or(false, |s| s.starts_with(SECRET_PREFIX))
+}
+
+/// Whether the value is an encrypted secret or holds one in its tables and arrays.
+pub fn has_secret(value: &toml::Value) -> bool {
+    any_value(value, &is_secret)
+}
+
+/// Whether the value is a redacted secret or holds one in its tables and arrays. Configuration
+/// read back from the census can't be gossiped again when this is true.
+pub fn has_redacted(value: &toml::Value) -> bool {
+    any_value(value, &|v| v.as_str() == Some(REDACTED))
+}
+
+/// Decrypt every secret in the configuration, including those nested in tables and arrays.
+pub fn decrypt_secrets<P>(config: &mut toml::value::Table, cache_key_path: P) -> Result<()>
+where
+    P: AsRef<Path>,
+{
+    for value in config.values_mut() {
+        decrypt_value(value, cache_key_path.as_ref())?;
+    }
+    Ok(())
+}
+
+/// Replace every secret in the configuration with `REDACTED`, including those nested in tables
+/// and arrays.
+pub fn redact_secrets(config: &mut toml::value::Table) {
+    for value in config.values_mut() {
+        redact_value(value);
+    }
+}
+
+/// Replace every secret in the value with `REDACTED`, including those nested in tables and
+/// arrays.
+pub fn redact_value(value: &mut toml::Value) {
+    if is_secret(value) {
+        *value = toml::Value::String(REDACTED.to_string());
+        return;
+    }
+    match *value {
+        toml::Value::Array(ref mut items) => {
+            for item in items.iter_mut() {
+                redact_value(item);
+            }
+        }
+        toml::Value::Table(ref mut table) => redact_secrets(table),
+        _ => (),
+    }
+}
+
+fn any_value<F>(value: &toml::Value, matches: &F) -> bool
+where
+    F: Fn(&toml::Value) -> bool,
+{
+    if matches(value) {
+        return true;
+    }
+    match *value {
+        toml::Value::Array(ref items) => items.iter().any(|item| any_value(item, matches)),
+        toml::Value::Table(ref table) => table.values().any(|v| any_value(v, matches)),
+        _ => false,
+    }
+}
+
+fn decrypt_value(value: &mut toml::Value, cache_key_path: &Path) -> Result<()> {
+    if is_secret(value) {
+        let decrypted = decrypt(value.as_str().unwrap(), cache_key_path)?;
+        *value = toml::Value::String(decrypted);
+        return Ok(());
+    }
+    match *value {
+        toml::Value::Array(ref mut items) => {
+            for item in items.iter_mut() {
+                decrypt_value(item, cache_key_path)?;
+            }
+        }
+        toml::Value::Table(ref mut table) => decrypt_secrets(table, cache_key_path)?,
+        _ => (),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use tempdir::TempDir;
+
+    use super::*;
+
+    fn key_pairs(cache: &Path) -> (BoxKeyPair, BoxKeyPair) {
+        let user_pair = BoxKeyPair::generate_pair_for_user("alice").unwrap();
+        user_pair.to_pair_files(cache).unwrap();
+        let service_pair = BoxKeyPair::generate_pair_for_service("acme", "redis.default")
+            .unwrap();
+        service_pair.to_pair_files(cache).unwrap();
+        (user_pair, service_pair)
+    }
+
+    fn config_from_str(content: &str) -> toml::value::Table {
+        toml::from_str(content).unwrap()
+    }
+
+    #[test]
+    fn encrypt_and_decrypt() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let (user_pair, service_pair) = key_pairs(cache.path());
+        let secret = encrypt("hunter2", &user_pair, &service_pair).unwrap();
+        assert!(secret.starts_with(SECRET_PREFIX));
+        assert!(!secret.contains("hunter2"));
+        assert!(is_secret(&toml::Value::String(secret.clone())));
+        assert_eq!(decrypt(&secret, cache.path()).unwrap(), "hunter2");
+    }
+
+    #[test]
+    fn decrypt_invalid_secret() {
+        let cache = TempDir::new("key_cache").unwrap();
+        match decrypt("hunter2", cache.path()) {
+            Err(Error::ConfigSecretInvalid(_)) => (),
+            r => panic!("Unexpected result: {:?}", r),
+        }
+        match decrypt("hab-secret:!!!", cache.path()) {
+            Err(Error::ConfigSecretInvalid(_)) => (),
+            r => panic!("Unexpected result: {:?}", r),
+        }
+    }
+
+    #[test]
+    fn decrypt_and_redact_nested_secrets() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let (user_pair, service_pair) = key_pairs(cache.path());
+        let password = encrypt("hunter2", &user_pair, &service_pair).unwrap();
+        let token = encrypt("s3cr3t", &user_pair, &service_pair).unwrap();
+        let content = format!(
+            "port = 6379\n[db]\nuser = \"app\"\npassword = \"{}\"\ntokens = [\"{}\", \"plain\"]\n",
+            password,
+            token
+        );
+
+        let mut decrypted = config_from_str(&content);
+        decrypt_secrets(&mut decrypted, cache.path()).unwrap();
+        assert_eq!(
+            decrypted,
+            config_from_str(
+                "port = 6379\n[db]\nuser = \"app\"\npassword = \"hunter2\"\n\
+                 tokens = [\"s3cr3t\", \"plain\"]\n",
+            )
+        );
+
+        let mut redacted = config_from_str(&content);
+        assert!(has_secret(&toml::Value::Table(redacted.clone())));
+        assert!(!has_redacted(&toml::Value::Table(redacted.clone())));
+        redact_secrets(&mut redacted);
+        assert!(!has_secret(&toml::Value::Table(redacted.clone())));
+        assert!(has_redacted(&toml::Value::Table(redacted.clone())));
+        assert_eq!(
+            redacted,
+            config_from_str(
+                "port = 6379\n[db]\nuser = \"app\"\npassword = \"<redacted>\"\n\
+                 tokens = [\"<redacted>\", \"plain\"]\n",
+            )
+        );
+    }
+}
@@ -31,6 +31,11 @@ pub enum Error {
     ArtifactIdentMismatch((String, String, String)),
     CantUploadGossipToml,
     ChannelNotFound,
+    /// Occurs when a configuration does not satisfy its package's schema, with every violation.
+    ConfigInvalid(Vec<String>),
+    /// Occurs when a package's configuration schema cannot be parsed.
+    ConfigSchemaInvalid(String),
+    ConfigSecretInvalid(String),
     CryptoKeyError(String),
     GossipFileRelativePath(String),
     DepotClient(depot_client::Error),
@@ -61,6 +66,14 @@ impl fmt::Display for Error {
                 format!("Can't upload gossip.toml, it's a reserved file name")
             }
             Error::ChannelNotFound => format!("Channel not found"),
+            Error::ConfigInvalid(ref violations) => {
+                format!(
+                    "Configuration does not match the package's schema: {}",
+                    violations.join("; ")
+                )
+            }
+            Error::ConfigSchemaInvalid(ref e) => format!("Invalid configuration schema: {}", e),
+            Error::ConfigSecretInvalid(ref e) => format!("Invalid configuration secret: {}", e),
             Error::CryptoKeyError(ref s) => format!("Missing or invalid key: {}", s),
             Error::GossipFileRelativePath(ref s) => {
                 format!(
@@ -93,6 +106,9 @@ impl error::Error for Error {
             }
             Error::CantUploadGossipToml => "Can't upload gossip.toml, it's a reserved filename",
             Error::ChannelNotFound => "Channel not found",
+            Error::ConfigInvalid(_) => "Configuration does not match the package's schema",
+            Error::ConfigSchemaInvalid(_) => "Invalid configuration schema",
+            Error::ConfigSecretInvalid(_) => "Invalid configuration secret",
             Error::CryptoKeyError(_) => "Missing or invalid key",
             Error::GossipFileRelativePath(_) => {
                 "Path for gossip file cannot have relative components (eg: ..)"
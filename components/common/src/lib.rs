@@ -18,6 +18,7 @@ extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hcore;
 extern crate habitat_depot_client as depot_client;
 extern crate ansi_term;
+extern crate base64;
 extern crate hyper;
 #[macro_use]
 extern crate log;
@@ -33,5 +34,7 @@ extern crate toml;
 pub use self::error::{Error, Result};
 
 pub mod command;
+pub mod config_schema;
+pub mod config_secret;
 pub mod error;
 pub mod ui;
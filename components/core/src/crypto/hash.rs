@@ -20,6 +20,7 @@ use std::ptr;
 
 use hex::ToHex;
 use libsodium_sys;
+use sodiumoxide::crypto::hash::sha256;
 
 use error::Result;
 
@@ -65,6 +66,11 @@ pub fn hash_bytes(data: &[u8]) -> String {
     out.to_hex()
 }
 
+/// Calculate the SHA-256 hash of a string, return as a hex string
+pub fn sha256_string(data: &str) -> String {
+    sha256::hash(data.as_bytes()).0.to_hex()
+}
+
 pub fn hash_reader(reader: &mut BufReader<File>) -> Result<String> {
     let mut out = [0u8; libsodium_sys::crypto_generichash_BYTES];
     let mut st = vec![0u8; (unsafe { libsodium_sys::crypto_generichash_statebytes() })];
@@ -103,6 +103,8 @@ pub enum Error {
     InvalidPackageType(String),
     /// Occurs when validating a package target for an unsupported platform.
     InvalidPlatform(String),
+    /// Occurs when a package version requirement string cannot be successfully parsed.
+    InvalidVersionReq(String),
     /// Occurs when a service group string cannot be successfully parsed.
     InvalidServiceGroup(String),
     /// Occurs when an origin is in an invalid format
@@ -116,6 +118,8 @@ pub enum Error {
     /// Occurs when a BIND, BIND_OPTIONAL, or BIND_MAP MetaFile is
     /// read and contains a bad entry.
     MetaFileBadBind,
+    /// Occurs when a PERIODIC_HOOKS MetaFile is read and contains a bad entry.
+    MetaFileBadPeriodicHook(String),
     /// Occurs when a package metadata file cannot be opened, read, or parsed.
     MetaFileMalformed(package::metadata::MetaFile),
     /// Occurs when a particular package metadata file is not found.
@@ -307,6 +311,13 @@ impl fmt::Display for Error {
             Error::InvalidArchitecture(ref e) => format!("Invalid architecture: {}.", e),
             Error::InvalidPackageType(ref e) => format!("Invalid package type: {}.", e),
             Error::InvalidPlatform(ref e) => format!("Invalid platform: {}.", e),
+            Error::InvalidVersionReq(ref e) => {
+                format!(
+                    "Invalid version requirement: {}. A valid requirement is one or more \
+                         comma-separated constraints (example: >=2.0, <3.0)",
+                    e
+                )
+            }
             Error::InvalidServiceGroup(ref e) => {
                 format!(
                     "Invalid service group: {}. A valid service group string is in the form \
@@ -333,6 +344,13 @@ impl fmt::Display for Error {
             Error::MetaFileBadBind => {
                 format!("Bad value parsed from BIND, BIND_OPTIONAL, or BIND_MAP")
             }
+            Error::MetaFileBadPeriodicHook(ref e) => {
+                format!(
+                    "Bad value parsed from PERIODIC_HOOKS: '{}', expected \
+                         <HOOK>=<SECONDS>[ leader]",
+                    e
+                )
+            }
             Error::MetaFileMalformed(ref e) => {
                 format!("MetaFile: {:?}, didn't contain a valid UTF-8 string", e)
             }
@@ -461,6 +479,9 @@ impl error::Error for Error {
             Error::InvalidArchitecture(_) => "Unsupported target architecture supplied.",
             Error::InvalidPackageType(_) => "Unsupported package type supplied.",
             Error::InvalidPlatform(_) => "Unsupported target platform supplied.",
+            Error::InvalidVersionReq(_) => {
+                "Version requirements must be comma-separated constraints (example: >=2.0, <3.0)"
+            }
             Error::InvalidServiceGroup(_) => {
                 "Service group strings must be in service.group format (example: redis.production)"
             }
@@ -476,6 +497,7 @@ impl error::Error for Error {
             Error::MetaFileBadBind => {
                 "Bad value parsed from BIND, BIND_OPTIONAL, or BIND_MAP MetaFile"
             }
+            Error::MetaFileBadPeriodicHook(_) => "Bad value parsed from PERIODIC_HOOKS MetaFile",
             Error::MetaFileMalformed(_) => "MetaFile didn't contain a valid UTF-8 string",
             Error::MetaFileNotFound(_) => "Failed to read an archive's metafile",
             Error::MetaFileIO(_) => "MetaFile could not be read or written to",
@@ -265,6 +265,24 @@ impl Child {
             Ok(ExitStatus(status))
         }
     }
+
+    pub fn try_wait(&mut self) -> Result<Option<ExitStatus>> {
+        unsafe {
+            match kernel32::WaitForSingleObject(self.handle.raw(), 0) {
+                winapi::WAIT_OBJECT_0 => {}
+                winapi::WAIT_TIMEOUT => return Ok(None),
+                _ => {
+                    return Err(Error::WaitForSingleObjectFailed(format!(
+                        "Failed calling WaitForSingleObjectFailed: {}",
+                        io::Error::last_os_error()
+                    )))
+                }
+            }
+            let mut status = 0;
+            cvt(kernel32::GetExitCodeProcess(self.handle.raw(), &mut status))?;
+            Ok(Some(ExitStatus(status)))
+        }
+    }
 }
 
 pub trait AsInner<Inner: ?Sized> {
@@ -24,18 +24,27 @@
 //! is turned on, then every line printed is annotated with its preamble, logkey, and precise
 //! location. Without verbose, it prints simply the preamble and logkey. Coloring does what it says
 //! on the tin :)
+//!
+//! A third option turns on JSON output, where every line is printed as a JSON object carrying a
+//! timestamp, level, service group, source and message, for consumption by log pipelines.
 
 use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
 use std::fmt;
 
 use ansi_term::Colour::{White, Cyan, Green};
+use serde_json;
+use time;
 
 use PROGRAM_NAME;
 
+/// Environment variable which, when set, turns on JSON output.
+pub const JSON_LOGGING_ENVVAR: &'static str = "HAB_SUP_JSON_LOGGING";
+
 static mut VERBOSE: AtomicBool = ATOMIC_BOOL_INIT;
 // I am sorry this isn't named the other way; I can't get an atomic initializer that defaults to
 // true. Them's the breaks.
 static mut NO_COLOR: AtomicBool = ATOMIC_BOOL_INIT;
+static mut JSON: AtomicBool = ATOMIC_BOOL_INIT;
 
 /// True if verbose output is on.
 pub fn is_verbose() -> bool {
@@ -67,6 +76,26 @@ pub fn set_no_color(booly: bool) {
     }
 }
 
+/// True if every line is printed as a JSON object.
+pub fn is_json() -> bool {
+    unsafe { JSON.load(Ordering::Relaxed) }
+}
+
+/// Turn JSON output on or off.
+pub fn set_json(booly: bool) {
+    unsafe {
+        JSON.store(booly, Ordering::Relaxed);
+    }
+}
+
+/// The severity of a line of output. Anything a process writes to standard error is an `Error`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Info,
+    Error,
+}
+
 /// Adds structure to printed output. Stores a preamble, a logkey, line, file, column, and content
 /// to print.
 pub struct StructuredOutput<'a> {
@@ -76,8 +105,25 @@ pub struct StructuredOutput<'a> {
     file: &'static str,
     column: u32,
     content: &'a str,
+    /// What produced the output within the service, such as `hook[init]`, shown after the
+    /// preamble. Output without a source comes from the Supervisor itself.
+    pub source: Option<&'a str>,
+    pub level: Level,
     pub verbose: Option<bool>,
     pub color: Option<bool>,
+    pub json: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct JsonOutput<'a> {
+    timestamp: String,
+    level: Level,
+    service_group: Option<&'a str>,
+    source: &'a str,
+    logkey: &'static str,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
 }
 
 impl<'a> StructuredOutput<'a> {
@@ -97,30 +143,83 @@ impl<'a> StructuredOutput<'a> {
             file: file,
             column: column,
             content: content,
+            source: None,
+            level: Level::Info,
             verbose: None,
             color: None,
+            json: None,
         }
     }
+
+    fn fmt_json(&self, f: &mut fmt::Formatter, verbose: bool) -> fmt::Result {
+        let is_supervisor = self.preamble == PROGRAM_NAME.as_str();
+        let output = JsonOutput {
+            timestamp: format!("{}", time::now_utc().rfc3339()),
+            level: self.level,
+            service_group: if is_supervisor {
+                None
+            } else {
+                Some(self.preamble)
+            },
+            source: self.source.unwrap_or("supervisor"),
+            logkey: self.logkey,
+            message: strip_ansi(self.content.trim_right_matches(|c| c == '\r' || c == '\n')),
+            location: if verbose {
+                Some(format!("{}:{}:{}", self.file, self.line, self.column))
+            } else {
+                None
+            },
+        };
+        let json = serde_json::to_string(&output).map_err(|_| fmt::Error)?;
+        write!(f, "{}", json)
+    }
+}
+
+/// Remove the ANSI escape sequences from a message, such as the colors painted onto much of the
+/// Supervisor's output, which have no place in a JSON log.
+fn strip_ansi(message: &str) -> String {
+    let mut stripped = String::with_capacity(message.len());
+    let mut chars = message.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            stripped.push(c);
+            continue;
+        }
+        // A control sequence ends with its first character in the range `@` to `~`, any other
+        // escape with the character right after it.
+        if let Some('[') = chars.next() {
+            for c in chars.by_ref() {
+                if c >= '@' && c <= '~' {
+                    break;
+                }
+            }
+        }
+    }
+    stripped
 }
 
-// If we ever want to create multiple output formats in the future, we would do it here -
-// essentially create a flag we check to see what output you want, then call a different formatting
-// function. Viola!
 impl<'a> fmt::Display for StructuredOutput<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let verbose = self.verbose.unwrap_or(is_verbose());
+        if self.json.unwrap_or(is_json()) {
+            return self.fmt_json(f, verbose);
+        }
         let color = self.color.unwrap_or(is_color());
         let preamble_color = if self.preamble == PROGRAM_NAME.as_str() {
             Cyan
         } else {
             Green
         };
+        let preamble = match self.source {
+            Some(source) => format!("{} {}:", self.preamble, source),
+            None => self.preamble.to_string(),
+        };
         if verbose {
             if color {
                 write!(
                     f,
                     "{}({})[{}]: {}",
-                    preamble_color.paint(self.preamble),
+                    preamble_color.paint(preamble.as_str()),
                     White.bold().paint(self.logkey),
                     White.underline().paint(format!(
                         "{}:{}:{}",
@@ -134,7 +233,7 @@ impl<'a> fmt::Display for StructuredOutput<'a> {
                 write!(
                     f,
                     "{}({})[{}:{}:{}]: {}",
-                    self.preamble,
+                    preamble,
                     self.logkey,
                     self.file,
                     self.line,
@@ -147,12 +246,12 @@ impl<'a> fmt::Display for StructuredOutput<'a> {
                 write!(
                     f,
                     "{}({}): {}",
-                    preamble_color.paint(self.preamble),
+                    preamble_color.paint(preamble.as_str()),
                     White.bold().paint(self.logkey),
                     self.content
                 )
             } else {
-                write!(f, "{}({}): {}", self.preamble, self.logkey, self.content)
+                write!(f, "{}({}): {}", preamble, self.logkey, self.content)
             }
         }
     }
@@ -351,8 +450,9 @@ macro_rules! output_format {
 
 #[cfg(test)]
 mod tests {
-    use super::StructuredOutput;
-    use ansi_term::Colour::{White, Cyan};
+    use super::{Level, StructuredOutput};
+    use ansi_term::Colour::{White, Cyan, Red};
+    use serde_json::{self, Value};
 
     use PROGRAM_NAME;
 
@@ -393,4 +493,52 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn format_source() {
+        let mut so = so("redis.default", "listening");
+        so.source = Some("hook[init]");
+        so.verbose = Some(false);
+        so.color = Some(false);
+        so.json = Some(false);
+        assert_eq!(format!("{}", so), "redis.default hook[init]:(SOT): listening");
+    }
+
+    #[test]
+    fn format_json() {
+        let mut so = so("redis.default", "no such file\n");
+        so.source = Some("hook[init]");
+        so.level = Level::Error;
+        so.verbose = Some(false);
+        so.json = Some(true);
+        let json: Value = serde_json::from_str(&format!("{}", so)).unwrap();
+        assert!(json["timestamp"].is_string());
+        assert_eq!(json["level"], "error");
+        assert_eq!(json["service_group"], "redis.default");
+        assert_eq!(json["source"], "hook[init]");
+        assert_eq!(json["logkey"], "SOT");
+        assert_eq!(json["message"], "no such file");
+        assert!(json.get("location").is_none());
+    }
+
+    #[test]
+    fn format_json_supervisor() {
+        let mut so = so(PROGRAM_NAME.as_str(), "Starting");
+        so.verbose = Some(true);
+        so.json = Some(true);
+        let json: Value = serde_json::from_str(&format!("{}", so)).unwrap();
+        assert_eq!(json["level"], "info");
+        assert!(json["service_group"].is_null());
+        assert_eq!(json["source"], "supervisor");
+        assert_eq!(json["location"], format!("{}:1:2", file!()));
+    }
+
+    #[test]
+    fn format_json_without_colors() {
+        let content = format!("Update failed, {}", Red.bold().paint("rolling back"));
+        let mut so = so("redis.default", &content);
+        so.json = Some(true);
+        let json: Value = serde_json::from_str(&format!("{}", so)).unwrap();
+        assert_eq!(json["message"], "Update failed, rolling back");
+    }
 }
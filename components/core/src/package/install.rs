@@ -26,8 +26,9 @@ use std::str::FromStr;
 use toml;
 use toml::Value;
 
-use super::{Identifiable, PackageIdent, Target, PackageTarget};
-use super::metadata::{Bind, BindMapping, MetaFile, PackageType, PkgEnv, parse_key_value};
+use super::{Identifiable, PackageIdent, Target, PackageTarget, VersionReq};
+use super::metadata::{Bind, BindMapping, HookSchedule, MetaFile, PackageType, PkgEnv,
+                      parse_key_value};
 use error::{Error, Result};
 use fs;
 
@@ -85,6 +86,40 @@ impl PackageInstall {
         }
     }
 
+    /// Verifies an installation of the newest release of a package whose version satisfies the
+    /// given requirement and returns a Result of a `PackageInstall` if one exists.
+    ///
+    /// An optional `fs_root` path may be provided to search for a package that is mounted on a
+    /// filesystem not currently rooted at `/`.
+    pub fn load_matching(
+        ident: &PackageIdent,
+        version_req: &VersionReq,
+        fs_root_path: Option<&Path>,
+    ) -> Result<PackageInstall> {
+        let fs_root_path = fs_root_path.map_or(PathBuf::from("/"), |p| p.into());
+        let package_root_path = fs::pkg_root_path(Some(&fs_root_path));
+        if !package_root_path.exists() {
+            return Err(Error::PackageNotFound(ident.clone()));
+        }
+        let latest = Self::package_list(&package_root_path)?
+            .into_iter()
+            .filter(|p| p.satisfies(ident) && version_req.matches_ident(p))
+            .max();
+        let package_install = match latest {
+            Some(id) => {
+                PackageInstall {
+                    installed_path: fs::pkg_install_path(&id, Some(&fs_root_path)),
+                    fs_root_path: fs_root_path,
+                    package_root_path: package_root_path,
+                    ident: id,
+                }
+            }
+            None => return Err(Error::PackageNotFound(ident.clone())),
+        };
+        package_install.target()?.validate()?;
+        Ok(package_install)
+    }
+
     fn resolve_package_install<T>(
         ident: &PackageIdent,
         fs_root_path: Option<T>,
@@ -379,6 +414,15 @@ impl PackageInstall {
         }
     }
 
+    /// The hooks in the package's `hooks/periodic` directory and their schedules.
+    pub fn periodic_hooks(&self) -> Result<Vec<HookSchedule>> {
+        match self.read_metafile(MetaFile::PeriodicHooks) {
+            Ok(body) => body.lines().map(HookSchedule::from_str).collect(),
+            Err(Error::MetaFileNotFound(MetaFile::PeriodicHooks)) => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
     /// A vector of ports we expose
     pub fn exposes(&self) -> Result<Vec<String>> {
         match self.read_metafile(MetaFile::Exposes) {
@@ -723,8 +767,9 @@ mod test {
     use std::io::Write;
     use std::path::PathBuf;
     use std::str::FromStr;
+    use fs;
     use super::PackageInstall;
-    use super::super::PackageIdent;
+    use super::super::{PackageIdent, PackageTarget, VersionReq};
     use super::super::test_support::*;
     use tempdir::TempDir;
     use toml;
@@ -748,6 +793,35 @@ mod test {
         }
     }
 
+    #[test]
+    fn load_matching_finds_newest_release_satisfying_requirement() {
+        let fs_root = TempDir::new("load_matching").unwrap();
+        let installed = [
+            "core/redis/3.2.4/20170101000000",
+            "core/redis/3.2.9/20170202000000",
+            "core/redis/4.0.1/20170303000000",
+        ];
+        for ident in installed.iter() {
+            let ident = PackageIdent::from_str(ident).unwrap();
+            let path = fs::pkg_install_path(&ident, Some(fs_root.path()));
+            ::std::fs::create_dir_all(&path).unwrap();
+            let mut f = File::create(path.join(MetaFile::Target.to_string())).unwrap();
+            f.write_all(PackageTarget::default().to_string().as_bytes())
+                .unwrap();
+        }
+        let redis = PackageIdent::from_str("core/redis").unwrap();
+
+        let req = VersionReq::from_str("~3.2").unwrap();
+        let install = PackageInstall::load_matching(&redis, &req, Some(fs_root.path())).unwrap();
+        assert_eq!(
+            install.ident(),
+            &PackageIdent::from_str("core/redis/3.2.9/20170202000000").unwrap()
+        );
+
+        let req = VersionReq::from_str(">=5.0").unwrap();
+        assert!(PackageInstall::load_matching(&redis, &req, Some(fs_root.path())).is_err());
+    }
+
     /// Create a `PackageInstall` struct for the explicit purpose of
     /// testing metadata file interpretation. This exists to point to
     /// a directory of metadata files, and that's it.
@@ -92,6 +92,53 @@ impl FromStr for BindMapping {
     }
 }
 
+/// Describes a hook a package ships in its `hooks/periodic` directory and how often the
+/// Supervisor runs it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HookSchedule {
+    /// The file name of the hook within `hooks/periodic`.
+    pub name: String,
+    /// The number of seconds between runs of the hook.
+    pub interval: u64,
+    /// Whether only the leader of a service group in a leader topology runs the hook.
+    pub leader_only: bool,
+}
+
+impl FromStr for HookSchedule {
+    type Err = Error;
+
+    /// Parses a line of the form `<HOOK>=<SECONDS>`, optionally followed by ` leader`.
+    fn from_str(line: &str) -> Result<Self> {
+        let bad = || Error::MetaFileBadPeriodicHook(line.to_string());
+        let mut parts = line.splitn(2, '=');
+        let name = match parts.next() {
+            Some(name) if !name.is_empty() => name.to_string(),
+            _ => return Err(bad()),
+        };
+        let mut schedule = match parts.next() {
+            Some(schedule) => schedule.split_whitespace(),
+            None => return Err(bad()),
+        };
+        let interval = match schedule.next().map(|i| i.parse::<u64>()) {
+            Some(Ok(interval)) if interval > 0 => interval,
+            _ => return Err(bad()),
+        };
+        let leader_only = match schedule.next() {
+            None => false,
+            Some("leader") => true,
+            Some(_) => return Err(bad()),
+        };
+        if schedule.next().is_some() {
+            return Err(bad());
+        }
+        Ok(HookSchedule {
+            name: name,
+            interval: interval,
+            leader_only: leader_only,
+        })
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct EnvVar {
     pub key: String,
@@ -172,6 +219,7 @@ pub enum MetaFile {
     LdRunPath,
     Manifest,
     Path,
+    PeriodicHooks,
     ResolvedServices, // Composite-only
     Services, // Composite-only
     SvcGroup,
@@ -199,6 +247,7 @@ impl fmt::Display for MetaFile {
             MetaFile::LdRunPath => "LD_RUN_PATH",
             MetaFile::Manifest => "MANIFEST",
             MetaFile::Path => "PATH",
+            MetaFile::PeriodicHooks => "PERIODIC_HOOKS",
             MetaFile::ResolvedServices => "RESOLVED_SERVICES",
             MetaFile::Services => "SERVICES",
             MetaFile::SvcGroup => "SVC_GROUP",
@@ -356,6 +405,35 @@ port=front-end.port
         );
     }
 
+    #[test]
+    fn can_parse_a_hook_schedule() {
+        let output: HookSchedule = "backup=3600".parse().unwrap();
+        assert_eq!(output.name, "backup");
+        assert_eq!(output.interval, 3600);
+        assert!(!output.leader_only);
+
+        let output: HookSchedule = "compact=86400 leader".parse().unwrap();
+        assert_eq!(output.name, "compact");
+        assert_eq!(output.interval, 86400);
+        assert!(output.leader_only);
+    }
+
+    #[test]
+    fn fails_to_parse_a_bad_hook_schedule() {
+        for input in &[
+            "backup",
+            "=3600",
+            "backup=",
+            "backup=0",
+            "backup=hourly",
+            "backup=3600 follower",
+            "backup=3600 leader now",
+        ]
+        {
+            assert!(input.parse::<HookSchedule>().is_err(), "parsed {}", input);
+        }
+    }
+
     #[test]
     fn fails_to_parse_a_bind_mapping_with_an_invalid_service_identifier() {
         let input = "my_bind:this-is-a-bad-identifier";
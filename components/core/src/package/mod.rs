@@ -18,12 +18,14 @@ pub mod install;
 pub mod metadata;
 pub mod plan;
 pub mod target;
+pub mod version_req;
 
 pub use self::archive::{FromArchive, PackageArchive};
 pub use self::ident::{Identifiable, PackageIdent};
 pub use self::install::PackageInstall;
 pub use self::plan::Plan;
 pub use self::target::{Target, PackageTarget};
+pub use self::version_req::VersionReq;
 
 #[cfg(test)]
 pub mod test_support {
@@ -0,0 +1,275 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Requirements on the version of a package.
+//!
+//! A requirement is one or more comma-separated constraints, all of which a version must satisfy.
+//! A constraint is an operator followed by a version made of dot-separated numbers:
+//!
+//! * `>`, `>=`, `<` and `<=` compare against the version, so `>=2.0, <3.0` matches any 2.x
+//! * `=`, or no operator at all, matches the version and any version beginning with it, so `=1.4`
+//!   matches `1.4`, `1.4.0` and `1.4.7`
+//! * `~` allows changes to the last component given, or to the minor version when more than two
+//!   are given, so `~1.4` and `~1.4.2` both stay below `1.5`
+//! * `^` allows changes which do not modify the leftmost non-zero component, so `^1.4` stays below
+//!   `2.0` and `^0.4` below `0.5`
+//! * `*` matches every version
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::result;
+use std::str::FromStr;
+
+use serde;
+
+use error::{Error, Result};
+use package::Identifiable;
+use package::ident::version_sort;
+use util::deserialize_using_from_str;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionReq {
+    source: String,
+    predicates: Vec<Predicate>,
+}
+
+impl VersionReq {
+    /// Whether the given version satisfies every constraint. Versions which cannot be compared,
+    /// such as ones which don't start with a number, never match.
+    pub fn matches(&self, version: &str) -> bool {
+        self.predicates.iter().all(|p| p.matches(version))
+    }
+
+    /// Whether the version of the given package satisfies the requirement. Package identifiers
+    /// without a version never match.
+    pub fn matches_ident<I: Identifiable>(&self, ident: &I) -> bool {
+        match ident.version() {
+            Some(version) => self.matches(version),
+            None => false,
+        }
+    }
+}
+
+impl fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+impl FromStr for VersionReq {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let mut predicates = Vec::new();
+        for constraint in value.split(',') {
+            match parse_constraint(constraint.trim()) {
+                Ok(mut p) => predicates.append(&mut p),
+                Err(()) => return Err(Error::InvalidVersionReq(value.to_string())),
+            }
+        }
+        Ok(VersionReq {
+            source: value.trim().to_string(),
+            predicates: predicates,
+        })
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for VersionReq {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for VersionReq {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Predicate {
+    op: Op,
+    version: String,
+}
+
+impl Predicate {
+    fn new(op: Op, parts: &[u64]) -> Self {
+        let version: Vec<String> = parts.iter().map(|p| p.to_string()).collect();
+        Predicate {
+            op: op,
+            version: version.join("."),
+        }
+    }
+
+    fn matches(&self, version: &str) -> bool {
+        match version_sort(version, &self.version) {
+            Ok(ordering) => {
+                match self.op {
+                    Op::Greater => ordering == Ordering::Greater,
+                    Op::GreaterEq => ordering != Ordering::Less,
+                    Op::Less => ordering == Ordering::Less,
+                    Op::LessEq => ordering != Ordering::Greater,
+                }
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+/// Parse a single constraint into the predicates which express it.
+fn parse_constraint(value: &str) -> result::Result<Vec<Predicate>, ()> {
+    if value == "*" {
+        return Ok(vec![]);
+    }
+    let split = value
+        .find(|c: char| c.is_digit(10) || c.is_whitespace())
+        .unwrap_or(value.len());
+    let (op, version) = value.split_at(split);
+    let parts = version
+        .trim()
+        .split('.')
+        .map(|part| part.parse::<u64>().map_err(|_| ()))
+        .collect::<result::Result<Vec<u64>, ()>>()?;
+    let predicates = match op {
+        ">" => vec![Predicate::new(Op::Greater, &parts)],
+        ">=" => vec![Predicate::new(Op::GreaterEq, &parts)],
+        "<" => vec![Predicate::new(Op::Less, &parts)],
+        "<=" => vec![Predicate::new(Op::LessEq, &parts)],
+        "" | "=" => {
+            let last = parts.len() - 1;
+            vec![
+                Predicate::new(Op::GreaterEq, &parts),
+                Predicate::new(Op::Less, &bump(&parts, last)),
+            ]
+        }
+        "~" => {
+            let minor = if parts.len() > 1 { 1 } else { 0 };
+            vec![
+                Predicate::new(Op::GreaterEq, &parts),
+                Predicate::new(Op::Less, &bump(&parts, minor)),
+            ]
+        }
+        "^" => {
+            let first_non_zero = parts.iter().position(|p| *p != 0).unwrap_or(
+                parts.len() - 1,
+            );
+            vec![
+                Predicate::new(Op::GreaterEq, &parts),
+                Predicate::new(Op::Less, &bump(&parts, first_non_zero)),
+            ]
+        }
+        _ => return Err(()),
+    };
+    Ok(predicates)
+}
+
+/// The smallest version above every version which shares the first `index + 1` components.
+fn bump(parts: &[u64], index: usize) -> Vec<u64> {
+    let mut bumped = parts[..index + 1].to_vec();
+    bumped[index] += 1;
+    bumped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use package::PackageIdent;
+
+    fn assert_matches(req: &str, matching: &[&str], not_matching: &[&str]) {
+        let req = VersionReq::from_str(req).unwrap();
+        for version in matching {
+            assert!(req.matches(version), "{} should match {}", req, version);
+        }
+        for version in not_matching {
+            assert!(!req.matches(version), "{} should not match {}", req, version);
+        }
+    }
+
+    #[test]
+    fn version_req_tilde() {
+        assert_matches("~1.4", &["1.4", "1.4.0", "1.4.12"], &["1.3.9", "1.5", "2.0.0"]);
+        assert_matches("~1.4.2", &["1.4.2", "1.4.9"], &["1.4.1", "1.5.0"]);
+        assert_matches("~1", &["1.0", "1.9.3"], &["0.9", "2.0"]);
+    }
+
+    #[test]
+    fn version_req_caret() {
+        assert_matches("^1.4", &["1.4.0", "1.9.3"], &["1.3.0", "2.0.0"]);
+        assert_matches("^0.4.1", &["0.4.1", "0.4.7"], &["0.4.0", "0.5.0"]);
+    }
+
+    #[test]
+    fn version_req_exact() {
+        assert_matches("=1.4", &["1.4", "1.4.7"], &["1.3", "1.5.0", "1.40"]);
+        assert_matches("3.2.4", &["3.2.4"], &["3.2.3", "3.2.5"]);
+    }
+
+    #[test]
+    fn version_req_range() {
+        assert_matches(
+            ">=2.0, <3.0",
+            &["2.0", "2.0.0", "2.17.1"],
+            &["1.9.9", "3.0", "3.0.1"],
+        );
+        assert_matches(">1.0,<=1.2", &["1.1", "1.2", "1.2.0"], &["1.0", "1.2.1"]);
+        assert_matches("*", &["0.0.1", "12.0"], &[]);
+    }
+
+    #[test]
+    fn version_req_unparseable_version() {
+        assert_matches(">=1.0", &[], &["master", ""]);
+    }
+
+    #[test]
+    fn version_req_matches_ident() {
+        let req = VersionReq::from_str("~1.4").unwrap();
+        let ident = PackageIdent::from_str("core/redis/1.4.2/20170514150022").unwrap();
+        assert!(req.matches_ident(&ident));
+        let ident = PackageIdent::from_str("core/redis/2.0.0/20170514150022").unwrap();
+        assert!(!req.matches_ident(&ident));
+        let ident = PackageIdent::from_str("core/redis").unwrap();
+        assert!(!req.matches_ident(&ident));
+    }
+
+    #[test]
+    fn version_req_display() {
+        let req = VersionReq::from_str(" >=2.0, <3.0 ").unwrap();
+        assert_eq!(req.to_string(), ">=2.0, <3.0");
+    }
+
+    #[test]
+    fn version_req_invalid() {
+        for value in &["", "~", "1.x", ">>1.0", "=>1.0", "1.0,", "~1.0-beta", "1..2"] {
+            match VersionReq::from_str(value) {
+                Err(Error::InvalidVersionReq(_)) => (),
+                Err(e) => panic!("Unexpected error returned: {:?}", e),
+                Ok(_) => panic!("'{}' should fail to parse", value),
+            }
+        }
+    }
+}
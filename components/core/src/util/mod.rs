@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod perm;
+pub mod rotating_log;
 pub mod sys;
 
 use std::error;
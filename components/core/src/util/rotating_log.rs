@@ -0,0 +1,350 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A log file which rotates itself once it grows too large or has been written to for too long.
+//!
+//! Rotated files are kept next to the log as `<name>.1`, the most recent, through `<name>.<keep>`,
+//! and anything older is removed, which bounds the disk space a log can take up. A log rotated
+//! by age records when it was started in `<name>.started`, so that its age carries over when the
+//! log is reopened, however often that happens.
+
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Bytes a log may grow to before it is rotated, unless configured otherwise.
+pub const DEFAULT_MAX_SIZE: u64 = 10 * 1024 * 1024;
+/// Rotated files kept for each log, unless configured otherwise.
+pub const DEFAULT_KEEP: u32 = 5;
+
+/// When a log is rotated and how many rotated files are kept.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RotationPolicy {
+    /// Bytes the log may grow to before it is rotated
+    pub max_size: u64,
+    /// Seconds the log may be written to before it is rotated, however small, if set
+    pub max_age: Option<u64>,
+    /// Rotated files kept, older ones are removed. With none kept, the log is truncated instead.
+    pub keep: u32,
+}
+
+impl Default for RotationPolicy {
+    fn default() -> Self {
+        RotationPolicy {
+            max_size: DEFAULT_MAX_SIZE,
+            max_age: None,
+            keep: DEFAULT_KEEP,
+        }
+    }
+}
+
+pub struct RotatingLog {
+    path: PathBuf,
+    policy: RotationPolicy,
+    // Only `None` while the log is being rotated, so the file is closed before it's renamed.
+    file: Option<File>,
+    size: u64,
+    // When the log was started, so its age survives the log being reopened.
+    started: SystemTime,
+}
+
+impl RotatingLog {
+    /// Open the log for appending, creating it if it doesn't exist.
+    pub fn open<P>(path: P, policy: RotationPolicy) -> io::Result<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        let path = path.into();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+        let mut log = RotatingLog {
+            path: path,
+            policy: policy,
+            file: Some(file),
+            size: size,
+            started: SystemTime::now(),
+        };
+        if log.policy.max_age.is_some() {
+            match read_started(&log.path) {
+                // A log with no record of its start, such as one written before it was rotated
+                // by age, is treated as started now.
+                Some(started) if size > 0 => log.started = started,
+                _ => write_started(&log.path, log.started)?,
+            }
+        }
+        Ok(log)
+    }
+
+    /// Open the log empty, first rotating away anything written to it earlier.
+    pub fn create<P>(path: P, policy: RotationPolicy) -> io::Result<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        let mut log = Self::open(path, policy)?;
+        if log.size > 0 {
+            log.rotate()?;
+        }
+        Ok(log)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Move the log to `<name>.1`, shifting earlier rotated files along and removing any beyond
+    /// the number kept, then start over with an empty log.
+    pub fn rotate(&mut self) -> io::Result<()> {
+        if let Some(mut file) = self.file.take() {
+            file.flush()?;
+        }
+        if self.policy.keep > 0 {
+            let oldest = rotated_path(&self.path, self.policy.keep);
+            if oldest.exists() {
+                fs::remove_file(&oldest)?;
+            }
+            for n in (1..self.policy.keep).rev() {
+                let from = rotated_path(&self.path, n);
+                if from.exists() {
+                    fs::rename(&from, rotated_path(&self.path, n + 1))?;
+                }
+            }
+            fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        }
+        self.file = Some(OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&self.path)?);
+        self.size = 0;
+        self.started = SystemTime::now();
+        if self.policy.max_age.is_some() {
+            write_started(&self.path, self.started)?;
+        }
+        Ok(())
+    }
+
+    fn should_rotate(&self, incoming: usize) -> bool {
+        if self.size == 0 {
+            return false;
+        }
+        if self.size + incoming as u64 > self.policy.max_size {
+            return true;
+        }
+        match self.policy.max_age {
+            Some(age) => {
+                // A clock set back makes the log seem new rather than failing the write
+                let elapsed = self.started.elapsed().unwrap_or(Duration::from_secs(0));
+                elapsed >= Duration::from_secs(age)
+            }
+            None => false,
+        }
+    }
+}
+
+impl Write for RotatingLog {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.should_rotate(buf.len()) {
+            self.rotate()?;
+        }
+        let written = match self.file {
+            Some(ref mut file) => file.write(buf)?,
+            None => return Err(io::Error::new(io::ErrorKind::Other, "log is not open")),
+        };
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.file {
+            Some(ref mut file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The path the log is moved to when it's the `n`th most recently rotated.
+pub fn rotated_path(path: &Path, n: u32) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(format!(".{}", n));
+    PathBuf::from(name)
+}
+
+/// The path of the file recording when the log was started, in milliseconds since the epoch.
+fn started_path(path: &Path) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(".started");
+    PathBuf::from(name)
+}
+
+fn read_started(path: &Path) -> Option<SystemTime> {
+    let mut content = String::new();
+    match File::open(started_path(path)).and_then(|mut f| f.read_to_string(&mut content)) {
+        Ok(_) => {
+            content.trim().parse().ok().map(|millis| {
+                UNIX_EPOCH + Duration::from_millis(millis)
+            })
+        }
+        Err(_) => None,
+    }
+}
+
+fn write_started(path: &Path, started: SystemTime) -> io::Result<()> {
+    let millis = started
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() * 1000 + (d.subsec_nanos() / 1_000_000) as u64)
+        .unwrap_or(0);
+    File::create(started_path(path))?.write_all(format!("{}\n", millis).as_bytes())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::io::Write;
+    use std::thread;
+
+    use tempdir::TempDir;
+
+    use super::*;
+
+    fn policy(max_size: u64, keep: u32) -> RotationPolicy {
+        RotationPolicy {
+            max_size: max_size,
+            max_age: None,
+            keep: keep,
+        }
+    }
+
+    fn content(path: &Path) -> String {
+        let mut content = String::new();
+        File::open(path)
+            .and_then(|mut f| io::Read::read_to_string(&mut f, &mut content))
+            .unwrap();
+        content
+    }
+
+    #[test]
+    fn rotates_by_size_and_keeps_a_bounded_number_of_files() {
+        let tmp = TempDir::new("rotating_log").unwrap();
+        let path = tmp.path().join("run.stdout.log");
+        let mut log = RotatingLog::open(&path, policy(10, 2)).unwrap();
+        for line in &["first\n", "second\n", "third\n", "fourth\n"] {
+            log.write_all(line.as_bytes()).unwrap();
+        }
+
+        assert_eq!(content(&path), "fourth\n");
+        assert_eq!(content(&rotated_path(&path, 1)), "third\n");
+        assert_eq!(content(&rotated_path(&path, 2)), "second\n");
+        assert!(!rotated_path(&path, 3).exists());
+    }
+
+    #[test]
+    fn rotates_by_age() {
+        let tmp = TempDir::new("rotating_log").unwrap();
+        let path = tmp.path().join("run.stdout.log");
+        let mut log = RotatingLog::open(
+            &path,
+            RotationPolicy {
+                max_size: DEFAULT_MAX_SIZE,
+                max_age: Some(0),
+                keep: 1,
+            },
+        ).unwrap();
+        log.write_all(b"first\n").unwrap();
+        log.write_all(b"second\n").unwrap();
+
+        assert_eq!(content(&path), "second\n");
+        assert_eq!(content(&rotated_path(&path, 1)), "first\n");
+    }
+
+    #[test]
+    fn rotates_by_age_of_a_reopened_log() {
+        let tmp = TempDir::new("rotating_log").unwrap();
+        let path = tmp.path().join("run.stdout.log");
+        let policy = RotationPolicy {
+            max_size: DEFAULT_MAX_SIZE,
+            max_age: Some(1),
+            keep: 1,
+        };
+        RotatingLog::open(&path, policy)
+            .unwrap()
+            .write_all(b"first\n")
+            .unwrap();
+        thread::sleep(Duration::from_millis(1500));
+        RotatingLog::open(&path, policy)
+            .unwrap()
+            .write_all(b"second\n")
+            .unwrap();
+
+        assert_eq!(content(&path), "second\n");
+        assert_eq!(content(&rotated_path(&path, 1)), "first\n");
+    }
+
+    #[test]
+    fn rotates_by_age_of_a_log_reopened_more_often_than_its_max_age() {
+        let tmp = TempDir::new("rotating_log").unwrap();
+        let path = tmp.path().join("run.stdout.log");
+        let policy = RotationPolicy {
+            max_size: DEFAULT_MAX_SIZE,
+            max_age: Some(2),
+            keep: 1,
+        };
+        for line in &["first\n", "second\n", "third\n"] {
+            RotatingLog::open(&path, policy)
+                .unwrap()
+                .write_all(line.as_bytes())
+                .unwrap();
+            thread::sleep(Duration::from_millis(1100));
+        }
+
+        assert_eq!(content(&path), "third\n");
+        assert_eq!(content(&rotated_path(&path, 1)), "first\nsecond\n");
+    }
+
+    #[test]
+    fn open_appends_and_create_rotates_earlier_content() {
+        let tmp = TempDir::new("rotating_log").unwrap();
+        let path = tmp.path().join("init.stdout.log");
+        RotatingLog::open(&path, policy(DEFAULT_MAX_SIZE, 1))
+            .unwrap()
+            .write_all(b"first\n")
+            .unwrap();
+        RotatingLog::open(&path, policy(DEFAULT_MAX_SIZE, 1))
+            .unwrap()
+            .write_all(b"second\n")
+            .unwrap();
+        assert_eq!(content(&path), "first\nsecond\n");
+
+        RotatingLog::create(&path, policy(DEFAULT_MAX_SIZE, 1))
+            .unwrap()
+            .write_all(b"third\n")
+            .unwrap();
+        assert_eq!(content(&path), "third\n");
+        assert_eq!(content(&rotated_path(&path, 1)), "first\nsecond\n");
+    }
+
+    #[test]
+    fn truncates_when_none_are_kept() {
+        let tmp = TempDir::new("rotating_log").unwrap();
+        let path = tmp.path().join("run.stdout.log");
+        let mut log = RotatingLog::open(&path, policy(10, 0)).unwrap();
+        log.write_all(b"first\n").unwrap();
+        log.write_all(b"second\n").unwrap();
+
+        assert_eq!(content(&path), "second\n");
+        assert!(!rotated_path(&path, 1).exists());
+        fs::remove_file(&path).unwrap();
+    }
+}
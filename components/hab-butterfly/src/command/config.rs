@@ -12,15 +12,70 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::thread;
+use std::time;
+
+use butterfly::client::Client;
+use common::ui::{Status, UI};
+use hcore::crypto::{SymKey, BoxKeyPair};
+use hcore::service::ServiceGroup;
+
+use error::{Error, Result};
+
+/// Gossip a service configuration to each peer, encrypting it first if both a user and a service
+/// key pair are given.
+fn gossip(
+    ui: &mut UI,
+    sg: &ServiceGroup,
+    number: u64,
+    mut body: Vec<u8>,
+    peers: &Vec<String>,
+    ring_key: Option<&SymKey>,
+    user_pair: Option<&BoxKeyPair>,
+    service_pair: Option<&BoxKeyPair>,
+) -> Result<()> {
+    let mut encrypted = false;
+    if service_pair.is_some() && user_pair.is_some() {
+        ui.status(
+            Status::Encrypting,
+            format!(
+                "TOML as {} for {}",
+                user_pair.unwrap().name_with_rev(),
+                service_pair.unwrap().name_with_rev()
+            ),
+        )?;
+        body = user_pair.unwrap().encrypt(
+            &body,
+            Some(service_pair.unwrap()),
+        )?;
+        encrypted = true;
+    }
+
+    for peer in peers.iter() {
+        ui.status(Status::Applying, format!("to peer {}", peer))?;
+        let mut client = Client::new(peer, ring_key.map(|k| k.clone())).map_err(|e| {
+            Error::ButterflyError(format!("{}", e))
+        })?;
+        client
+            .send_service_config(sg.clone(), number, body.clone(), encrypted)
+            .map_err(|e| Error::ButterflyError(format!("{}", e)))?;
+
+        // please take a moment to weep over the following line
+        // of code. We must sleep to allow messages to be sent
+        // before freeing the socket to prevent loss.
+        // see https://github.com/zeromq/libzmq/issues/1264
+        thread::sleep(time::Duration::from_millis(100));
+    }
+    Ok(())
+}
+
 pub mod apply {
     use std::str;
     use std::path::Path;
     use std::io::{self, Read};
     use std::fs::File;
-    use std::thread;
-    use std::time;
 
-    use butterfly::client::Client;
+    use common::config_schema::ConfigSchema;
     use common::ui::{Status, UI};
     use hcore::crypto::{SymKey, BoxKeyPair};
     use hcore::service::ServiceGroup;
@@ -33,6 +88,7 @@ pub mod apply {
         sg: &ServiceGroup,
         number: u64,
         file_path: Option<&Path>,
+        schema_path: Option<&Path>,
         peers: &Vec<String>,
         ring_key: Option<&SymKey>,
         user_pair: Option<&BoxKeyPair>,
@@ -59,12 +115,13 @@ pub mod apply {
             }
         };
 
-        match toml::de::from_slice::<toml::value::Value>(&body) {
-            Ok(_) => {
+        let table = match toml::de::from_slice::<toml::value::Table>(&body) {
+            Ok(table) => {
                 ui.status(
                     Status::Verified,
                     "this configuration is valid TOML",
-                )?
+                )?;
+                table
             }
             Err(err) => {
                 ui.fatal("Invalid TOML")?;
@@ -73,43 +130,179 @@ pub mod apply {
                 ui.br()?;
                 return Err(Error::TomlDeserializeError(err));
             }
+        };
+
+        if let Some(p) = schema_path {
+            let schema = ConfigSchema::from_file(p)?;
+            match schema.validate(&table, true) {
+                Ok(()) => {
+                    ui.status(
+                        Status::Verified,
+                        format!("this configuration matches {}", p.display()),
+                    )?
+                }
+                Err(err) => {
+                    ui.fatal("Configuration does not match the schema")?;
+                    ui.br()?;
+                    ui.warn(&err)?;
+                    ui.br()?;
+                    return Err(Error::from(err));
+                }
+            }
+        }
+
+        super::gossip(
+            ui,
+            sg,
+            number,
+            body,
+            peers,
+            ring_key,
+            user_pair,
+            service_pair,
+        )?;
+        ui.end("Applied configuration")?;
+        Ok(())
+    }
+}
+
+pub mod rollback {
+    use common::config_secret;
+    use common::ui::{Status, UI};
+    use hcore::crypto::{SymKey, BoxKeyPair};
+    use hcore::service::ServiceGroup;
+    use hyper::Client as HttpClient;
+    use hyper::header::{Authorization, Bearer};
+    use hyper::net::HttpsConnector;
+    use hyper::status::StatusCode;
+    use hyper_openssl::OpensslClient;
+    use serde_json;
+    use toml;
+
+    use error::{Error, Result};
+
+    /// A configuration from the history a Supervisor keeps for a service group.
+    #[derive(Deserialize)]
+    struct ConfigVersion {
+        incarnation: u64,
+        value: toml::Value,
+    }
+
+    pub fn start(
+        ui: &mut UI,
+        sg: &ServiceGroup,
+        number: u64,
+        remote_sup: &str,
+        auth_token: Option<String>,
+        peers: &Vec<String>,
+        ring_key: Option<&SymKey>,
+        user_pair: Option<&BoxKeyPair>,
+        service_pair: Option<&BoxKeyPair>,
+    ) -> Result<()> {
+        ui.begin(format!(
+            "Rolling back configuration for {} to incarnation {}",
+            sg,
+            number
+        ))?;
+
+        ui.status(
+            Status::Downloading,
+            format!("configuration history from {}", remote_sup),
+        )?;
+        let history = fetch_history(sg, remote_sup, auth_token)?;
+        let config = match history.iter().find(|c| c.incarnation == number) {
+            Some(config) => config,
+            None => return Err(Error::ConfigIncarnationNotFound(sg.to_string(), number)),
+        };
+        if config_secret::has_redacted(&config.value) {
+            return Err(Error::ConfigIncarnationRedacted(sg.to_string(), number));
         }
+        // The history always holds the current configuration, so this supersedes it
+        let incarnation = history.iter().map(|c| c.incarnation).max().unwrap() + 1;
+        let body = toml::to_string(&config.value)?.into_bytes();
+
+        ui.status(
+            Status::Creating,
+            format!("service configuration incarnation {}", incarnation),
+        )?;
+        super::gossip(
+            ui,
+            sg,
+            incarnation,
+            body,
+            peers,
+            ring_key,
+            user_pair,
+            service_pair,
+        )?;
+        ui.end(format!(
+            "Re-applied configuration incarnation {} as incarnation {}",
+            number,
+            incarnation
+        ))?;
+        Ok(())
+    }
 
-        let mut encrypted = false;
-        if service_pair.is_some() && user_pair.is_some() {
-            ui.status(
-                Status::Encrypting,
-                format!(
-                    "TOML as {} for {}",
-                    user_pair.unwrap().name_with_rev(),
-                    service_pair.unwrap().name_with_rev()
-                ),
-            )?;
-            body = user_pair.unwrap().encrypt(
-                &body,
-                Some(service_pair.unwrap()),
-            )?;
-            encrypted = true;
+    fn fetch_history(
+        sg: &ServiceGroup,
+        remote_sup: &str,
+        auth_token: Option<String>,
+    ) -> Result<Vec<ConfigVersion>> {
+        let mut url = format!("{}/services/{}/{}", remote_sup, sg.service(), sg.group());
+        if let Some(org) = sg.org() {
+            url.push_str("/");
+            url.push_str(org);
         }
+        url.push_str("/config/history");
 
-        for peer in peers.iter() {
-            ui.status(Status::Applying, format!("to peer {}", peer))?;
-            let mut client = Client::new(peer, ring_key.map(|k| k.clone())).map_err(
-                |e| {
-                    Error::ButterflyError(format!("{}", e))
-                },
-            )?;
-            client
-                .send_service_config(sg.clone(), number, body.clone(), encrypted)
-                .map_err(|e| Error::ButterflyError(format!("{}", e)))?;
-
-            // please take a moment to weep over the following line
-            // of code. We must sleep to allow messages to be sent
-            // before freeing the socket to prevent loss.
-            // see https://github.com/zeromq/libzmq/issues/1264
-            thread::sleep(time::Duration::from_millis(100));
+        let ssl = OpensslClient::new().map_err(|e| {
+            Error::SupervisorGateway(format!("{}: {}", url, e))
+        })?;
+        let client = HttpClient::with_connector(HttpsConnector::new(ssl));
+        let mut request = client.get(&url);
+        if let Some(token) = auth_token {
+            request = request.header(Authorization(Bearer { token: token }));
         }
-        ui.end("Applied configuration")?;
+        let response = request.send().map_err(|e| {
+            Error::SupervisorGateway(format!("{}: {}", url, e))
+        })?;
+        match response.status {
+            StatusCode::Ok => {
+                serde_json::from_reader(response).map_err(|e| {
+                    Error::SupervisorGateway(format!("{}: {}", url, e))
+                })
+            }
+            StatusCode::NotFound => Err(Error::SupervisorGateway(
+                format!("{} is not in the census of {}", sg, remote_sup),
+            )),
+            status => Err(Error::SupervisorGateway(format!("{}: {}", url, status))),
+        }
+    }
+}
+
+pub mod encrypt {
+    use std::io::{self, Read};
+
+    use common::config_secret;
+    use hcore::crypto::BoxKeyPair;
+
+    use error::Result;
+
+    /// Print the value, or stdin when none is given, as a secret for use in configuration.
+    pub fn start(
+        value: Option<&str>,
+        user_pair: &BoxKeyPair,
+        service_pair: &BoxKeyPair,
+    ) -> Result<()> {
+        let value = match value {
+            Some(value) => value.to_string(),
+            None => {
+                let mut value = String::new();
+                io::stdin().read_to_string(&mut value)?;
+                value.trim_right_matches(|c| c == '\r' || c == '\n').to_string()
+            }
+        };
+        println!("{}", config_secret::encrypt(&value, user_pair, service_pair)?);
         Ok(())
     }
 }
@@ -22,7 +22,13 @@ extern crate habitat_butterfly as butterfly;
 
 #[macro_use]
 extern crate clap;
+extern crate hyper;
+extern crate hyper_openssl;
 extern crate log;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
 extern crate toml;
 
 pub use hab::config;
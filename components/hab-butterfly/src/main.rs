@@ -48,6 +48,9 @@ const HABITAT_ORG_ENVVAR: &'static str = "HAB_ORG";
 /// Makes the --user CLI param optional when this env var is set
 const HABITAT_USER_ENVVAR: &'static str = "HAB_USER";
 const HABITAT_BUTTERFLY_PORT: u64 = 9638;
+const HABITAT_HTTP_GATEWAY_PORT: u64 = 9631;
+/// Token sent to the Supervisor's HTTP gateway, if it requires one
+const HTTP_AUTH_TOKEN_ENVVAR: &'static str = "HAB_SUP_GATEWAY_AUTH_TOKEN";
 const MAX_FILE_UPLOAD_SIZE_BYTES: u64 = 4096;
 
 lazy_static! {
@@ -88,6 +91,8 @@ fn start(ui: &mut UI) -> Result<()> {
         ("config", Some(matches)) => {
             match matches.subcommand() {
                 ("apply", Some(m)) => sub_config_apply(ui, m)?,
+                ("rollback", Some(m)) => sub_config_rollback(ui, m)?,
+                ("encrypt", Some(m)) => sub_config_encrypt(m)?,
                 _ => unreachable!(),
             }
         }
@@ -139,6 +144,7 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         Some("-") | None => None,
         Some(p) => Some(Path::new(p)),
     };
+    let schema_path = m.value_of("SCHEMA").map(Path::new);
 
     init();
     let cache = default_cache_key_path(Some(&*FS_ROOT));
@@ -165,6 +171,7 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         &sg,
         number,
         file_path,
+        schema_path,
         &peers,
         ring_key.as_ref(),
         user_pair.as_ref(),
@@ -172,6 +179,84 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     )
 }
 
+fn sub_config_rollback(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+    let remote_sup = m.value_of("REMOTE_SUP").unwrap_or("127.0.0.1");
+    let (scheme, mut remote_sup) = match remote_sup.find("://") {
+        Some(i) => (&remote_sup[..i], remote_sup[i + 3..].to_string()),
+        None => ("http", remote_sup.to_string()),
+    };
+    if remote_sup.find(':').is_none() {
+        remote_sup.push(':');
+        remote_sup.push_str(&HABITAT_HTTP_GATEWAY_PORT.to_string());
+    }
+    let remote_sup = format!("{}://{}", scheme, remote_sup);
+    let number = value_t!(m, "VERSION_NUMBER", u64).unwrap_or_else(|e| e.exit());
+
+    init();
+    let cache = default_cache_key_path(Some(&*FS_ROOT));
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(SymKey::get_latest_pair_for(&name, &cache)?),
+        None => None,
+    };
+
+    let mut sg = ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap())?;
+    if let Some(org) = org_param_or_env(&m) {
+        sg.set_org(org);
+    }
+    let service_pair = if sg.org().is_some() {
+        Some(BoxKeyPair::get_latest_pair_for(&sg, &cache)?)
+    } else {
+        None
+    };
+    let user_pair = match user_param_or_env(&m) {
+        Some(username) => Some(BoxKeyPair::get_latest_pair_for(username, &cache)?),
+        None => None,
+    };
+    command::config::rollback::start(
+        ui,
+        &sg,
+        number,
+        &remote_sup,
+        henv::var(HTTP_AUTH_TOKEN_ENVVAR).ok(),
+        &peers,
+        ring_key.as_ref(),
+        user_pair.as_ref(),
+        service_pair.as_ref(),
+    )
+}
+
+fn sub_config_encrypt(m: &ArgMatches) -> Result<()> {
+    init();
+    let cache = default_cache_key_path(Some(&*FS_ROOT));
+    let mut sg = ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap())?;
+    match org_param_or_env(&m) {
+        Some(org) => sg.set_org(org),
+        None => {
+            return Err(Error::CryptoCLI(
+                "An organization is required to encrypt a secret for a service group".to_string(),
+            ))
+        }
+    }
+    let service_pair = BoxKeyPair::get_latest_pair_for(&sg, &cache)?;
+    let user_pair = match user_param_or_env(&m) {
+        Some(username) => BoxKeyPair::get_latest_pair_for(username, &cache)?,
+        None => {
+            return Err(Error::CryptoCLI(
+                "A user key is required to encrypt a secret for a service group".to_string(),
+            ))
+        }
+    };
+    command::config::encrypt::start(m.value_of("VALUE"), &user_pair, &service_pair)
+}
+
 fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
     let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
@@ -53,6 +53,8 @@ pub fn get() -> App<'static, 'static> {
             (aliases: &["co", "con", "conf", "confi"])
             (@setting ArgRequiredElseHelp)
             (subcommand: sub_config_apply().aliases(&["a", "ap", "app", "appl"]))
+            (subcommand: sub_config_rollback().aliases(&["r", "ro", "rol", "roll"]))
+            (subcommand: sub_config_encrypt().aliases(&["e", "en", "enc", "encr"]))
         )
         (@subcommand file =>
             (about: "Commands relating to Habitat files")
@@ -530,10 +532,45 @@ fn sub_config_apply() -> App<'static, 'static> {
             "A version number (positive integer) for this configuration (ex: 42)")
         (@arg FILE: {file_exists_or_stdin}
             "Path to local file on disk (ex: /tmp/config.toml, default: <stdin>)")
+        (@arg SCHEMA: --schema +takes_value {file_exists}
+            "Path to a config_schema.toml the configuration must match before it is applied")
         (@arg ORG: --org +takes_value "Name of service organization")
     )
 }
 
+fn sub_config_rollback() -> App<'static, 'static> {
+    clap_app!(@subcommand rollback =>
+        (about: "Re-applies an earlier configuration of a group of Habitat Supervisors as a new \
+            incarnation")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+        (@arg REMOTE_SUP: --("remote-sup") +takes_value
+            "Address of the HTTP gateway of a Supervisor running the service group, whose \
+            configuration history is used. The history only covers configurations applied \
+            since that Supervisor started (default: 127.0.0.1:9631)")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg VERSION_NUMBER: +required
+            "The version number of the earlier configuration to re-apply (ex: 41)")
+        (@arg ORG: --org +takes_value "Name of service organization")
+    )
+}
+
+fn sub_config_encrypt() -> App<'static, 'static> {
+    clap_app!(@subcommand encrypt =>
+        (about: "Encrypts a secret configuration value for a service group, to be decrypted only \
+            by its Supervisors when rendering templates and hooks")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg VALUE: "The value to encrypt (default: <stdin>)")
+        (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg USER: -u --user +takes_value "Name of the user key")
+    )
+}
+
 fn sub_pkg_build() -> App<'static, 'static> {
     let sub = clap_app!(@subcommand build =>
         (about: "Builds a Plan using a Studio")
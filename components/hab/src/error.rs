@@ -38,6 +38,8 @@ pub enum Error {
     ButterflyError(String),
     CannotRemoveFromChannel((String, String)),
     CommandNotFoundInPkg((String, String)),
+    ConfigIncarnationNotFound(String, u64),
+    ConfigIncarnationRedacted(String, u64),
     CryptoCLI(String),
     DepotClient(depot_client::Error),
     DockerDaemonDown,
@@ -60,6 +62,7 @@ pub enum Error {
     ProvidesError(String),
     RootRequired,
     SubcommandNotSupported(String),
+    SupervisorGateway(String),
     UnsupportedExportFormat(String),
     TomlDeserializeError(toml::de::Error),
     TomlSerializeError(toml::ser::Error),
@@ -82,6 +85,21 @@ impl fmt::Display for Error {
                     p
                 )
             }
+            Error::ConfigIncarnationNotFound(ref sg, incarnation) => {
+                format!(
+                    "No configuration with incarnation {} in the history of {}",
+                    incarnation,
+                    sg
+                )
+            }
+            Error::ConfigIncarnationRedacted(ref sg, incarnation) => {
+                format!(
+                    "Configuration incarnation {} of {} holds secrets, which are redacted from \
+                     the history. Apply it again with `hab config apply` instead.",
+                    incarnation,
+                    sg
+                )
+            }
             Error::CryptoCLI(ref e) => format!("{}", e),
             Error::DepotClient(ref err) => format!("{}", err),
             Error::DockerDaemonDown => {
@@ -151,6 +169,7 @@ impl fmt::Display for Error {
             Error::SubcommandNotSupported(ref e) => {
                 format!("Subcommand `{}' not supported on this operating system", e)
             }
+            Error::SupervisorGateway(ref e) => format!("Supervisor HTTP gateway error: {}", e),
             Error::UnsupportedExportFormat(ref e) => format!("Unsupported export format: {}", e),
             Error::TomlDeserializeError(ref e) => format!("Can't deserialize TOML: {}", e),
             Error::TomlSerializeError(ref e) => format!("Can't serialize TOML: {}", e),
@@ -172,6 +191,12 @@ impl error::Error for Error {
             Error::CommandNotFoundInPkg(_) => {
                 "Command was not found under any 'PATH' directories in the package"
             }
+            Error::ConfigIncarnationNotFound(_, _) => {
+                "Configuration incarnation not found in the service group's history"
+            }
+            Error::ConfigIncarnationRedacted(_, _) => {
+                "Configuration incarnation holds secrets redacted from the history"
+            }
             Error::CryptoCLI(_) => "A cryptographic error has occurred",
             Error::DepotClient(ref err) => err.description(),
             Error::DockerDaemonDown => "The Docker daemon could not be found.",
@@ -202,6 +227,7 @@ impl error::Error for Error {
                 "Root or administrator permissions required to complete operation"
             }
             Error::SubcommandNotSupported(_) => "Subcommand not supported on this operating system",
+            Error::SupervisorGateway(_) => "The Supervisor HTTP gateway request failed",
             Error::UnsupportedExportFormat(_) => "Unsupported export format",
             Error::TomlDeserializeError(_) => "Can't deserialize TOML",
             Error::TomlSerializeError(_) => "Can't serialize TOML",
@@ -17,6 +17,7 @@ use std::io;
 use std::path::Path;
 
 use core::os::process::Pid;
+use core::util::rotating_log::RotationPolicy;
 use ipc_channel::ipc::{IpcOneShotServer, IpcReceiver, IpcSender};
 use protobuf;
 use protocol;
@@ -26,9 +27,34 @@ use error::{Error, Result};
 type Env = HashMap<String, String>;
 type IpcServer = IpcOneShotServer<Vec<u8>>;
 
+/// Limits on the resources a spawned process and its descendants may use. Nothing is limited
+/// if a value is not set.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResourceLimits {
+    /// Bytes of memory
+    pub memory: Option<u64>,
+    /// Thousandths of a CPU
+    pub cpu: Option<u32>,
+    /// Number of processes and threads
+    pub pids: Option<u64>,
+}
+
+/// Resources used by a process and its descendants, where the Launcher is able to tell.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResourceUsage {
+    /// Bytes of memory
+    pub memory: Option<u64>,
+    /// Nanoseconds of CPU time
+    pub cpu: Option<u64>,
+    /// Number of processes and threads
+    pub pids: Option<u64>,
+}
+
 pub struct LauncherCli {
     tx: IpcSender<Vec<u8>>,
     rx: IpcReceiver<Vec<u8>>,
+    /// Protocol version of the connected Launcher
+    version: u32,
 }
 
 impl LauncherCli {
@@ -39,8 +65,26 @@ impl LauncherCli {
         cmd.set_pipe(pipe);
         Self::send(&tx, &cmd)?;
         let (rx, raw) = ipc_srv.accept().map_err(|_| Error::AcceptConn)?;
-        Self::read::<protocol::NetOk>(&raw)?;
-        Ok(LauncherCli { tx: tx, rx: rx })
+        let version = Self::read_version(&raw)?;
+        Ok(LauncherCli {
+            tx: tx,
+            rx: rx,
+            version: version,
+        })
+    }
+
+    /// Read the protocol version from the Launcher's reply to `Register`
+    ///
+    /// A Launcher which predates versioning replies with `NetOk` and is treated as version 0.
+    fn read_version(bytes: &[u8]) -> Result<u32> {
+        let txn = protocol::NetTxn::from_bytes(bytes).map_err(
+            Error::Deserialize,
+        )?;
+        if txn.message_id() == "NetOk" {
+            return Ok(0);
+        }
+        let reply = Self::read::<protocol::RegisterOk>(bytes)?;
+        Ok(reply.get_version())
     }
 
     /// Read a launcher protocol message from a byte array
@@ -96,6 +140,60 @@ impl LauncherCli {
         }
     }
 
+    /// Protocol version of the connected Launcher
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Retrieve the exit code of a process which has exited on its own
+    ///
+    /// Returns `None` if the process was terminated by a signal or if the Launcher is too old to
+    /// know the exit code.
+    pub fn exit_status(&self, pid: Pid) -> Result<Option<i32>> {
+        if self.version < 1 {
+            return Ok(None);
+        }
+        let mut msg = protocol::ExitStatus::new();
+        msg.set_pid(pid.into());
+        Self::send(&self.tx, &msg)?;
+        let reply = Self::recv::<protocol::ExitStatusOk>(&self.rx)?;
+        if reply.has_exit_code() {
+            Ok(Some(reply.get_exit_code()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Retrieve the resource usage of a running process
+    ///
+    /// Nothing is reported if the Launcher is too old to measure usage.
+    pub fn resource_usage(&self, pid: Pid) -> Result<ResourceUsage> {
+        if self.version < 1 {
+            return Ok(ResourceUsage::default());
+        }
+        let mut msg = protocol::ResourceUsage::new();
+        msg.set_pid(pid.into());
+        Self::send(&self.tx, &msg)?;
+        let reply = Self::recv::<protocol::ResourceUsageOk>(&self.rx)?;
+        Ok(ResourceUsage {
+            memory: if reply.has_memory_usage() {
+                Some(reply.get_memory_usage())
+            } else {
+                None
+            },
+            cpu: if reply.has_cpu_usage() {
+                Some(reply.get_cpu_usage())
+            } else {
+                None
+            },
+            pids: if reply.has_pids() {
+                Some(reply.get_pids())
+            } else {
+                None
+            },
+        })
+    }
+
     pub fn is_stopping(&self) -> bool {
         match Self::try_recv::<protocol::Shutdown>(&self.rx) {
             Ok(Some(_)) |
@@ -115,7 +213,12 @@ impl LauncherCli {
     }
 
     /// Send a process spawn command to the connected Launcher
-    pub fn spawn<I, B, U, G, P>(
+    ///
+    /// The process' standard output and standard error are appended to `stdout_log` and
+    /// `stderr_log`, which are rotated according to `logs`, in addition to being written to the
+    /// Launcher's own output. On Linux the process is placed in a cgroup which enforces the given
+    /// resource limits.
+    pub fn spawn<I, B, U, G, P, L>(
         &self,
         id: I,
         bin: B,
@@ -123,6 +226,10 @@ impl LauncherCli {
         group: G,
         password: Option<P>,
         env: Env,
+        stdout_log: L,
+        stderr_log: L,
+        logs: &RotationPolicy,
+        limits: &ResourceLimits,
     ) -> Result<Pid>
     where
         I: ToString,
@@ -130,6 +237,7 @@ impl LauncherCli {
         U: ToString,
         G: ToString,
         P: ToString,
+        L: AsRef<Path>,
     {
         let mut msg = protocol::Spawn::new();
         msg.set_binary(bin.as_ref().to_path_buf().to_string_lossy().into_owned());
@@ -140,6 +248,22 @@ impl LauncherCli {
         }
         msg.set_env(env);
         msg.set_id(id.to_string());
+        msg.set_stdout_log(stdout_log.as_ref().to_string_lossy().into_owned());
+        msg.set_stderr_log(stderr_log.as_ref().to_string_lossy().into_owned());
+        msg.set_log_max_size(logs.max_size);
+        if let Some(age) = logs.max_age {
+            msg.set_log_max_age(age);
+        }
+        msg.set_log_keep(logs.keep);
+        if let Some(memory) = limits.memory {
+            msg.set_memory_limit(memory);
+        }
+        if let Some(cpu) = limits.cpu {
+            msg.set_cpu_limit(cpu);
+        }
+        if let Some(pids) = limits.pids {
+            msg.set_pids_limit(pids);
+        }
         Self::send(&self.tx, &msg)?;
         let reply = Self::recv::<protocol::SpawnOk>(&self.rx)?;
         Ok(reply.get_pid() as Pid)
@@ -37,6 +37,11 @@ pub const OK_NO_RETRY_EXCODE: i32 = 84;
 /// Same as `OK_NO_RETRY_EXCODE` except the Supervisor ran to completion with an unsuccessful
 /// exit code. The Launcher should exit immediately with a non-zero exit code.
 pub const ERR_NO_RETRY_EXCODE: i32 = 86;
+/// Version of the protocol spoken by this Launcher, sent to a Supervisor when it registers. A
+/// Launcher which predates versioning replies to `Register` with `NetOk` and is version 0.
+///
+/// Version 1 added the `ExitStatus` and `ResourceUsage` messages and the `UnknownMessage` error.
+pub const PROTOCOL_VERSION: u32 = 1;
 
 pub struct NetTxn(Envelope);
 
@@ -386,6 +386,7 @@ pub enum ErrCode {
     UserNotFound = 2,
     ExecWait = 3,
     NoPID = 4,
+    UnknownMessage = 5,
 }
 
 impl ::protobuf::ProtobufEnum for ErrCode {
@@ -400,6 +401,7 @@ impl ::protobuf::ProtobufEnum for ErrCode {
             2 => ::std::option::Option::Some(ErrCode::UserNotFound),
             3 => ::std::option::Option::Some(ErrCode::ExecWait),
             4 => ::std::option::Option::Some(ErrCode::NoPID),
+            5 => ::std::option::Option::Some(ErrCode::UnknownMessage),
             _ => ::std::option::Option::None
         }
     }
@@ -411,6 +413,7 @@ impl ::protobuf::ProtobufEnum for ErrCode {
             ErrCode::UserNotFound,
             ErrCode::ExecWait,
             ErrCode::NoPID,
+            ErrCode::UnknownMessage,
         ];
         values
     }
@@ -440,30 +443,33 @@ impl ::protobuf::reflect::ProtobufValue for ErrCode {
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x15protocols/error.proto\x12\x05error\">\n\x06NetErr\x12\"\n\x04code\
     \x18\x01\x20\x01(\x0e2\x0e.error.ErrCodeR\x04code\x12\x10\n\x03msg\x18\
-    \x02\x20\x01(\tR\x03msg\"\x07\n\x05NetOk*T\n\x07ErrCode\x12\x0b\n\x07Unk\
+    \x02\x20\x01(\tR\x03msg\"\x07\n\x05NetOk*h\n\x07ErrCode\x12\x0b\n\x07Unk\
     nown\x10\0\x12\x11\n\rGroupNotFound\x10\x01\x12\x10\n\x0cUserNotFound\
-    \x10\x02\x12\x0c\n\x08ExecWait\x10\x03\x12\t\n\x05NoPID\x10\x04J\xba\x03\
-    \n\x06\x12\x04\0\0\x11\x10\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\
-    \x02\x12\x03\x02\x08\r\n\n\n\x02\x05\0\x12\x04\x04\0\n\x01\n\n\n\x03\x05\
-    \0\x01\x12\x03\x04\x05\x0c\n\x0b\n\x04\x05\0\x02\0\x12\x03\x05\x02\x0e\n\
-    \x0c\n\x05\x05\0\x02\0\x01\x12\x03\x05\x02\t\n\x0c\n\x05\x05\0\x02\0\x02\
-    \x12\x03\x05\x0c\r\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x06\x02\x14\n\x0c\n\
-    \x05\x05\0\x02\x01\x01\x12\x03\x06\x02\x0f\n\x0c\n\x05\x05\0\x02\x01\x02\
-    \x12\x03\x06\x12\x13\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x07\x02\x13\n\x0c\
-    \n\x05\x05\0\x02\x02\x01\x12\x03\x07\x02\x0e\n\x0c\n\x05\x05\0\x02\x02\
-    \x02\x12\x03\x07\x11\x12\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x08\x02\x0f\n\
-    \x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x08\x02\n\n\x0c\n\x05\x05\0\x02\x03\
-    \x02\x12\x03\x08\r\x0e\n\x0b\n\x04\x05\0\x02\x04\x12\x03\t\x02\x0c\n\x0c\
-    \n\x05\x05\0\x02\x04\x01\x12\x03\t\x02\x07\n\x0c\n\x05\x05\0\x02\x04\x02\
-    \x12\x03\t\n\x0b\n\n\n\x02\x04\0\x12\x04\x0c\0\x0f\x01\n\n\n\x03\x04\0\
-    \x01\x12\x03\x0c\x08\x0e\n\x0b\n\x04\x04\0\x02\0\x12\x03\r\x02\x1c\n\x0c\
-    \n\x05\x04\0\x02\0\x04\x12\x03\r\x02\n\n\x0c\n\x05\x04\0\x02\0\x06\x12\
-    \x03\r\x0b\x12\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\r\x13\x17\n\x0c\n\x05\
-    \x04\0\x02\0\x03\x12\x03\r\x1a\x1b\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x0e\
-    \x02\x1a\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03\x0e\x02\n\n\x0c\n\x05\x04\
-    \0\x02\x01\x05\x12\x03\x0e\x0b\x11\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\
-    \x0e\x12\x15\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x0e\x18\x19\n\t\n\x02\
-    \x04\x01\x12\x03\x11\0\x10\n\n\n\x03\x04\x01\x01\x12\x03\x11\x08\r\
+    \x10\x02\x12\x0c\n\x08ExecWait\x10\x03\x12\t\n\x05NoPID\x10\x04\x12\x12\
+    \n\x0eUnknownMessage\x10\x05J\xe3\x03\n\x06\x12\x04\0\0\x12\x10\n\x08\n\
+    \x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x02\x08\r\n\n\n\x02\x05\
+    \0\x12\x04\x04\0\x0b\x01\n\n\n\x03\x05\0\x01\x12\x03\x04\x05\x0c\n\x0b\n\
+    \x04\x05\0\x02\0\x12\x03\x05\x02\x0e\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\
+    \x05\x02\t\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x05\x0c\r\n\x0b\n\x04\x05\
+    \0\x02\x01\x12\x03\x06\x02\x14\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x06\
+    \x02\x0f\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x06\x12\x13\n\x0b\n\x04\
+    \x05\0\x02\x02\x12\x03\x07\x02\x13\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\
+    \x07\x02\x0e\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x07\x11\x12\n\x0b\n\
+    \x04\x05\0\x02\x03\x12\x03\x08\x02\x0f\n\x0c\n\x05\x05\0\x02\x03\x01\x12\
+    \x03\x08\x02\n\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x08\r\x0e\n\x0b\n\
+    \x04\x05\0\x02\x04\x12\x03\t\x02\x0c\n\x0c\n\x05\x05\0\x02\x04\x01\x12\
+    \x03\t\x02\x07\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\t\n\x0b\n\x0b\n\x04\
+    \x05\0\x02\x05\x12\x03\n\x02\x15\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\n\
+    \x02\x10\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\n\x13\x14\n\n\n\x02\x04\0\
+    \x12\x04\r\0\x10\x01\n\n\n\x03\x04\0\x01\x12\x03\r\x08\x0e\n\x0b\n\x04\
+    \x04\0\x02\0\x12\x03\x0e\x02\x1c\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\x0e\
+    \x02\n\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\x0e\x0b\x12\n\x0c\n\x05\x04\0\
+    \x02\0\x01\x12\x03\x0e\x13\x17\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x0e\
+    \x1a\x1b\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x0f\x02\x1a\n\x0c\n\x05\x04\0\
+    \x02\x01\x04\x12\x03\x0f\x02\n\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x0f\
+    \x0b\x11\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x0f\x12\x15\n\x0c\n\x05\
+    \x04\0\x02\x01\x03\x12\x03\x0f\x18\x19\n\t\n\x02\x04\x01\x12\x03\x12\0\
+    \x10\n\n\n\x03\x04\x01\x01\x12\x03\x12\x08\r\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -207,6 +207,179 @@ impl ::protobuf::reflect::ProtobufValue for Register {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct RegisterOk {
+    // message fields
+    version: ::std::option::Option<u32>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for RegisterOk {}
+
+impl RegisterOk {
+    pub fn new() -> RegisterOk {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static RegisterOk {
+        static mut instance: ::protobuf::lazy::Lazy<RegisterOk> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RegisterOk,
+        };
+        unsafe {
+            instance.get(RegisterOk::new)
+        }
+    }
+
+    // optional uint32 version = 1;
+
+    pub fn clear_version(&mut self) {
+        self.version = ::std::option::Option::None;
+    }
+
+    pub fn has_version(&self) -> bool {
+        self.version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: u32) {
+        self.version = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_version(&self) -> u32 {
+        self.version.unwrap_or(0)
+    }
+
+    fn get_version_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.version
+    }
+
+    fn mut_version_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.version
+    }
+}
+
+impl ::protobuf::Message for RegisterOk {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.version = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.version {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.version {
+            os.write_uint32(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for RegisterOk {
+    fn new() -> RegisterOk {
+        RegisterOk::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<RegisterOk>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "version",
+                    RegisterOk::get_version_for_reflect,
+                    RegisterOk::mut_version_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RegisterOk>(
+                    "RegisterOk",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for RegisterOk {
+    fn clear(&mut self) {
+        self.clear_version();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RegisterOk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RegisterOk {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct Restart {
     // message fields
@@ -389,6 +562,14 @@ pub struct Spawn {
     svc_group: ::protobuf::SingularField<::std::string::String>,
     svc_password: ::protobuf::SingularField<::std::string::String>,
     pub env: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    stdout_log: ::protobuf::SingularField<::std::string::String>,
+    stderr_log: ::protobuf::SingularField<::std::string::String>,
+    memory_limit: ::std::option::Option<u64>,
+    cpu_limit: ::std::option::Option<u32>,
+    pids_limit: ::std::option::Option<u64>,
+    log_max_size: ::std::option::Option<u64>,
+    log_max_age: ::std::option::Option<u64>,
+    log_keep: ::std::option::Option<u32>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -664,34 +845,1223 @@ impl Spawn {
     fn mut_env_for_reflect(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
         &mut self.env
     }
-}
 
-impl ::protobuf::Message for Spawn {
-    fn is_initialized(&self) -> bool {
-        true
+    // optional string stdout_log = 7;
+
+    pub fn clear_stdout_log(&mut self) {
+        self.stdout_log.clear();
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.id)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.binary)?;
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.svc_user)?;
-                },
-                4 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.svc_group)?;
-                },
-                5 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.svc_password)?;
-                },
-                6 => {
-                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(wire_type, is, &mut self.env)?;
+    pub fn has_stdout_log(&self) -> bool {
+        self.stdout_log.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stdout_log(&mut self, v: ::std::string::String) {
+        self.stdout_log = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_stdout_log(&mut self) -> &mut ::std::string::String {
+        if self.stdout_log.is_none() {
+            self.stdout_log.set_default();
+        }
+        self.stdout_log.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_stdout_log(&mut self) -> ::std::string::String {
+        self.stdout_log.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_stdout_log(&self) -> &str {
+        match self.stdout_log.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_stdout_log_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.stdout_log
+    }
+
+    fn mut_stdout_log_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.stdout_log
+    }
+
+    // optional string stderr_log = 8;
+
+    pub fn clear_stderr_log(&mut self) {
+        self.stderr_log.clear();
+    }
+
+    pub fn has_stderr_log(&self) -> bool {
+        self.stderr_log.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stderr_log(&mut self, v: ::std::string::String) {
+        self.stderr_log = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_stderr_log(&mut self) -> &mut ::std::string::String {
+        if self.stderr_log.is_none() {
+            self.stderr_log.set_default();
+        }
+        self.stderr_log.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_stderr_log(&mut self) -> ::std::string::String {
+        self.stderr_log.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_stderr_log(&self) -> &str {
+        match self.stderr_log.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_stderr_log_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.stderr_log
+    }
+
+    fn mut_stderr_log_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.stderr_log
+    }
+
+    // optional uint64 memory_limit = 9;
+
+    pub fn clear_memory_limit(&mut self) {
+        self.memory_limit = ::std::option::Option::None;
+    }
+
+    pub fn has_memory_limit(&self) -> bool {
+        self.memory_limit.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_memory_limit(&mut self, v: u64) {
+        self.memory_limit = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_memory_limit(&self) -> u64 {
+        self.memory_limit.unwrap_or(0)
+    }
+
+    fn get_memory_limit_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.memory_limit
+    }
+
+    fn mut_memory_limit_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.memory_limit
+    }
+
+    // optional uint32 cpu_limit = 10;
+
+    pub fn clear_cpu_limit(&mut self) {
+        self.cpu_limit = ::std::option::Option::None;
+    }
+
+    pub fn has_cpu_limit(&self) -> bool {
+        self.cpu_limit.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_limit(&mut self, v: u32) {
+        self.cpu_limit = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_cpu_limit(&self) -> u32 {
+        self.cpu_limit.unwrap_or(0)
+    }
+
+    fn get_cpu_limit_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.cpu_limit
+    }
+
+    fn mut_cpu_limit_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.cpu_limit
+    }
+
+    // optional uint64 pids_limit = 11;
+
+    pub fn clear_pids_limit(&mut self) {
+        self.pids_limit = ::std::option::Option::None;
+    }
+
+    pub fn has_pids_limit(&self) -> bool {
+        self.pids_limit.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pids_limit(&mut self, v: u64) {
+        self.pids_limit = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_pids_limit(&self) -> u64 {
+        self.pids_limit.unwrap_or(0)
+    }
+
+    fn get_pids_limit_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.pids_limit
+    }
+
+    fn mut_pids_limit_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.pids_limit
+    }
+
+    // optional uint64 log_max_size = 12;
+
+    pub fn clear_log_max_size(&mut self) {
+        self.log_max_size = ::std::option::Option::None;
+    }
+
+    pub fn has_log_max_size(&self) -> bool {
+        self.log_max_size.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_log_max_size(&mut self, v: u64) {
+        self.log_max_size = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_log_max_size(&self) -> u64 {
+        self.log_max_size.unwrap_or(0)
+    }
+
+    fn get_log_max_size_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.log_max_size
+    }
+
+    fn mut_log_max_size_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.log_max_size
+    }
+
+    // optional uint64 log_max_age = 13;
+
+    pub fn clear_log_max_age(&mut self) {
+        self.log_max_age = ::std::option::Option::None;
+    }
+
+    pub fn has_log_max_age(&self) -> bool {
+        self.log_max_age.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_log_max_age(&mut self, v: u64) {
+        self.log_max_age = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_log_max_age(&self) -> u64 {
+        self.log_max_age.unwrap_or(0)
+    }
+
+    fn get_log_max_age_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.log_max_age
+    }
+
+    fn mut_log_max_age_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.log_max_age
+    }
+
+    // optional uint32 log_keep = 14;
+
+    pub fn clear_log_keep(&mut self) {
+        self.log_keep = ::std::option::Option::None;
+    }
+
+    pub fn has_log_keep(&self) -> bool {
+        self.log_keep.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_log_keep(&mut self, v: u32) {
+        self.log_keep = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_log_keep(&self) -> u32 {
+        self.log_keep.unwrap_or(0)
+    }
+
+    fn get_log_keep_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.log_keep
+    }
+
+    fn mut_log_keep_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.log_keep
+    }
+}
+
+impl ::protobuf::Message for Spawn {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.binary)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.svc_user)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.svc_group)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.svc_password)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(wire_type, is, &mut self.env)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.stdout_log)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.stderr_log)?;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.memory_limit = ::std::option::Option::Some(tmp);
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.cpu_limit = ::std::option::Option::Some(tmp);
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.pids_limit = ::std::option::Option::Some(tmp);
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.log_max_size = ::std::option::Option::Some(tmp);
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.log_max_age = ::std::option::Option::Some(tmp);
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.log_keep = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.id.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.binary.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.svc_user.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(ref v) = self.svc_group.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        if let Some(ref v) = self.svc_password.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        }
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(6, &self.env);
+        if let Some(ref v) = self.stdout_log.as_ref() {
+            my_size += ::protobuf::rt::string_size(7, &v);
+        }
+        if let Some(ref v) = self.stderr_log.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v);
+        }
+        if let Some(v) = self.memory_limit {
+            my_size += ::protobuf::rt::value_size(9, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.cpu_limit {
+            my_size += ::protobuf::rt::value_size(10, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.pids_limit {
+            my_size += ::protobuf::rt::value_size(11, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.log_max_size {
+            my_size += ::protobuf::rt::value_size(12, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.log_max_age {
+            my_size += ::protobuf::rt::value_size(13, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.log_keep {
+            my_size += ::protobuf::rt::value_size(14, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.id.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.binary.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.svc_user.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(ref v) = self.svc_group.as_ref() {
+            os.write_string(4, &v)?;
+        }
+        if let Some(ref v) = self.svc_password.as_ref() {
+            os.write_string(5, &v)?;
+        }
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(6, &self.env, os)?;
+        if let Some(ref v) = self.stdout_log.as_ref() {
+            os.write_string(7, &v)?;
+        }
+        if let Some(ref v) = self.stderr_log.as_ref() {
+            os.write_string(8, &v)?;
+        }
+        if let Some(v) = self.memory_limit {
+            os.write_uint64(9, v)?;
+        }
+        if let Some(v) = self.cpu_limit {
+            os.write_uint32(10, v)?;
+        }
+        if let Some(v) = self.pids_limit {
+            os.write_uint64(11, v)?;
+        }
+        if let Some(v) = self.log_max_size {
+            os.write_uint64(12, v)?;
+        }
+        if let Some(v) = self.log_max_age {
+            os.write_uint64(13, v)?;
+        }
+        if let Some(v) = self.log_keep {
+            os.write_uint32(14, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Spawn {
+    fn new() -> Spawn {
+        Spawn::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Spawn>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "id",
+                    Spawn::get_id_for_reflect,
+                    Spawn::mut_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "binary",
+                    Spawn::get_binary_for_reflect,
+                    Spawn::mut_binary_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "svc_user",
+                    Spawn::get_svc_user_for_reflect,
+                    Spawn::mut_svc_user_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "svc_group",
+                    Spawn::get_svc_group_for_reflect,
+                    Spawn::mut_svc_group_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "svc_password",
+                    Spawn::get_svc_password_for_reflect,
+                    Spawn::mut_svc_password_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(
+                    "env",
+                    Spawn::get_env_for_reflect,
+                    Spawn::mut_env_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "stdout_log",
+                    Spawn::get_stdout_log_for_reflect,
+                    Spawn::mut_stdout_log_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "stderr_log",
+                    Spawn::get_stderr_log_for_reflect,
+                    Spawn::mut_stderr_log_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "memory_limit",
+                    Spawn::get_memory_limit_for_reflect,
+                    Spawn::mut_memory_limit_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "cpu_limit",
+                    Spawn::get_cpu_limit_for_reflect,
+                    Spawn::mut_cpu_limit_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "pids_limit",
+                    Spawn::get_pids_limit_for_reflect,
+                    Spawn::mut_pids_limit_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "log_max_size",
+                    Spawn::get_log_max_size_for_reflect,
+                    Spawn::mut_log_max_size_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "log_max_age",
+                    Spawn::get_log_max_age_for_reflect,
+                    Spawn::mut_log_max_age_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "log_keep",
+                    Spawn::get_log_keep_for_reflect,
+                    Spawn::mut_log_keep_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Spawn>(
+                    "Spawn",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Spawn {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_binary();
+        self.clear_svc_user();
+        self.clear_svc_group();
+        self.clear_svc_password();
+        self.clear_env();
+        self.clear_stdout_log();
+        self.clear_stderr_log();
+        self.clear_memory_limit();
+        self.clear_cpu_limit();
+        self.clear_pids_limit();
+        self.clear_log_max_size();
+        self.clear_log_max_age();
+        self.clear_log_keep();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Spawn {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Spawn {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SpawnOk {
+    // message fields
+    pid: ::std::option::Option<i64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SpawnOk {}
+
+impl SpawnOk {
+    pub fn new() -> SpawnOk {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static SpawnOk {
+        static mut instance: ::protobuf::lazy::Lazy<SpawnOk> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SpawnOk,
+        };
+        unsafe {
+            instance.get(SpawnOk::new)
+        }
+    }
+
+    // optional int64 pid = 1;
+
+    pub fn clear_pid(&mut self) {
+        self.pid = ::std::option::Option::None;
+    }
+
+    pub fn has_pid(&self) -> bool {
+        self.pid.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pid(&mut self, v: i64) {
+        self.pid = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_pid(&self) -> i64 {
+        self.pid.unwrap_or(0)
+    }
+
+    fn get_pid_for_reflect(&self) -> &::std::option::Option<i64> {
+        &self.pid
+    }
+
+    fn mut_pid_for_reflect(&mut self) -> &mut ::std::option::Option<i64> {
+        &mut self.pid
+    }
+}
+
+impl ::protobuf::Message for SpawnOk {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.pid = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.pid {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.pid {
+            os.write_int64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for SpawnOk {
+    fn new() -> SpawnOk {
+        SpawnOk::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<SpawnOk>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "pid",
+                    SpawnOk::get_pid_for_reflect,
+                    SpawnOk::mut_pid_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SpawnOk>(
+                    "SpawnOk",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for SpawnOk {
+    fn clear(&mut self) {
+        self.clear_pid();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SpawnOk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SpawnOk {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Terminate {
+    // message fields
+    pid: ::std::option::Option<i64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Terminate {}
+
+impl Terminate {
+    pub fn new() -> Terminate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Terminate {
+        static mut instance: ::protobuf::lazy::Lazy<Terminate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Terminate,
+        };
+        unsafe {
+            instance.get(Terminate::new)
+        }
+    }
+
+    // optional int64 pid = 1;
+
+    pub fn clear_pid(&mut self) {
+        self.pid = ::std::option::Option::None;
+    }
+
+    pub fn has_pid(&self) -> bool {
+        self.pid.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pid(&mut self, v: i64) {
+        self.pid = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_pid(&self) -> i64 {
+        self.pid.unwrap_or(0)
+    }
+
+    fn get_pid_for_reflect(&self) -> &::std::option::Option<i64> {
+        &self.pid
+    }
+
+    fn mut_pid_for_reflect(&mut self) -> &mut ::std::option::Option<i64> {
+        &mut self.pid
+    }
+}
+
+impl ::protobuf::Message for Terminate {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.pid = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.pid {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.pid {
+            os.write_int64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Terminate {
+    fn new() -> Terminate {
+        Terminate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Terminate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "pid",
+                    Terminate::get_pid_for_reflect,
+                    Terminate::mut_pid_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Terminate>(
+                    "Terminate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Terminate {
+    fn clear(&mut self) {
+        self.clear_pid();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Terminate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Terminate {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TerminateOk {
+    // message fields
+    exit_code: ::std::option::Option<i32>,
+    shutdown_method: ::std::option::Option<ShutdownMethod>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for TerminateOk {}
+
+impl TerminateOk {
+    pub fn new() -> TerminateOk {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static TerminateOk {
+        static mut instance: ::protobuf::lazy::Lazy<TerminateOk> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const TerminateOk,
+        };
+        unsafe {
+            instance.get(TerminateOk::new)
+        }
+    }
+
+    // optional int32 exit_code = 1;
+
+    pub fn clear_exit_code(&mut self) {
+        self.exit_code = ::std::option::Option::None;
+    }
+
+    pub fn has_exit_code(&self) -> bool {
+        self.exit_code.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exit_code(&mut self, v: i32) {
+        self.exit_code = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_exit_code(&self) -> i32 {
+        self.exit_code.unwrap_or(0)
+    }
+
+    fn get_exit_code_for_reflect(&self) -> &::std::option::Option<i32> {
+        &self.exit_code
+    }
+
+    fn mut_exit_code_for_reflect(&mut self) -> &mut ::std::option::Option<i32> {
+        &mut self.exit_code
+    }
+
+    // optional .launcher.ShutdownMethod shutdown_method = 2;
+
+    pub fn clear_shutdown_method(&mut self) {
+        self.shutdown_method = ::std::option::Option::None;
+    }
+
+    pub fn has_shutdown_method(&self) -> bool {
+        self.shutdown_method.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shutdown_method(&mut self, v: ShutdownMethod) {
+        self.shutdown_method = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_shutdown_method(&self) -> ShutdownMethod {
+        self.shutdown_method.unwrap_or(ShutdownMethod::AlreadyExited)
+    }
+
+    fn get_shutdown_method_for_reflect(&self) -> &::std::option::Option<ShutdownMethod> {
+        &self.shutdown_method
+    }
+
+    fn mut_shutdown_method_for_reflect(&mut self) -> &mut ::std::option::Option<ShutdownMethod> {
+        &mut self.shutdown_method
+    }
+}
+
+impl ::protobuf::Message for TerminateOk {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.exit_code = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.shutdown_method = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.exit_code {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.shutdown_method {
+            my_size += ::protobuf::rt::enum_size(2, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.exit_code {
+            os.write_int32(1, v)?;
+        }
+        if let Some(v) = self.shutdown_method {
+            os.write_enum(2, v.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for TerminateOk {
+    fn new() -> TerminateOk {
+        TerminateOk::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<TerminateOk>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "exit_code",
+                    TerminateOk::get_exit_code_for_reflect,
+                    TerminateOk::mut_exit_code_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ShutdownMethod>>(
+                    "shutdown_method",
+                    TerminateOk::get_shutdown_method_for_reflect,
+                    TerminateOk::mut_shutdown_method_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TerminateOk>(
+                    "TerminateOk",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for TerminateOk {
+    fn clear(&mut self) {
+        self.clear_exit_code();
+        self.clear_shutdown_method();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TerminateOk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TerminateOk {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExitStatus {
+    // message fields
+    pid: ::std::option::Option<i64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ExitStatus {}
+
+impl ExitStatus {
+    pub fn new() -> ExitStatus {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ExitStatus {
+        static mut instance: ::protobuf::lazy::Lazy<ExitStatus> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ExitStatus,
+        };
+        unsafe {
+            instance.get(ExitStatus::new)
+        }
+    }
+
+    // optional int64 pid = 1;
+
+    pub fn clear_pid(&mut self) {
+        self.pid = ::std::option::Option::None;
+    }
+
+    pub fn has_pid(&self) -> bool {
+        self.pid.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pid(&mut self, v: i64) {
+        self.pid = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_pid(&self) -> i64 {
+        self.pid.unwrap_or(0)
+    }
+
+    fn get_pid_for_reflect(&self) -> &::std::option::Option<i64> {
+        &self.pid
+    }
+
+    fn mut_pid_for_reflect(&mut self) -> &mut ::std::option::Option<i64> {
+        &mut self.pid
+    }
+}
+
+impl ::protobuf::Message for ExitStatus {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.pid = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -705,44 +2075,18 @@ impl ::protobuf::Message for Spawn {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.id.as_ref() {
-            my_size += ::protobuf::rt::string_size(1, &v);
-        }
-        if let Some(ref v) = self.binary.as_ref() {
-            my_size += ::protobuf::rt::string_size(2, &v);
-        }
-        if let Some(ref v) = self.svc_user.as_ref() {
-            my_size += ::protobuf::rt::string_size(3, &v);
-        }
-        if let Some(ref v) = self.svc_group.as_ref() {
-            my_size += ::protobuf::rt::string_size(4, &v);
-        }
-        if let Some(ref v) = self.svc_password.as_ref() {
-            my_size += ::protobuf::rt::string_size(5, &v);
+        if let Some(v) = self.pid {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(6, &self.env);
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.id.as_ref() {
-            os.write_string(1, &v)?;
-        }
-        if let Some(ref v) = self.binary.as_ref() {
-            os.write_string(2, &v)?;
-        }
-        if let Some(ref v) = self.svc_user.as_ref() {
-            os.write_string(3, &v)?;
-        }
-        if let Some(ref v) = self.svc_group.as_ref() {
-            os.write_string(4, &v)?;
-        }
-        if let Some(ref v) = self.svc_password.as_ref() {
-            os.write_string(5, &v)?;
+        if let Some(v) = self.pid {
+            os.write_int64(1, v)?;
         }
-        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(6, &self.env, os)?;
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -774,12 +2118,12 @@ impl ::protobuf::Message for Spawn {
     }
 }
 
-impl ::protobuf::MessageStatic for Spawn {
-    fn new() -> Spawn {
-        Spawn::new()
+impl ::protobuf::MessageStatic for ExitStatus {
+    fn new() -> ExitStatus {
+        ExitStatus::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Spawn>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<ExitStatus>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -787,38 +2131,13 @@ impl ::protobuf::MessageStatic for Spawn {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "id",
-                    Spawn::get_id_for_reflect,
-                    Spawn::mut_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "binary",
-                    Spawn::get_binary_for_reflect,
-                    Spawn::mut_binary_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "svc_user",
-                    Spawn::get_svc_user_for_reflect,
-                    Spawn::mut_svc_user_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "svc_group",
-                    Spawn::get_svc_group_for_reflect,
-                    Spawn::mut_svc_group_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "svc_password",
-                    Spawn::get_svc_password_for_reflect,
-                    Spawn::mut_svc_password_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(
-                    "env",
-                    Spawn::get_env_for_reflect,
-                    Spawn::mut_env_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "pid",
+                    ExitStatus::get_pid_for_reflect,
+                    ExitStatus::mut_pid_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Spawn>(
-                    "Spawn",
+                ::protobuf::reflect::MessageDescriptor::new::<ExitStatus>(
+                    "ExitStatus",
                     fields,
                     file_descriptor_proto()
                 )
@@ -827,86 +2146,81 @@ impl ::protobuf::MessageStatic for Spawn {
     }
 }
 
-impl ::protobuf::Clear for Spawn {
+impl ::protobuf::Clear for ExitStatus {
     fn clear(&mut self) {
-        self.clear_id();
-        self.clear_binary();
-        self.clear_svc_user();
-        self.clear_svc_group();
-        self.clear_svc_password();
-        self.clear_env();
+        self.clear_pid();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Spawn {
+impl ::std::fmt::Debug for ExitStatus {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Spawn {
+impl ::protobuf::reflect::ProtobufValue for ExitStatus {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct SpawnOk {
+pub struct ExitStatusOk {
     // message fields
-    pid: ::std::option::Option<i64>,
+    exit_code: ::std::option::Option<i32>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for SpawnOk {}
+unsafe impl ::std::marker::Sync for ExitStatusOk {}
 
-impl SpawnOk {
-    pub fn new() -> SpawnOk {
+impl ExitStatusOk {
+    pub fn new() -> ExitStatusOk {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static SpawnOk {
-        static mut instance: ::protobuf::lazy::Lazy<SpawnOk> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static ExitStatusOk {
+        static mut instance: ::protobuf::lazy::Lazy<ExitStatusOk> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SpawnOk,
+            ptr: 0 as *const ExitStatusOk,
         };
         unsafe {
-            instance.get(SpawnOk::new)
+            instance.get(ExitStatusOk::new)
         }
     }
 
-    // optional int64 pid = 1;
+    // optional int32 exit_code = 1;
 
-    pub fn clear_pid(&mut self) {
-        self.pid = ::std::option::Option::None;
+    pub fn clear_exit_code(&mut self) {
+        self.exit_code = ::std::option::Option::None;
     }
 
-    pub fn has_pid(&self) -> bool {
-        self.pid.is_some()
+    pub fn has_exit_code(&self) -> bool {
+        self.exit_code.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_pid(&mut self, v: i64) {
-        self.pid = ::std::option::Option::Some(v);
+    pub fn set_exit_code(&mut self, v: i32) {
+        self.exit_code = ::std::option::Option::Some(v);
     }
 
-    pub fn get_pid(&self) -> i64 {
-        self.pid.unwrap_or(0)
+    pub fn get_exit_code(&self) -> i32 {
+        self.exit_code.unwrap_or(0)
     }
 
-    fn get_pid_for_reflect(&self) -> &::std::option::Option<i64> {
-        &self.pid
+    fn get_exit_code_for_reflect(&self) -> &::std::option::Option<i32> {
+        &self.exit_code
     }
 
-    fn mut_pid_for_reflect(&mut self) -> &mut ::std::option::Option<i64> {
-        &mut self.pid
+    fn mut_exit_code_for_reflect(&mut self) -> &mut ::std::option::Option<i32> {
+        &mut self.exit_code
     }
 }
 
-impl ::protobuf::Message for SpawnOk {
+impl ::protobuf::Message for ExitStatusOk {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -919,8 +2233,8 @@ impl ::protobuf::Message for SpawnOk {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int64()?;
-                    self.pid = ::std::option::Option::Some(tmp);
+                    let tmp = is.read_int32()?;
+                    self.exit_code = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -934,7 +2248,7 @@ impl ::protobuf::Message for SpawnOk {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.pid {
+        if let Some(v) = self.exit_code {
             my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -943,8 +2257,8 @@ impl ::protobuf::Message for SpawnOk {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.pid {
-            os.write_int64(1, v)?;
+        if let Some(v) = self.exit_code {
+            os.write_int32(1, v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -977,12 +2291,12 @@ impl ::protobuf::Message for SpawnOk {
     }
 }
 
-impl ::protobuf::MessageStatic for SpawnOk {
-    fn new() -> SpawnOk {
-        SpawnOk::new()
+impl ::protobuf::MessageStatic for ExitStatusOk {
+    fn new() -> ExitStatusOk {
+        ExitStatusOk::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<SpawnOk>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<ExitStatusOk>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -990,13 +2304,13 @@ impl ::protobuf::MessageStatic for SpawnOk {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                    "pid",
-                    SpawnOk::get_pid_for_reflect,
-                    SpawnOk::mut_pid_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "exit_code",
+                    ExitStatusOk::get_exit_code_for_reflect,
+                    ExitStatusOk::mut_exit_code_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<SpawnOk>(
-                    "SpawnOk",
+                ::protobuf::reflect::MessageDescriptor::new::<ExitStatusOk>(
+                    "ExitStatusOk",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1005,27 +2319,27 @@ impl ::protobuf::MessageStatic for SpawnOk {
     }
 }
 
-impl ::protobuf::Clear for SpawnOk {
+impl ::protobuf::Clear for ExitStatusOk {
     fn clear(&mut self) {
-        self.clear_pid();
+        self.clear_exit_code();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for SpawnOk {
+impl ::std::fmt::Debug for ExitStatusOk {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SpawnOk {
+impl ::protobuf::reflect::ProtobufValue for ExitStatusOk {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Terminate {
+pub struct ResourceUsage {
     // message fields
     pid: ::std::option::Option<i64>,
     // special fields
@@ -1034,20 +2348,20 @@ pub struct Terminate {
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Terminate {}
+unsafe impl ::std::marker::Sync for ResourceUsage {}
 
-impl Terminate {
-    pub fn new() -> Terminate {
+impl ResourceUsage {
+    pub fn new() -> ResourceUsage {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Terminate {
-        static mut instance: ::protobuf::lazy::Lazy<Terminate> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static ResourceUsage {
+        static mut instance: ::protobuf::lazy::Lazy<ResourceUsage> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Terminate,
+            ptr: 0 as *const ResourceUsage,
         };
         unsafe {
-            instance.get(Terminate::new)
+            instance.get(ResourceUsage::new)
         }
     }
 
@@ -1079,7 +2393,7 @@ impl Terminate {
     }
 }
 
-impl ::protobuf::Message for Terminate {
+impl ::protobuf::Message for ResourceUsage {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1150,12 +2464,12 @@ impl ::protobuf::Message for Terminate {
     }
 }
 
-impl ::protobuf::MessageStatic for Terminate {
-    fn new() -> Terminate {
-        Terminate::new()
+impl ::protobuf::MessageStatic for ResourceUsage {
+    fn new() -> ResourceUsage {
+        ResourceUsage::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Terminate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<ResourceUsage>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1165,11 +2479,11 @@ impl ::protobuf::MessageStatic for Terminate {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
                     "pid",
-                    Terminate::get_pid_for_reflect,
-                    Terminate::mut_pid_for_reflect,
+                    ResourceUsage::get_pid_for_reflect,
+                    ResourceUsage::mut_pid_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Terminate>(
-                    "Terminate",
+                ::protobuf::reflect::MessageDescriptor::new::<ResourceUsage>(
+                    "ResourceUsage",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1178,109 +2492,137 @@ impl ::protobuf::MessageStatic for Terminate {
     }
 }
 
-impl ::protobuf::Clear for Terminate {
+impl ::protobuf::Clear for ResourceUsage {
     fn clear(&mut self) {
         self.clear_pid();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Terminate {
+impl ::std::fmt::Debug for ResourceUsage {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Terminate {
+impl ::protobuf::reflect::ProtobufValue for ResourceUsage {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct TerminateOk {
+pub struct ResourceUsageOk {
     // message fields
-    exit_code: ::std::option::Option<i32>,
-    shutdown_method: ::std::option::Option<ShutdownMethod>,
+    memory_usage: ::std::option::Option<u64>,
+    cpu_usage: ::std::option::Option<u64>,
+    pids: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for TerminateOk {}
+unsafe impl ::std::marker::Sync for ResourceUsageOk {}
 
-impl TerminateOk {
-    pub fn new() -> TerminateOk {
+impl ResourceUsageOk {
+    pub fn new() -> ResourceUsageOk {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static TerminateOk {
-        static mut instance: ::protobuf::lazy::Lazy<TerminateOk> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static ResourceUsageOk {
+        static mut instance: ::protobuf::lazy::Lazy<ResourceUsageOk> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const TerminateOk,
+            ptr: 0 as *const ResourceUsageOk,
         };
         unsafe {
-            instance.get(TerminateOk::new)
+            instance.get(ResourceUsageOk::new)
         }
     }
 
-    // optional int32 exit_code = 1;
+    // optional uint64 memory_usage = 1;
 
-    pub fn clear_exit_code(&mut self) {
-        self.exit_code = ::std::option::Option::None;
+    pub fn clear_memory_usage(&mut self) {
+        self.memory_usage = ::std::option::Option::None;
     }
 
-    pub fn has_exit_code(&self) -> bool {
-        self.exit_code.is_some()
+    pub fn has_memory_usage(&self) -> bool {
+        self.memory_usage.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_exit_code(&mut self, v: i32) {
-        self.exit_code = ::std::option::Option::Some(v);
+    pub fn set_memory_usage(&mut self, v: u64) {
+        self.memory_usage = ::std::option::Option::Some(v);
     }
 
-    pub fn get_exit_code(&self) -> i32 {
-        self.exit_code.unwrap_or(0)
+    pub fn get_memory_usage(&self) -> u64 {
+        self.memory_usage.unwrap_or(0)
     }
 
-    fn get_exit_code_for_reflect(&self) -> &::std::option::Option<i32> {
-        &self.exit_code
+    fn get_memory_usage_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.memory_usage
     }
 
-    fn mut_exit_code_for_reflect(&mut self) -> &mut ::std::option::Option<i32> {
-        &mut self.exit_code
+    fn mut_memory_usage_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.memory_usage
     }
 
-    // optional .launcher.ShutdownMethod shutdown_method = 2;
+    // optional uint64 cpu_usage = 2;
 
-    pub fn clear_shutdown_method(&mut self) {
-        self.shutdown_method = ::std::option::Option::None;
+    pub fn clear_cpu_usage(&mut self) {
+        self.cpu_usage = ::std::option::Option::None;
     }
 
-    pub fn has_shutdown_method(&self) -> bool {
-        self.shutdown_method.is_some()
+    pub fn has_cpu_usage(&self) -> bool {
+        self.cpu_usage.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_shutdown_method(&mut self, v: ShutdownMethod) {
-        self.shutdown_method = ::std::option::Option::Some(v);
+    pub fn set_cpu_usage(&mut self, v: u64) {
+        self.cpu_usage = ::std::option::Option::Some(v);
     }
 
-    pub fn get_shutdown_method(&self) -> ShutdownMethod {
-        self.shutdown_method.unwrap_or(ShutdownMethod::AlreadyExited)
+    pub fn get_cpu_usage(&self) -> u64 {
+        self.cpu_usage.unwrap_or(0)
     }
 
-    fn get_shutdown_method_for_reflect(&self) -> &::std::option::Option<ShutdownMethod> {
-        &self.shutdown_method
+    fn get_cpu_usage_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.cpu_usage
     }
 
-    fn mut_shutdown_method_for_reflect(&mut self) -> &mut ::std::option::Option<ShutdownMethod> {
-        &mut self.shutdown_method
+    fn mut_cpu_usage_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.cpu_usage
+    }
+
+    // optional uint64 pids = 3;
+
+    pub fn clear_pids(&mut self) {
+        self.pids = ::std::option::Option::None;
+    }
+
+    pub fn has_pids(&self) -> bool {
+        self.pids.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pids(&mut self, v: u64) {
+        self.pids = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_pids(&self) -> u64 {
+        self.pids.unwrap_or(0)
+    }
+
+    fn get_pids_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.pids
+    }
+
+    fn mut_pids_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.pids
     }
 }
 
-impl ::protobuf::Message for TerminateOk {
+impl ::protobuf::Message for ResourceUsageOk {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1293,15 +2635,22 @@ impl ::protobuf::Message for TerminateOk {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int32()?;
-                    self.exit_code = ::std::option::Option::Some(tmp);
+                    let tmp = is.read_uint64()?;
+                    self.memory_usage = ::std::option::Option::Some(tmp);
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_enum()?;
-                    self.shutdown_method = ::std::option::Option::Some(tmp);
+                    let tmp = is.read_uint64()?;
+                    self.cpu_usage = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.pids = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1315,11 +2664,14 @@ impl ::protobuf::Message for TerminateOk {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.exit_code {
+        if let Some(v) = self.memory_usage {
             my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(v) = self.shutdown_method {
-            my_size += ::protobuf::rt::enum_size(2, v);
+        if let Some(v) = self.cpu_usage {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.pids {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1327,11 +2679,14 @@ impl ::protobuf::Message for TerminateOk {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.exit_code {
-            os.write_int32(1, v)?;
+        if let Some(v) = self.memory_usage {
+            os.write_uint64(1, v)?;
         }
-        if let Some(v) = self.shutdown_method {
-            os.write_enum(2, v.value())?;
+        if let Some(v) = self.cpu_usage {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.pids {
+            os.write_uint64(3, v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1364,12 +2719,12 @@ impl ::protobuf::Message for TerminateOk {
     }
 }
 
-impl ::protobuf::MessageStatic for TerminateOk {
-    fn new() -> TerminateOk {
-        TerminateOk::new()
+impl ::protobuf::MessageStatic for ResourceUsageOk {
+    fn new() -> ResourceUsageOk {
+        ResourceUsageOk::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<TerminateOk>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<ResourceUsageOk>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1377,18 +2732,23 @@ impl ::protobuf::MessageStatic for TerminateOk {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "exit_code",
-                    TerminateOk::get_exit_code_for_reflect,
-                    TerminateOk::mut_exit_code_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "memory_usage",
+                    ResourceUsageOk::get_memory_usage_for_reflect,
+                    ResourceUsageOk::mut_memory_usage_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ShutdownMethod>>(
-                    "shutdown_method",
-                    TerminateOk::get_shutdown_method_for_reflect,
-                    TerminateOk::mut_shutdown_method_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "cpu_usage",
+                    ResourceUsageOk::get_cpu_usage_for_reflect,
+                    ResourceUsageOk::mut_cpu_usage_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<TerminateOk>(
-                    "TerminateOk",
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "pids",
+                    ResourceUsageOk::get_pids_for_reflect,
+                    ResourceUsageOk::mut_pids_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ResourceUsageOk>(
+                    "ResourceUsageOk",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1397,21 +2757,22 @@ impl ::protobuf::MessageStatic for TerminateOk {
     }
 }
 
-impl ::protobuf::Clear for TerminateOk {
+impl ::protobuf::Clear for ResourceUsageOk {
     fn clear(&mut self) {
-        self.clear_exit_code();
-        self.clear_shutdown_method();
+        self.clear_memory_usage();
+        self.clear_cpu_usage();
+        self.clear_pids();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for TerminateOk {
+impl ::std::fmt::Debug for ResourceUsageOk {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for TerminateOk {
+impl ::protobuf::reflect::ProtobufValue for ResourceUsageOk {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -1471,77 +2832,144 @@ impl ::protobuf::reflect::ProtobufValue for ShutdownMethod {
 
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x18protocols/launcher.proto\x12\x08launcher\"\x1e\n\x08Register\x12\
-    \x12\n\x04pipe\x18\x01\x20\x01(\tR\x04pipe\"\x1b\n\x07Restart\x12\x10\n\
-    \x03pid\x18\x01\x20\x01(\x03R\x03pid\"\xee\x01\n\x05Spawn\x12\x0e\n\x02i\
+    \x12\n\x04pipe\x18\x01\x20\x01(\tR\x04pipe\"&\n\nRegisterOk\x12\x18\n\
+    \x07version\x18\x01\x20\x01(\rR\x07version\"\x1b\n\x07Restart\x12\x10\n\
+    \x03pid\x18\x01\x20\x01(\x03R\x03pid\"\xe8\x03\n\x05Spawn\x12\x0e\n\x02i\
     d\x18\x01\x20\x01(\tR\x02id\x12\x16\n\x06binary\x18\x02\x20\x01(\tR\x06b\
     inary\x12\x19\n\x08svc_user\x18\x03\x20\x01(\tR\x07svcUser\x12\x1b\n\tsv\
     c_group\x18\x04\x20\x01(\tR\x08svcGroup\x12!\n\x0csvc_password\x18\x05\
     \x20\x01(\tR\x0bsvcPassword\x12*\n\x03env\x18\x06\x20\x03(\x0b2\x18.laun\
-    cher.Spawn.EnvEntryR\x03env\x1a6\n\x08EnvEntry\x12\x10\n\x03key\x18\x01\
-    \x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x02\
-    8\x01\"\x1b\n\x07SpawnOk\x12\x10\n\x03pid\x18\x01\x20\x01(\x03R\x03pid\"\
-    \x1d\n\tTerminate\x12\x10\n\x03pid\x18\x01\x20\x01(\x03R\x03pid\"m\n\x0b\
-    TerminateOk\x12\x1b\n\texit_code\x18\x01\x20\x01(\x05R\x08exitCode\x12A\
-    \n\x0fshutdown_method\x18\x02\x20\x01(\x0e2\x18.launcher.ShutdownMethodR\
-    \x0eshutdownMethod*H\n\x0eShutdownMethod\x12\x11\n\rAlreadyExited\x10\0\
-    \x12\x17\n\x13GracefulTermination\x10\x01\x12\n\n\x06Killed\x10\x02J\xfc\
-    \x08\n\x06\x12\x04\0\0&\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\
-    \x02\x12\x03\x02\x08\x10\n\n\n\x02\x04\0\x12\x04\x04\0\x06\x01\n\n\n\x03\
-    \x04\0\x01\x12\x03\x04\x08\x10\n\x0b\n\x04\x04\0\x02\0\x12\x03\x05\x02\
-    \x1b\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\x05\x02\n\n\x0c\n\x05\x04\0\x02\
-    \0\x05\x12\x03\x05\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x05\x12\
-    \x16\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x05\x19\x1a\n\n\n\x02\x04\x01\
-    \x12\x04\x08\0\n\x01\n\n\n\x03\x04\x01\x01\x12\x03\x08\x08\x0f\n\x0b\n\
-    \x04\x04\x01\x02\0\x12\x03\t\x02\x19\n\x0c\n\x05\x04\x01\x02\0\x04\x12\
-    \x03\t\x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\t\x0b\x10\n\x0c\n\x05\
-    \x04\x01\x02\0\x01\x12\x03\t\x11\x14\n\x0c\n\x05\x04\x01\x02\0\x03\x12\
-    \x03\t\x17\x18\n\n\n\x02\x04\x02\x12\x04\x0c\0\x13\x01\n\n\n\x03\x04\x02\
-    \x01\x12\x03\x0c\x08\r\n\x0b\n\x04\x04\x02\x02\0\x12\x03\r\x02\x19\n\x0c\
-    \n\x05\x04\x02\x02\0\x04\x12\x03\r\x02\n\n\x0c\n\x05\x04\x02\x02\0\x05\
-    \x12\x03\r\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\r\x12\x14\n\x0c\
-    \n\x05\x04\x02\x02\0\x03\x12\x03\r\x17\x18\n\x0b\n\x04\x04\x02\x02\x01\
-    \x12\x03\x0e\x02\x1d\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\x03\x0e\x02\n\n\
-    \x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x0e\x0b\x11\n\x0c\n\x05\x04\x02\
-    \x02\x01\x01\x12\x03\x0e\x12\x18\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\
-    \x0e\x1b\x1c\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x0f\x02\x1f\n\x0c\n\x05\
-    \x04\x02\x02\x02\x04\x12\x03\x0f\x02\n\n\x0c\n\x05\x04\x02\x02\x02\x05\
-    \x12\x03\x0f\x0b\x11\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x0f\x12\x1a\
-    \n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x0f\x1d\x1e\n\x0b\n\x04\x04\x02\
-    \x02\x03\x12\x03\x10\x02\x20\n\x0c\n\x05\x04\x02\x02\x03\x04\x12\x03\x10\
-    \x02\n\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x10\x0b\x11\n\x0c\n\x05\
-    \x04\x02\x02\x03\x01\x12\x03\x10\x12\x1b\n\x0c\n\x05\x04\x02\x02\x03\x03\
-    \x12\x03\x10\x1e\x1f\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x11\x02#\n\x0c\
-    \n\x05\x04\x02\x02\x04\x04\x12\x03\x11\x02\n\n\x0c\n\x05\x04\x02\x02\x04\
-    \x05\x12\x03\x11\x0b\x11\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x11\x12\
-    \x1e\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\x11!\"\n\x0b\n\x04\x04\x02\
-    \x02\x05\x12\x03\x12\x02\x1e\n\r\n\x05\x04\x02\x02\x05\x04\x12\x04\x12\
-    \x02\x11#\n\x0c\n\x05\x04\x02\x02\x05\x06\x12\x03\x12\x02\x15\n\x0c\n\
-    \x05\x04\x02\x02\x05\x01\x12\x03\x12\x16\x19\n\x0c\n\x05\x04\x02\x02\x05\
-    \x03\x12\x03\x12\x1c\x1d\n\n\n\x02\x04\x03\x12\x04\x15\0\x17\x01\n\n\n\
-    \x03\x04\x03\x01\x12\x03\x15\x08\x0f\n\x0b\n\x04\x04\x03\x02\0\x12\x03\
-    \x16\x02\x19\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x16\x02\n\n\x0c\n\x05\
-    \x04\x03\x02\0\x05\x12\x03\x16\x0b\x10\n\x0c\n\x05\x04\x03\x02\0\x01\x12\
-    \x03\x16\x11\x14\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x16\x17\x18\n\n\n\
-    \x02\x04\x04\x12\x04\x19\0\x1b\x01\n\n\n\x03\x04\x04\x01\x12\x03\x19\x08\
-    \x11\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x1a\x02\x19\n\x0c\n\x05\x04\x04\
-    \x02\0\x04\x12\x03\x1a\x02\n\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\x1a\
-    \x0b\x10\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x1a\x11\x14\n\x0c\n\x05\
-    \x04\x04\x02\0\x03\x12\x03\x1a\x17\x18\n\n\n\x02\x04\x05\x12\x04\x1d\0\
-    \x20\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1d\x08\x13\n\x0b\n\x04\x04\x05\
-    \x02\0\x12\x03\x1e\x02\x1f\n\x0c\n\x05\x04\x05\x02\0\x04\x12\x03\x1e\x02\
-    \n\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03\x1e\x0b\x10\n\x0c\n\x05\x04\x05\
-    \x02\0\x01\x12\x03\x1e\x11\x1a\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\x1e\
-    \x1d\x1e\n\x0b\n\x04\x04\x05\x02\x01\x12\x03\x1f\x02.\n\x0c\n\x05\x04\
-    \x05\x02\x01\x04\x12\x03\x1f\x02\n\n\x0c\n\x05\x04\x05\x02\x01\x06\x12\
-    \x03\x1f\x0b\x19\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03\x1f\x1a)\n\x0c\
-    \n\x05\x04\x05\x02\x01\x03\x12\x03\x1f,-\n\n\n\x02\x05\0\x12\x04\"\0&\
-    \x01\n\n\n\x03\x05\0\x01\x12\x03\"\x05\x13\n\x0b\n\x04\x05\0\x02\0\x12\
-    \x03#\x02\x14\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03#\x02\x0f\n\x0c\n\x05\
-    \x05\0\x02\0\x02\x12\x03#\x12\x13\n\x0b\n\x04\x05\0\x02\x01\x12\x03$\x02\
-    \x1a\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03$\x02\x15\n\x0c\n\x05\x05\0\
-    \x02\x01\x02\x12\x03$\x18\x19\n\x0b\n\x04\x05\0\x02\x02\x12\x03%\x02\r\n\
-    \x0c\n\x05\x05\0\x02\x02\x01\x12\x03%\x02\x08\n\x0c\n\x05\x05\0\x02\x02\
-    \x02\x12\x03%\x0b\x0c\
+    cher.Spawn.EnvEntryR\x03env\x12\x1d\n\nstdout_log\x18\x07\x20\x01(\tR\ts\
+    tdoutLog\x12\x1d\n\nstderr_log\x18\x08\x20\x01(\tR\tstderrLog\x12!\n\x0c\
+    memory_limit\x18\t\x20\x01(\x04R\x0bmemoryLimit\x12\x1b\n\tcpu_limit\x18\
+    \n\x20\x01(\rR\x08cpuLimit\x12\x1d\n\npids_limit\x18\x0b\x20\x01(\x04R\t\
+    pidsLimit\x12\x20\n\x0clog_max_size\x18\x0c\x20\x01(\x04R\nlogMaxSize\
+    \x12\x1e\n\x0blog_max_age\x18\r\x20\x01(\x04R\tlogMaxAge\x12\x19\n\x08lo\
+    g_keep\x18\x0e\x20\x01(\rR\x07logKeep\x1a6\n\x08EnvEntry\x12\x10\n\x03ke\
+    y\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05v\
+    alue:\x028\x01\"\x1b\n\x07SpawnOk\x12\x10\n\x03pid\x18\x01\x20\x01(\x03R\
+    \x03pid\"\x1d\n\tTerminate\x12\x10\n\x03pid\x18\x01\x20\x01(\x03R\x03pid\
+    \"m\n\x0bTerminateOk\x12\x1b\n\texit_code\x18\x01\x20\x01(\x05R\x08exitC\
+    ode\x12A\n\x0fshutdown_method\x18\x02\x20\x01(\x0e2\x18.launcher.Shutdow\
+    nMethodR\x0eshutdownMethod\"\x1e\n\nExitStatus\x12\x10\n\x03pid\x18\x01\
+    \x20\x01(\x03R\x03pid\"+\n\x0cExitStatusOk\x12\x1b\n\texit_code\x18\x01\
+    \x20\x01(\x05R\x08exitCode\"!\n\rResourceUsage\x12\x10\n\x03pid\x18\x01\
+    \x20\x01(\x03R\x03pid\"e\n\x0fResourceUsageOk\x12!\n\x0cmemory_usage\x18\
+    \x01\x20\x01(\x04R\x0bmemoryUsage\x12\x1b\n\tcpu_usage\x18\x02\x20\x01(\
+    \x04R\x08cpuUsage\x12\x12\n\x04pids\x18\x03\x20\x01(\x04R\x04pids*H\n\
+    \x0eShutdownMethod\x12\x11\n\rAlreadyExited\x10\0\x12\x17\n\x13GracefulT\
+    ermination\x10\x01\x12\n\n\x06Killed\x10\x02J\xff\x11\n\x06\x12\x04\0\0D\
+    \x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x02\x08\x10\
+    \n\n\n\x02\x04\0\x12\x04\x04\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x04\
+    \x08\x10\n\x0b\n\x04\x04\0\x02\0\x12\x03\x05\x02\x1b\n\x0c\n\x05\x04\0\
+    \x02\0\x04\x12\x03\x05\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x05\x0b\
+    \x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x05\x12\x16\n\x0c\n\x05\x04\0\
+    \x02\0\x03\x12\x03\x05\x19\x1a\n\n\n\x02\x04\x01\x12\x04\x08\0\n\x01\n\n\
+    \n\x03\x04\x01\x01\x12\x03\x08\x08\x12\n\x0b\n\x04\x04\x01\x02\0\x12\x03\
+    \t\x02\x1e\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03\t\x02\n\n\x0c\n\x05\x04\
+    \x01\x02\0\x05\x12\x03\t\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\t\
+    \x12\x19\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\t\x1c\x1d\n\n\n\x02\x04\
+    \x02\x12\x04\x0c\0\x0e\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0c\x08\x0f\n\
+    \x0b\n\x04\x04\x02\x02\0\x12\x03\r\x02\x19\n\x0c\n\x05\x04\x02\x02\0\x04\
+    \x12\x03\r\x02\n\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\r\x0b\x10\n\x0c\n\
+    \x05\x04\x02\x02\0\x01\x12\x03\r\x11\x14\n\x0c\n\x05\x04\x02\x02\0\x03\
+    \x12\x03\r\x17\x18\n\n\n\x02\x04\x03\x12\x04\x10\0\x1f\x01\n\n\n\x03\x04\
+    \x03\x01\x12\x03\x10\x08\r\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x11\x02\x19\
+    \n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x11\x02\n\n\x0c\n\x05\x04\x03\x02\
+    \0\x05\x12\x03\x11\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x11\x12\
+    \x14\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x11\x17\x18\n\x0b\n\x04\x04\
+    \x03\x02\x01\x12\x03\x12\x02\x1d\n\x0c\n\x05\x04\x03\x02\x01\x04\x12\x03\
+    \x12\x02\n\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x12\x0b\x11\n\x0c\n\
+    \x05\x04\x03\x02\x01\x01\x12\x03\x12\x12\x18\n\x0c\n\x05\x04\x03\x02\x01\
+    \x03\x12\x03\x12\x1b\x1c\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\x13\x02\x1f\
+    \n\x0c\n\x05\x04\x03\x02\x02\x04\x12\x03\x13\x02\n\n\x0c\n\x05\x04\x03\
+    \x02\x02\x05\x12\x03\x13\x0b\x11\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03\
+    \x13\x12\x1a\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\x13\x1d\x1e\n\x0b\n\
+    \x04\x04\x03\x02\x03\x12\x03\x14\x02\x20\n\x0c\n\x05\x04\x03\x02\x03\x04\
+    \x12\x03\x14\x02\n\n\x0c\n\x05\x04\x03\x02\x03\x05\x12\x03\x14\x0b\x11\n\
+    \x0c\n\x05\x04\x03\x02\x03\x01\x12\x03\x14\x12\x1b\n\x0c\n\x05\x04\x03\
+    \x02\x03\x03\x12\x03\x14\x1e\x1f\n\x0b\n\x04\x04\x03\x02\x04\x12\x03\x15\
+    \x02#\n\x0c\n\x05\x04\x03\x02\x04\x04\x12\x03\x15\x02\n\n\x0c\n\x05\x04\
+    \x03\x02\x04\x05\x12\x03\x15\x0b\x11\n\x0c\n\x05\x04\x03\x02\x04\x01\x12\
+    \x03\x15\x12\x1e\n\x0c\n\x05\x04\x03\x02\x04\x03\x12\x03\x15!\"\n\x0b\n\
+    \x04\x04\x03\x02\x05\x12\x03\x16\x02\x1e\n\r\n\x05\x04\x03\x02\x05\x04\
+    \x12\x04\x16\x02\x15#\n\x0c\n\x05\x04\x03\x02\x05\x06\x12\x03\x16\x02\
+    \x15\n\x0c\n\x05\x04\x03\x02\x05\x01\x12\x03\x16\x16\x19\n\x0c\n\x05\x04\
+    \x03\x02\x05\x03\x12\x03\x16\x1c\x1d\n\x0b\n\x04\x04\x03\x02\x06\x12\x03\
+    \x17\x02!\n\x0c\n\x05\x04\x03\x02\x06\x04\x12\x03\x17\x02\n\n\x0c\n\x05\
+    \x04\x03\x02\x06\x05\x12\x03\x17\x0b\x11\n\x0c\n\x05\x04\x03\x02\x06\x01\
+    \x12\x03\x17\x12\x1c\n\x0c\n\x05\x04\x03\x02\x06\x03\x12\x03\x17\x1f\x20\
+    \n\x0b\n\x04\x04\x03\x02\x07\x12\x03\x18\x02!\n\x0c\n\x05\x04\x03\x02\
+    \x07\x04\x12\x03\x18\x02\n\n\x0c\n\x05\x04\x03\x02\x07\x05\x12\x03\x18\
+    \x0b\x11\n\x0c\n\x05\x04\x03\x02\x07\x01\x12\x03\x18\x12\x1c\n\x0c\n\x05\
+    \x04\x03\x02\x07\x03\x12\x03\x18\x1f\x20\n\x0b\n\x04\x04\x03\x02\x08\x12\
+    \x03\x19\x02#\n\x0c\n\x05\x04\x03\x02\x08\x04\x12\x03\x19\x02\n\n\x0c\n\
+    \x05\x04\x03\x02\x08\x05\x12\x03\x19\x0b\x11\n\x0c\n\x05\x04\x03\x02\x08\
+    \x01\x12\x03\x19\x12\x1e\n\x0c\n\x05\x04\x03\x02\x08\x03\x12\x03\x19!\"\
+    \n\x0b\n\x04\x04\x03\x02\t\x12\x03\x1a\x02!\n\x0c\n\x05\x04\x03\x02\t\
+    \x04\x12\x03\x1a\x02\n\n\x0c\n\x05\x04\x03\x02\t\x05\x12\x03\x1a\x0b\x11\
+    \n\x0c\n\x05\x04\x03\x02\t\x01\x12\x03\x1a\x12\x1b\n\x0c\n\x05\x04\x03\
+    \x02\t\x03\x12\x03\x1a\x1e\x20\n\x0b\n\x04\x04\x03\x02\n\x12\x03\x1b\x02\
+    \"\n\x0c\n\x05\x04\x03\x02\n\x04\x12\x03\x1b\x02\n\n\x0c\n\x05\x04\x03\
+    \x02\n\x05\x12\x03\x1b\x0b\x11\n\x0c\n\x05\x04\x03\x02\n\x01\x12\x03\x1b\
+    \x12\x1c\n\x0c\n\x05\x04\x03\x02\n\x03\x12\x03\x1b\x1f!\n\x0b\n\x04\x04\
+    \x03\x02\x0b\x12\x03\x1c\x02$\n\x0c\n\x05\x04\x03\x02\x0b\x04\x12\x03\
+    \x1c\x02\n\n\x0c\n\x05\x04\x03\x02\x0b\x05\x12\x03\x1c\x0b\x11\n\x0c\n\
+    \x05\x04\x03\x02\x0b\x01\x12\x03\x1c\x12\x1e\n\x0c\n\x05\x04\x03\x02\x0b\
+    \x03\x12\x03\x1c!#\n\x0b\n\x04\x04\x03\x02\x0c\x12\x03\x1d\x02#\n\x0c\n\
+    \x05\x04\x03\x02\x0c\x04\x12\x03\x1d\x02\n\n\x0c\n\x05\x04\x03\x02\x0c\
+    \x05\x12\x03\x1d\x0b\x11\n\x0c\n\x05\x04\x03\x02\x0c\x01\x12\x03\x1d\x12\
+    \x1d\n\x0c\n\x05\x04\x03\x02\x0c\x03\x12\x03\x1d\x20\"\n\x0b\n\x04\x04\
+    \x03\x02\r\x12\x03\x1e\x02\x20\n\x0c\n\x05\x04\x03\x02\r\x04\x12\x03\x1e\
+    \x02\n\n\x0c\n\x05\x04\x03\x02\r\x05\x12\x03\x1e\x0b\x11\n\x0c\n\x05\x04\
+    \x03\x02\r\x01\x12\x03\x1e\x12\x1a\n\x0c\n\x05\x04\x03\x02\r\x03\x12\x03\
+    \x1e\x1d\x1f\n\n\n\x02\x04\x04\x12\x04!\0#\x01\n\n\n\x03\x04\x04\x01\x12\
+    \x03!\x08\x0f\n\x0b\n\x04\x04\x04\x02\0\x12\x03\"\x02\x19\n\x0c\n\x05\
+    \x04\x04\x02\0\x04\x12\x03\"\x02\n\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\
+    \"\x0b\x10\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\"\x11\x14\n\x0c\n\x05\
+    \x04\x04\x02\0\x03\x12\x03\"\x17\x18\n\n\n\x02\x04\x05\x12\x04%\0'\x01\n\
+    \n\n\x03\x04\x05\x01\x12\x03%\x08\x11\n\x0b\n\x04\x04\x05\x02\0\x12\x03&\
+    \x02\x19\n\x0c\n\x05\x04\x05\x02\0\x04\x12\x03&\x02\n\n\x0c\n\x05\x04\
+    \x05\x02\0\x05\x12\x03&\x0b\x10\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03&\
+    \x11\x14\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03&\x17\x18\n\n\n\x02\x04\
+    \x06\x12\x04)\0,\x01\n\n\n\x03\x04\x06\x01\x12\x03)\x08\x13\n\x0b\n\x04\
+    \x04\x06\x02\0\x12\x03*\x02\x1f\n\x0c\n\x05\x04\x06\x02\0\x04\x12\x03*\
+    \x02\n\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03*\x0b\x10\n\x0c\n\x05\x04\
+    \x06\x02\0\x01\x12\x03*\x11\x1a\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03*\
+    \x1d\x1e\n\x0b\n\x04\x04\x06\x02\x01\x12\x03+\x02.\n\x0c\n\x05\x04\x06\
+    \x02\x01\x04\x12\x03+\x02\n\n\x0c\n\x05\x04\x06\x02\x01\x06\x12\x03+\x0b\
+    \x19\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03+\x1a)\n\x0c\n\x05\x04\x06\
+    \x02\x01\x03\x12\x03+,-\n\n\n\x02\x04\x07\x12\x04.\00\x01\n\n\n\x03\x04\
+    \x07\x01\x12\x03.\x08\x12\n\x0b\n\x04\x04\x07\x02\0\x12\x03/\x02\x19\n\
+    \x0c\n\x05\x04\x07\x02\0\x04\x12\x03/\x02\n\n\x0c\n\x05\x04\x07\x02\0\
+    \x05\x12\x03/\x0b\x10\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03/\x11\x14\n\
+    \x0c\n\x05\x04\x07\x02\0\x03\x12\x03/\x17\x18\n\n\n\x02\x04\x08\x12\x042\
+    \04\x01\n\n\n\x03\x04\x08\x01\x12\x032\x08\x14\n\x0b\n\x04\x04\x08\x02\0\
+    \x12\x033\x02\x1f\n\x0c\n\x05\x04\x08\x02\0\x04\x12\x033\x02\n\n\x0c\n\
+    \x05\x04\x08\x02\0\x05\x12\x033\x0b\x10\n\x0c\n\x05\x04\x08\x02\0\x01\
+    \x12\x033\x11\x1a\n\x0c\n\x05\x04\x08\x02\0\x03\x12\x033\x1d\x1e\n\n\n\
+    \x02\x04\t\x12\x046\08\x01\n\n\n\x03\x04\t\x01\x12\x036\x08\x15\n\x0b\n\
+    \x04\x04\t\x02\0\x12\x037\x02\x19\n\x0c\n\x05\x04\t\x02\0\x04\x12\x037\
+    \x02\n\n\x0c\n\x05\x04\t\x02\0\x05\x12\x037\x0b\x10\n\x0c\n\x05\x04\t\
+    \x02\0\x01\x12\x037\x11\x14\n\x0c\n\x05\x04\t\x02\0\x03\x12\x037\x17\x18\
+    \n\n\n\x02\x04\n\x12\x04:\0>\x01\n\n\n\x03\x04\n\x01\x12\x03:\x08\x17\n\
+    \x0b\n\x04\x04\n\x02\0\x12\x03;\x02#\n\x0c\n\x05\x04\n\x02\0\x04\x12\x03\
+    ;\x02\n\n\x0c\n\x05\x04\n\x02\0\x05\x12\x03;\x0b\x11\n\x0c\n\x05\x04\n\
+    \x02\0\x01\x12\x03;\x12\x1e\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03;!\"\n\
+    \x0b\n\x04\x04\n\x02\x01\x12\x03<\x02\x20\n\x0c\n\x05\x04\n\x02\x01\x04\
+    \x12\x03<\x02\n\n\x0c\n\x05\x04\n\x02\x01\x05\x12\x03<\x0b\x11\n\x0c\n\
+    \x05\x04\n\x02\x01\x01\x12\x03<\x12\x1b\n\x0c\n\x05\x04\n\x02\x01\x03\
+    \x12\x03<\x1e\x1f\n\x0b\n\x04\x04\n\x02\x02\x12\x03=\x02\x1b\n\x0c\n\x05\
+    \x04\n\x02\x02\x04\x12\x03=\x02\n\n\x0c\n\x05\x04\n\x02\x02\x05\x12\x03=\
+    \x0b\x11\n\x0c\n\x05\x04\n\x02\x02\x01\x12\x03=\x12\x16\n\x0c\n\x05\x04\
+    \n\x02\x02\x03\x12\x03=\x19\x1a\n\n\n\x02\x05\0\x12\x04@\0D\x01\n\n\n\
+    \x03\x05\0\x01\x12\x03@\x05\x13\n\x0b\n\x04\x05\0\x02\0\x12\x03A\x02\x14\
+    \n\x0c\n\x05\x05\0\x02\0\x01\x12\x03A\x02\x0f\n\x0c\n\x05\x05\0\x02\0\
+    \x02\x12\x03A\x12\x13\n\x0b\n\x04\x05\0\x02\x01\x12\x03B\x02\x1a\n\x0c\n\
+    \x05\x05\0\x02\x01\x01\x12\x03B\x02\x15\n\x0c\n\x05\x05\0\x02\x01\x02\
+    \x12\x03B\x18\x19\n\x0b\n\x04\x05\0\x02\x02\x12\x03C\x02\r\n\x0c\n\x05\
+    \x05\0\x02\x02\x01\x12\x03C\x02\x08\n\x0c\n\x05\x05\0\x02\x02\x02\x12\
+    \x03C\x0b\x0c\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -15,6 +15,7 @@
 use std::error;
 use std::fmt;
 use std::io;
+use std::path::PathBuf;
 use std::result;
 
 use ipc_channel;
@@ -26,11 +27,13 @@ use {SUP_CMD, SUP_PACKAGE_IDENT};
 #[derive(Debug)]
 pub enum Error {
     AcceptConn,
+    Cgroup(PathBuf, io::Error),
     Connect(io::Error),
     Deserialize(protobuf::ProtobufError),
     ExecWait(io::Error),
     GroupNotFound(String),
     OpenPipe(io::Error),
+    ResourceLimitsUnsupported,
     Send(ipc_channel::Error),
     Serialize(protobuf::ProtobufError),
     Spawn(io::Error),
@@ -47,6 +50,9 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
             Error::AcceptConn => format!("Unable to accept connection from Supervisor"),
+            Error::Cgroup(ref path, ref e) => {
+                format!("Unable to configure cgroup at {}, {}", path.display(), e)
+            }
             Error::Connect(ref e) => {
                 format!("Unable to connect to Supervisor's comm channel, {}", e)
             }
@@ -56,6 +62,9 @@ impl fmt::Display for Error {
             Error::ExecWait(ref e) => format!("Error waiting on PID, {}", e),
             Error::GroupNotFound(ref e) => format!("No GID for group '{}' could be found", e),
             Error::OpenPipe(ref e) => format!("Unable to open Launcher's comm channel, {}", e),
+            Error::ResourceLimitsUnsupported => {
+                format!("Resource limits are not supported on this platform")
+            }
             Error::Send(ref e) => format!("Unable to send to Launcher's comm channel, {}", e),
             Error::Serialize(ref e) => format!("Unable to serialize message to Supervisor, {}", e),
             Error::Spawn(ref e) => format!("Unable to spawn process, {}", e),
@@ -77,11 +86,13 @@ impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::AcceptConn => "Unable to accept connection from Supervisor",
+            Error::Cgroup(_, _) => "Unable to configure cgroup",
             Error::Connect(_) => "Unable to connect to Supervisor's pipe",
             Error::Deserialize(_) => "Unable to deserialize message from Supervisor",
             Error::GroupNotFound(_) => "No matching GID for group found",
             Error::ExecWait(_) => "OS Error while waiting on PID",
             Error::OpenPipe(_) => "Unable to open Launcher's pipe",
+            Error::ResourceLimitsUnsupported => "Resource limits are not supported on this platform",
             Error::Send(_) => "Unable to send to Launcher's pipe",
             Error::Serialize(_) => "Unable to serialize message to Supervisor",
             Error::Spawn(_) => "Unable to spawn process",
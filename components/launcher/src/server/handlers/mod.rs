@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod exit_status;
+mod resource_usage;
 mod restart;
 mod spawn;
 mod terminate;
 
+pub use self::exit_status::*;
+pub use self::resource_usage::*;
 pub use self::restart::*;
 pub use self::spawn::*;
 pub use self::terminate::*;
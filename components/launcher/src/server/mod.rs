@@ -25,6 +25,7 @@ use core;
 use core::package::{PackageIdent, PackageInstall};
 use core::os::process::{self, Pid, Signal};
 use core::os::signals::{self, SignalEvent};
+use core::output;
 use ipc_channel::ipc::{IpcOneShotServer, IpcReceiver, IpcSender};
 use protobuf;
 use protocol::{self, ERR_NO_RETRY_EXCODE, OK_NO_RETRY_EXCODE};
@@ -167,27 +168,38 @@ impl Server {
 }
 
 #[derive(Debug, Default)]
-pub struct ServiceTable(HashMap<Pid, Service>);
+pub struct ServiceTable {
+    services: HashMap<Pid, Service>,
+    /// Exit codes of services which exited on their own, kept until the Supervisor asks for
+    /// them. A service terminated by a signal has no exit code.
+    exited: HashMap<Pid, Option<i32>>,
+}
 
 impl ServiceTable {
     pub fn get(&self, pid: Pid) -> Option<&Service> {
-        self.0.get(&pid)
+        self.services.get(&pid)
     }
 
     pub fn get_mut(&mut self, pid: Pid) -> Option<&mut Service> {
-        self.0.get_mut(&pid)
+        self.services.get_mut(&pid)
     }
 
     pub fn insert(&mut self, service: Service) {
-        self.0.insert(service.id(), service);
+        self.services.insert(service.id(), service);
     }
 
     pub fn remove(&mut self, pid: Pid) -> Option<Service> {
-        self.0.remove(&pid)
+        self.services.remove(&pid)
+    }
+
+    /// Take the exit status of a reaped service, returning `None` if no service with the given
+    /// PID has exited.
+    pub fn take_exit_status(&mut self, pid: Pid) -> Option<Option<i32>> {
+        self.exited.remove(&pid)
     }
 
     fn kill_all(&mut self) {
-        for service in self.0.values_mut() {
+        for service in self.services.values_mut() {
             outputln!(preamble service.name(), "Stopping...");
             let shutdown_method = service.kill();
             outputln!(preamble service.name(), "Shutdown OK: {}", shutdown_method);
@@ -195,8 +207,8 @@ impl ServiceTable {
     }
 
     fn reap_zombies(&mut self) {
-        let mut dead: Vec<Pid> = vec![];
-        for service in self.0.values_mut() {
+        let mut dead: Vec<(Pid, Option<i32>)> = vec![];
+        for service in self.services.values_mut() {
             match service.try_wait() {
                 Ok(None) => (),
                 Ok(Some(code)) => {
@@ -206,16 +218,17 @@ impl ServiceTable {
                         service.id(),
                         code
                     );
-                    dead.push(service.id());
+                    dead.push((service.id(), code.code()));
                 }
                 Err(err) => {
                     warn!("Error waiting for child, {}, {}", service.id(), err);
-                    dead.push(service.id());
+                    dead.push((service.id(), None));
                 }
             }
         }
-        for pid in dead {
-            self.0.remove(&pid);
+        for (pid, code) in dead {
+            self.services.remove(&pid);
+            self.exited.insert(pid, code);
         }
     }
 }
@@ -237,6 +250,12 @@ where
 }
 
 pub fn run(args: Vec<String>) -> Result<i32> {
+    // Service output passes through the Launcher, so it follows the Supervisor's output format
+    if args.iter().any(|a| a == "--json-logging") ||
+        core::env::var(output::JSON_LOGGING_ENVVAR).is_ok()
+    {
+        output::set_json(true);
+    }
     let mut server = Server::new(args)?;
     signals::init();
     loop {
@@ -279,11 +298,19 @@ fn dispatch(tx: &Sender, bytes: &[u8], services: &mut ServiceTable) {
         }
     };
     let func = match msg.message_id() {
+        "ExitStatus" => handlers::ExitStatusHandler::run,
+        "ResourceUsage" => handlers::ResourceUsageHandler::run,
         "Restart" => handlers::RestartHandler::run,
         "Spawn" => handlers::SpawnHandler::run,
         "Terminate" => handlers::TerminateHandler::run,
         unknown => {
             warn!("Received unknown message from Supervisor, {}", unknown);
+            let mut reply = protocol::NetErr::new();
+            reply.set_code(protocol::ErrCode::UnknownMessage);
+            reply.set_msg(format!("Unknown message, {}", unknown));
+            if let Err(err) = self::reply(tx, &msg, &reply) {
+                error!("{}: replying, {}", unknown, err);
+            }
             return;
         }
     };
@@ -299,7 +326,9 @@ fn setup_connection(server: IpcOneShotServer<Vec<u8>>) -> Result<(Receiver, Send
         Error::Deserialize,
     )?;
     let tx = IpcSender::connect(msg.take_pipe()).map_err(Error::Connect)?;
-    send(&tx, &protocol::NetOk::new())?;
+    let mut reply = protocol::RegisterOk::new();
+    reply.set_version(protocol::PROTOCOL_VERSION);
+    send(&tx, &reply)?;
     Ok((rx, tx))
 }
 
@@ -22,6 +22,8 @@ use ansi_term::Colour;
 #[cfg(windows)]
 use core::os::process::windows_child::{ChildStderr, ChildStdout, ExitStatus};
 use core::os::process::Pid;
+use core::output::{self, Level, StructuredOutput};
+use core::util::rotating_log::{RotatingLog, RotationPolicy};
 use protocol;
 
 pub use sys::service::*;
@@ -42,17 +44,23 @@ impl Service {
     ) -> Self {
         if let Some(stdout) = stdout {
             let id = spawn.get_id().to_string();
-            thread::Builder::new()
+            let log = open_log(spawn.get_stdout_log(), rotation_policy(&spawn));
+            let piped = thread::Builder::new()
                 .name(format!("{}-out", spawn.get_id()))
-                .spawn(move || pipe_stdout(stdout, id))
-                .ok();
+                .spawn(move || pipe_stdout(stdout, id, log));
+            if let Err(err) = piped {
+                warn!("Unable to pipe standard output of {}, {}", spawn.get_id(), err);
+            }
         }
         if let Some(stderr) = stderr {
             let id = spawn.get_id().to_string();
-            thread::Builder::new()
+            let log = open_log(spawn.get_stderr_log(), rotation_policy(&spawn));
+            let piped = thread::Builder::new()
                 .name(format!("{}-err", spawn.get_id()))
-                .spawn(move || pipe_stderr(stderr, id))
-                .ok();
+                .spawn(move || pipe_stderr(stderr, id, log));
+            if let Err(err) = piped {
+                warn!("Unable to pipe standard error of {}, {}", spawn.get_id(), err);
+            }
         }
         Service {
             args: spawn,
@@ -75,6 +83,10 @@ impl Service {
         self.process.kill()
     }
 
+    pub fn resource_usage(&self) -> protocol::ResourceUsageOk {
+        self.process.resource_usage()
+    }
+
     pub fn name(&self) -> &str {
         self.args.get_id()
     }
@@ -103,33 +115,120 @@ impl fmt::Debug for Service {
     }
 }
 
+/// How the logs of a spawned process are rotated, falling back to the defaults for anything the
+/// Supervisor didn't send.
+fn rotation_policy(spawn: &protocol::Spawn) -> RotationPolicy {
+    let mut policy = RotationPolicy::default();
+    if spawn.has_log_max_size() {
+        policy.max_size = spawn.get_log_max_size();
+    }
+    if spawn.has_log_max_age() {
+        policy.max_age = Some(spawn.get_log_max_age());
+    }
+    if spawn.has_log_keep() {
+        policy.keep = spawn.get_log_keep();
+    }
+    policy
+}
+
+/// Open the file a service's output is additionally appended to, if one was requested.
+fn open_log(path: &str, policy: RotationPolicy) -> Option<ServiceLog> {
+    if path.is_empty() {
+        return None;
+    }
+    match RotatingLog::open(path, policy) {
+        Ok(log) => Some(ServiceLog {
+            log: log,
+            failing: false,
+        }),
+        Err(err) => {
+            warn!("Unable to open service log {}, {}", path, err);
+            None
+        }
+    }
+}
+
+/// A log of a service's output, which warns when writing to it starts failing rather than for
+/// every line lost, and again once it recovers.
+struct ServiceLog {
+    log: RotatingLog,
+    failing: bool,
+}
+
+impl ServiceLog {
+    fn write_line(&mut self, line: &str) {
+        match self.log.write_all(line.as_bytes()) {
+            Ok(()) => {
+                if self.failing {
+                    warn!("Writing to service log {} again", self.log.path().display());
+                    self.failing = false;
+                }
+            }
+            Err(err) => {
+                if !self.failing {
+                    warn!(
+                        "Unable to write to service log {}, {}",
+                        self.log.path().display(),
+                        err
+                    );
+                    self.failing = true;
+                }
+            }
+        }
+    }
+}
+
 /// Consume output from a child process until EOF, then finish
-fn pipe_stdout<T>(out: T, id: String)
+fn pipe_stdout<T>(out: T, id: String, mut log: Option<ServiceLog>)
 where
     T: Read,
 {
     let mut reader = BufReader::new(out);
     let mut buffer = String::new();
     while reader.read_line(&mut buffer).unwrap() > 0 {
-        let mut line = output_format!(preamble &id, logkey "O");
-        line.push_str(&buffer);
+        let line = if output::is_json() {
+            json_line(&id, "O", &buffer, Level::Info)
+        } else {
+            let mut line = output_format!(preamble &id, logkey "O");
+            line.push_str(&buffer);
+            line
+        };
         write!(&mut io::stdout(), "{}", line).expect("unable to write to stdout");
+        if let Some(ref mut log) = log {
+            log.write_line(&buffer);
+        }
         buffer.clear();
     }
 }
 
+/// Format a line of a service's output as a JSON object attributed to the service itself.
+fn json_line(id: &str, logkey: &'static str, content: &str, level: Level) -> String {
+    let mut so = StructuredOutput::new(id, logkey, line!(), file!(), column!(), content);
+    so.source = Some("service");
+    so.level = level;
+    format!("{}\n", so)
+}
+
 /// Consume standard error from a child process until EOF, then finish
-fn pipe_stderr<T>(err: T, id: String)
+fn pipe_stderr<T>(err: T, id: String, mut log: Option<ServiceLog>)
 where
     T: Read,
 {
     let mut reader = BufReader::new(err);
     let mut buffer = String::new();
     while reader.read_line(&mut buffer).unwrap() > 0 {
-        let mut line = output_format!(preamble &id, logkey "E");
-        let c = format!("{}", Colour::Red.bold().paint(buffer.clone()));
-        line.push_str(c.as_str());
+        let line = if output::is_json() {
+            json_line(&id, "E", &buffer, Level::Error)
+        } else {
+            let mut line = output_format!(preamble &id, logkey "E");
+            let c = format!("{}", Colour::Red.bold().paint(buffer.clone()));
+            line.push_str(c.as_str());
+            line
+        };
         write!(&mut io::stderr(), "{}", line).expect("unable to write to stderr");
+        if let Some(ref mut log) = log {
+            log.write_line(&buffer);
+        }
         buffer.clear();
     }
 }
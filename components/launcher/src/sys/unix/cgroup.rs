@@ -0,0 +1,216 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Places each service in its own control group, limiting the memory, CPU time and number of
+//! processes it may use and accounting for what it actually uses.
+//!
+//! Every service gets a group named after it under `habitat` in the memory, cpu, cpuacct and
+//! pids hierarchies of the (version 1) cgroup filesystem. The cpu and cpuacct controllers are
+//! usually mounted together, in which case they share a group.
+
+use std::ffi::CString;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+
+use protocol;
+
+use error::{Error, Result};
+
+const CGROUP_ROOT: &'static str = "/sys/fs/cgroup";
+const CGROUP_PARENT: &'static str = "habitat";
+/// Length in microseconds of the period over which a service's CPU time is limited.
+const CPU_PERIOD_US: u64 = 100_000;
+
+/// Resource limits requested for a service. Nothing is limited if a value is not set.
+#[derive(Debug, Default)]
+pub struct Limits {
+    /// Bytes of memory.
+    pub memory: Option<u64>,
+    /// Thousandths of a CPU.
+    pub cpu: Option<u32>,
+    /// Number of processes and threads.
+    pub pids: Option<u64>,
+}
+
+impl Limits {
+    pub fn is_empty(&self) -> bool {
+        self.memory.is_none() && self.cpu.is_none() && self.pids.is_none()
+    }
+}
+
+impl<'a> From<&'a protocol::Spawn> for Limits {
+    fn from(msg: &'a protocol::Spawn) -> Self {
+        Limits {
+            memory: if msg.has_memory_limit() {
+                Some(msg.get_memory_limit())
+            } else {
+                None
+            },
+            cpu: if msg.has_cpu_limit() {
+                Some(msg.get_cpu_limit())
+            } else {
+                None
+            },
+            pids: if msg.has_pids_limit() {
+                Some(msg.get_pids_limit())
+            } else {
+                None
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Cgroup {
+    memory: PathBuf,
+    cpu: PathBuf,
+    cpuacct: PathBuf,
+    pids: PathBuf,
+}
+
+impl Cgroup {
+    /// Create the control group for the named service, or reuse an existing one, and apply the
+    /// given limits to it. Limits which aren't set are lifted.
+    pub fn create(name: &str, limits: &Limits) -> Result<Self> {
+        let name = name.replace('/', "_");
+        let cgroup = Cgroup {
+            memory: group_path("memory", &name),
+            cpu: group_path("cpu", &name),
+            cpuacct: group_path("cpuacct", &name),
+            pids: group_path("pids", &name),
+        };
+        for path in cgroup.paths() {
+            fs::create_dir_all(path).map_err(
+                |err| Error::Cgroup(path.to_path_buf(), err),
+            )?;
+        }
+        write_value(
+            &cgroup.memory.join("memory.limit_in_bytes"),
+            limits.memory.map_or("-1".to_string(), |bytes| bytes.to_string()),
+        )?;
+        write_value(
+            &cgroup.cpu.join("cpu.cfs_period_us"),
+            CPU_PERIOD_US.to_string(),
+        )?;
+        write_value(
+            &cgroup.cpu.join("cpu.cfs_quota_us"),
+            limits.cpu.map_or("-1".to_string(), |cpu| {
+                (cpu as u64 * CPU_PERIOD_US / 1_000).to_string()
+            }),
+        )?;
+        write_value(
+            &cgroup.pids.join("pids.max"),
+            limits.pids.map_or("max".to_string(), |pids| pids.to_string()),
+        )?;
+        Ok(cgroup)
+    }
+
+    /// The `cgroup.procs` file of the group in every hierarchy, to which a process writes its
+    /// PID to join the group. Processes it starts from then on are created in the group as well.
+    pub fn procs_files(&self) -> Result<Vec<CString>> {
+        self.paths()
+            .into_iter()
+            .map(|path| {
+                let file = path.join("cgroup.procs");
+                CString::new(file.as_os_str().as_bytes()).map_err(|err| {
+                    Error::Cgroup(file, io::Error::new(io::ErrorKind::InvalidInput, err))
+                })
+            })
+            .collect()
+    }
+
+    /// Current resource usage of every process in the control group. Values which can't be
+    /// read are left unset.
+    pub fn usage(&self) -> protocol::ResourceUsageOk {
+        let mut usage = protocol::ResourceUsageOk::new();
+        if let Some(bytes) = read_value(&self.memory.join("memory.usage_in_bytes")) {
+            usage.set_memory_usage(bytes);
+        }
+        if let Some(nanos) = read_value(&self.cpuacct.join("cpuacct.usage")) {
+            usage.set_cpu_usage(nanos);
+        }
+        if let Some(pids) = read_value(&self.pids.join("pids.current")) {
+            usage.set_pids(pids);
+        }
+        usage
+    }
+
+    /// The group's directory in every hierarchy, listing a directory shared by co-mounted
+    /// controllers only once.
+    fn paths(&self) -> Vec<&Path> {
+        let mut paths: Vec<&Path> = vec![&self.memory, &self.cpu, &self.pids];
+        if !same_file(&self.cpu, &self.cpuacct) {
+            paths.push(&self.cpuacct);
+        }
+        paths
+    }
+}
+
+impl Drop for Cgroup {
+    fn drop(&mut self) {
+        // A group can only be removed once its processes have exited, which may not be the case
+        // yet if the service left any behind or was just restarted into the same group.
+        for path in self.paths() {
+            if let Err(err) = fs::remove_dir(path) {
+                debug!("Unable to remove cgroup {}, {}", path.display(), err);
+            }
+        }
+    }
+}
+
+/// Path of the named group in the hierarchy of a controller. A controller co-mounted with others
+/// is usually reachable through a symlink named after it; when it isn't, its hierarchy is the
+/// one named after all of its controllers.
+fn group_path(controller: &str, name: &str) -> PathBuf {
+    let mut hierarchy = Path::new(CGROUP_ROOT).join(controller);
+    if !hierarchy.exists() {
+        if let Ok(entries) = fs::read_dir(CGROUP_ROOT) {
+            for entry in entries.filter_map(|entry| entry.ok()) {
+                let controllers = entry.file_name().to_string_lossy().into_owned();
+                if controllers.split(',').any(|c| c == controller) {
+                    hierarchy = entry.path();
+                    break;
+                }
+            }
+        }
+    }
+    hierarchy.join(CGROUP_PARENT).join(name)
+}
+
+/// Whether two paths lead to the same directory, such as the groups of co-mounted controllers.
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (fs::canonicalize(a), fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+fn write_value(path: &Path, value: String) -> Result<()> {
+    File::create(path)
+        .and_then(|mut file| file.write_all(value.as_bytes()))
+        .map_err(|err| Error::Cgroup(path.to_path_buf(), err))
+}
+
+fn read_value(path: &Path) -> Option<u64> {
+    let mut buf = String::new();
+    match File::open(path).and_then(|mut file| file.read_to_string(&mut buf)) {
+        Ok(_) => buf.trim().parse().ok(),
+        Err(err) => {
+            debug!("Unable to read {}, {}", path.display(), err);
+            None
+        }
+    }
+}
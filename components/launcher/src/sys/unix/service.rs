@@ -12,31 +12,36 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::ffi::CString;
 use std::io;
 use std::ops::Neg;
 use std::os::unix::process::{CommandExt, ExitStatusExt};
 use std::process::{Command, ExitStatus, Stdio};
+use std::ptr;
 use std::result;
 
 use core::os;
 use core::os::process::{Pid, signal, Signal};
-use libc::{self, c_int, pid_t};
+use libc::{self, c_int, c_void, gid_t, pid_t, uid_t};
 use protocol::{self, ShutdownMethod};
 use time::{Duration, SteadyTime};
 
 use error::{Error, Result};
 use service::Service;
+use super::cgroup::{Cgroup, Limits};
 
 pub struct Process {
     pid: pid_t,
     status: Option<ExitStatus>,
+    cgroup: Option<Cgroup>,
 }
 
 impl Process {
-    fn new(pid: u32) -> Self {
+    fn new(pid: u32, cgroup: Option<Cgroup>) -> Self {
         Process {
             pid: pid as pid_t,
             status: None,
+            cgroup: cgroup,
         }
     }
 
@@ -44,6 +49,15 @@ impl Process {
         self.pid
     }
 
+    /// Resource usage of the process and its descendants, which is only known if the process
+    /// was placed in a cgroup.
+    pub fn resource_usage(&self) -> protocol::ResourceUsageOk {
+        match self.cgroup {
+            Some(ref cgroup) => cgroup.usage(),
+            None => protocol::ResourceUsageOk::new(),
+        }
+    }
+
     /// Attempt to gracefully terminate a proccess and then forcefully kill it after
     /// 8 seconds if it has not terminated.
     pub fn kill(&mut self) -> ShutdownMethod {
@@ -124,20 +138,106 @@ pub fn run(msg: protocol::Spawn) -> Result<Service> {
     let gid = os::users::get_gid_by_name(msg.get_svc_group()).ok_or(
         Error::GroupNotFound(msg.get_svc_group().to_string()),
     )?;
-    cmd.before_exec(owned_pgid);
+    let limits = Limits::from(&msg);
+    let cgroup = cgroup_for(&msg, &limits)?;
+    let procs_files = match cgroup {
+        Some(ref cgroup) => cgroup.procs_files()?,
+        None => vec![],
+    };
+    let limited = !limits.is_empty();
+    cmd.before_exec(move || {
+        owned_pgid()?;
+        if let Err(err) = join_cgroup(&procs_files) {
+            // The service must not run without the limits it asked for.
+            if limited {
+                return Err(err);
+            }
+        }
+        switch_user(uid, gid)
+    });
     cmd.stdin(Stdio::null())
         .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .uid(uid)
-        .gid(gid);
+        .stderr(Stdio::piped());
     for (key, val) in msg.get_env().iter() {
         cmd.env(key, val);
     }
     let child = cmd.spawn().map_err(Error::Spawn)?;
-    let process = Process::new(child.id());
+    let process = Process::new(child.id(), cgroup);
     Ok(Service::new(msg, process, child.stdout, child.stderr))
 }
 
+/// Create the cgroup a service's process joins as it starts, which applies the service's
+/// resource limits and lets the Launcher report its resource usage. Failing to do so is only an
+/// error if the service has limits; without them the process simply runs outside of a cgroup,
+/// such as on hosts without the cgroup filesystem.
+fn cgroup_for(msg: &protocol::Spawn, limits: &Limits) -> Result<Option<Cgroup>> {
+    match Cgroup::create(msg.get_id(), limits) {
+        Ok(cgroup) => Ok(Some(cgroup)),
+        Err(err) => {
+            if limits.is_empty() {
+                debug!("Not placing {} in a cgroup, {}", msg.get_id(), err);
+                Ok(None)
+            } else {
+                Err(err)
+            }
+        }
+    }
+}
+
+/// Move the calling process into a cgroup by writing its PID to each of the group's
+/// `cgroup.procs` files. This runs in the child between fork and exec, before the service
+/// could start anything outside of the group, so it sticks to calls which don't allocate.
+fn join_cgroup(procs_files: &[CString]) -> io::Result<()> {
+    let mut buf = [0u8; 20];
+    let pid = format_pid(unsafe { libc::getpid() }, &mut buf);
+    for file in procs_files {
+        let fd = unsafe { libc::open(file.as_ptr(), libc::O_WRONLY) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let written = unsafe { libc::write(fd, pid.as_ptr() as *const c_void, pid.len()) };
+        let err = io::Error::last_os_error();
+        unsafe {
+            libc::close(fd);
+        }
+        if written < 0 {
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
+fn format_pid(pid: pid_t, buf: &mut [u8; 20]) -> &[u8] {
+    let mut pid = pid as u64;
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (pid % 10) as u8;
+        pid /= 10;
+        if pid == 0 {
+            break;
+        }
+    }
+    &buf[start..]
+}
+
+/// Drop to the service's user and group. This is done here rather than by `Command` so that it
+/// happens after the process joined its cgroup, which takes the Launcher's privileges.
+fn switch_user(uid: uid_t, gid: gid_t) -> result::Result<(), io::Error> {
+    unsafe {
+        if libc::setgid(gid) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if libc::getuid() == 0 {
+            libc::setgroups(0, ptr::null());
+        }
+        if libc::setuid(uid) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
 // we want the command to spawn processes in their own process group
 // and not the same group as the Launcher. Otherwise if a child process
 // sends SIGTERM to the group, the Launcher could be terminated.
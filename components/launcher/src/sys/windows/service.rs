@@ -46,6 +46,11 @@ impl Process {
         unsafe { kernel32::GetProcessId(self.handle.raw()) as u32 }
     }
 
+    /// Resource usage is not tracked on Windows.
+    pub fn resource_usage(&self) -> protocol::ResourceUsageOk {
+        protocol::ResourceUsageOk::new()
+    }
+
     pub fn kill(&mut self) -> ShutdownMethod {
         if self.status().is_some() {
             return ShutdownMethod::AlreadyExited;
@@ -117,6 +122,9 @@ impl Process {
 
 pub fn run(mut msg: protocol::Spawn) -> Result<Service> {
     debug!("launcher is spawning {}", msg.get_binary());
+    if msg.has_memory_limit() || msg.has_cpu_limit() || msg.has_pids_limit() {
+        return Err(Error::ResourceLimitsUnsupported);
+    }
     let ps_cmd = format!("iex $(gc {} | out-string)", msg.get_binary());
     let password = if msg.get_svc_password().is_empty() {
         None
@@ -112,6 +112,8 @@ $script:pkg_svc_run = ''
 $script:pkg_expose = @()
 # An associative array representing configuration data which should be gossiped to peers.
 $script:pkg_exports = @{}
+# An associative array of hooks in `hooks/periodic` and the seconds between runs of each.
+$script:pkg_periodic_hooks = @{}
 # The user to run the service as
 $script:pkg_svc_user = "hab"
 # The group to run the service as
@@ -1244,6 +1246,18 @@ function _Write-Metadata {
         }
     }
 
+    if ($pkg_periodic_hooks) {
+        foreach ($hook in $pkg_periodic_hooks.GetEnumerator()) {
+            if (!(Test-Path "$PLAN_CONTEXT\hooks\periodic\$($hook.Key)")) {
+                _Exit-With "Bad key in pkg_periodic_hooks; No hook found at hooks\periodic\$($hook.Key)" 1
+            }
+            if ("$($hook.Value)" -notmatch '^[1-9][0-9]*( leader)?$') {
+                _Exit-With "Bad value in pkg_periodic_hooks; Schedule of `"$($hook.Key)`" must be a number of seconds, optionally followed by `"leader`": $($hook.Value)" 1
+            }
+            "$($hook.Key)=$($hook.Value)" | Out-File "$pkg_prefix\PERIODIC_HOOKS" -Encoding ascii -Append
+        }
+    }
+
     $pkg_build_deps_resolved | % {
         Resolve-HabPkgPath $_ | Out-File $pkg_prefix\BUILD_DEPS -Encoding ascii -Append
     }
@@ -211,6 +211,17 @@
 #   [storage]="port host"
 # )
 #
+# ### pkg_periodic_hooks
+# An associative array of hooks in the plan's `hooks/periodic` directory and the number of seconds
+# between runs of each. The Supervisor runs these hooks on schedule for every loaded service of the
+# package. Follow the number of seconds with `leader` to have only the leader run the hook when the
+# service runs in a leader topology.
+# ```
+# pkg_periodic_hooks=(
+#   [compact-logs]=3600
+#   [backup]="86400 leader"
+# )
+#
 # ### pkg_origin
 # A string to use for the origin. The origin is used to denote a particular upstream of a
 # package; when we resolve dependencies, we consider a version of a package to be equal
@@ -411,6 +422,7 @@ pkg_exposes=()
 declare -A pkg_exports
 declare -A pkg_binds
 declare -A pkg_binds_optional
+declare -A pkg_periodic_hooks
 # The user to run the service as
 pkg_svc_user=hab
 # The group to run the service as
@@ -1870,6 +1882,7 @@ do_default_install() {
 # * `$pkg_prefix/EXPOSES` - An array of `pkg_exports` for which ports that this package exposes
 # * `$pkg_prefix/BINDS` - A list of services you connect to and keys that you expect to be exported
 # * `$pkg_prefix/BINDS_OPTIONAL` - Same as `BINDS` but not required for the service to start
+# * `$pkg_prefix/PERIODIC_HOOKS` - A list of periodic hooks and how often they run
 # * `$pkg_prefix/FILES` - blake2b checksums of all files in the package
 # * `$pkg_prefix/LDFLAGS` - Any LDFLAGS for things that link against us
 # * `$pkg_prefix/LD_RUN_PATH` - The LD_RUN_PATH for things that link against us
@@ -1890,6 +1903,7 @@ _build_metadata() {
   _render_metadata_BINDS
   _render_metadata_BINDS_OPTIONAL
   _render_metadata_EXPOSES
+  _render_metadata_PERIODIC_HOOKS
   _render_metadata_INTERPRETERS
   _render_metadata_BUILD_DEPS
   _render_metadata_BUILD_TDEPS
@@ -81,6 +81,20 @@ _render_metadata_EXPOSES() {
   fi
 }
 
+_render_metadata_PERIODIC_HOOKS() {
+  local hook schedule
+  for hook in "${!pkg_periodic_hooks[@]}"; do
+    if [[ ! -f "$PLAN_CONTEXT/hooks/periodic/${hook}" ]]; then
+      exit_with "Bad key in pkg_periodic_hooks; No hook found at hooks/periodic/${hook}"
+    fi
+    schedule=${pkg_periodic_hooks[$hook]}
+    if [[ ! "$schedule" =~ ^[1-9][0-9]*( leader)?$ ]]; then
+      exit_with "Bad value in pkg_periodic_hooks; Schedule of \"${hook}\" must be a number of seconds, optionally followed by \"leader\": ${schedule}"
+    fi
+  done
+  _render_associative_array_file ${pkg_prefix} PERIODIC_HOOKS pkg_periodic_hooks
+}
+
 # Generate the blake2b hashes of all the files in the package. This
 # is not in the resulting MANIFEST because MANIFEST is included!
 _render_metadata_FILES() {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::fmt;
 use std::str::FromStr;
 
@@ -28,11 +28,16 @@ use butterfly::rumor::service::SysInfo;
 use hcore;
 use hcore::service::ServiceGroup;
 use hcore::package::PackageIdent;
+use common::config_secret;
+use serde::{Serialize, Serializer};
 use toml;
 
 use error::{Error, SupError};
+use manager::service::{CanarySize, HealthCheck};
 
 static LOGKEY: &'static str = "CE";
+/// Number of applied service configurations kept in the history of each service group.
+pub const CONFIG_HISTORY_SIZE: usize = 10;
 
 type MemberId = String;
 
@@ -118,6 +123,16 @@ impl CensusRing {
         self.census_groups.get(sg)
     }
 
+    /// Drop a configuration which the local service rejected from its group's history, so it is
+    /// never offered for a rollback.
+    pub fn reject_config(&mut self, sg: &ServiceGroup, incarnation: u64) {
+        if let Some(census_group) = self.census_groups.get_mut(sg) {
+            census_group.config_history.retain(
+                |config| config.incarnation != incarnation,
+            );
+        }
+    }
+
     pub fn groups(&self) -> Vec<&CensusGroup> {
         self.census_groups.values().map(|cg| cg).collect()
     }
@@ -277,9 +292,10 @@ pub struct ServiceFile {
     pub body: Vec<u8>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ServiceConfig {
     pub incarnation: u64,
+    #[serde(serialize_with = "serialize_redacted")]
     pub value: toml::Value,
 }
 
@@ -290,6 +306,10 @@ pub struct CensusGroup {
     pub update_election_status: ElectionStatus,
     pub leader_id: Option<MemberId>,
     pub service_config: Option<ServiceConfig>,
+    /// The most recently applied configurations, oldest first, including the current one. A
+    /// configuration the local service rejected against its package's schema is left out. The
+    /// history is only kept in memory, so it starts over when the Supervisor restarts.
+    pub config_history: VecDeque<ServiceConfig>,
 
     local_member_id: MemberId,
     population: BTreeMap<MemberId, CensusMember>,
@@ -309,6 +329,7 @@ impl CensusGroup {
             leader_id: None,
             update_leader_id: None,
             service_config: None,
+            config_history: VecDeque::new(),
             service_files: HashMap::new(),
             changed_service_files: Vec::new(),
         }
@@ -367,6 +388,30 @@ impl CensusGroup {
         }
     }
 
+    /// Return the alive members which are updated first in a canary update: the update leader
+    /// followed by the alive members after it in the ordered members list. Returns an empty list
+    /// if there is no alive update leader.
+    pub fn update_canaries(&self, size: CanarySize) -> Vec<&CensusMember> {
+        let alive_members: Vec<&CensusMember> =
+            self.population.values().filter(|cm| cm.alive).collect();
+        let leader_idx = match self.update_leader_id {
+            Some(ref id) => {
+                match alive_members.iter().position(|cm| &cm.member_id == id) {
+                    Some(idx) => idx,
+                    None => return Vec::new(),
+                }
+            }
+            None => return Vec::new(),
+        };
+        alive_members
+            .iter()
+            .cycle()
+            .skip(leader_idx)
+            .take(size.of(alive_members.len()))
+            .map(|cm| *cm)
+            .collect()
+    }
+
     fn update_from_service_rumors(&mut self, rumors: &HashMap<String, ServiceRumor>) {
         for (member_id, service_rumor) in rumors.iter() {
             // Yeah - we are ourself - we're alive.
@@ -429,10 +474,15 @@ impl CensusGroup {
                     service_config.get_incarnation() >
                         self.service_config.as_ref().unwrap().incarnation
                 {
-                    self.service_config = Some(ServiceConfig {
+                    let service_config = ServiceConfig {
                         incarnation: service_config.get_incarnation(),
                         value: config,
-                    });
+                    };
+                    if self.config_history.len() == CONFIG_HISTORY_SIZE {
+                        self.config_history.pop_front();
+                    }
+                    self.config_history.push_back(service_config.clone());
+                    self.service_config = Some(service_config);
                 }
             }
             Err(err) => warn!("{}", err),
@@ -497,6 +547,7 @@ pub struct CensusMember {
     pub update_election_is_running: bool,
     pub update_election_is_no_quorum: bool,
     pub update_election_is_finished: bool,
+    pub health: Option<HealthCheck>,
     pub sys: SysInfo,
     alive: bool,
     suspect: bool,
@@ -525,6 +576,11 @@ impl CensusMember {
             Ok(ident) => self.pkg = Some(ident),
             Err(err) => warn!("Received a bad package ident from gossip data, err={}", err),
         };
+        self.health = if rumor.has_health() {
+            HealthCheck::from_str(rumor.get_health()).ok()
+        } else {
+            None
+        };
         self.sys = rumor.get_sys().clone().into();
         self.cfg = toml::from_slice(rumor.get_cfg()).unwrap_or(toml::value::Table::default());
     }
@@ -597,6 +653,16 @@ fn service_group_from_str(sg: &str) -> Result<ServiceGroup, hcore::Error> {
     })
 }
 
+/// Gossiped configuration is shown in the census with its secrets redacted.
+fn serialize_redacted<S>(value: &toml::Value, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut value = value.clone();
+    config_secret::redact_value(&mut value);
+    value.serialize(serializer)
+}
+
 #[cfg(test)]
 mod tests {
     use hcore::package::ident::PackageIdent;
@@ -609,7 +675,11 @@ mod tests {
     use butterfly::rumor::election::ElectionUpdate as ElectionUpdateRumor;
     use butterfly::rumor::service::SysInfo;
     use butterfly::rumor::RumorStore;
-    use census::CensusRing;
+    use census::{CensusRing, CONFIG_HISTORY_SIZE};
+    use common::config_secret;
+    use manager::service::{CanarySize, HealthCheck};
+    use serde_json;
+    use toml;
 
     #[test]
     fn update_from_rumors() {
@@ -632,8 +702,9 @@ mod tests {
         let service_one =
             ServiceRumor::new("member-a".to_string(), &pg_id, &sg_one, &sys_info, None);
         let sg_two = ServiceGroup::new(None, "shield", "two", None).unwrap();
-        let service_two =
+        let mut service_two =
             ServiceRumor::new("member-b".to_string(), &pg_id, &sg_two, &sys_info, None);
+        service_two.set_health(HealthCheck::Ok.to_string());
         let service_three =
             ServiceRumor::new("member-a".to_string(), &pg_id, &sg_two, &sys_info, None);
 
@@ -682,5 +753,138 @@ mod tests {
         let members = census_group_two.members();
         assert_eq!(members[0].member_id, "member-a");
         assert_eq!(members[1].member_id, "member-b");
+        assert_eq!(members[0].health, None);
+        assert_eq!(members[1].health, Some(HealthCheck::Ok));
+
+        // Only ourselves are known to be alive, and we are the update leader.
+        let canaries = census_group_two.update_canaries(CanarySize::Count(2));
+        assert_eq!(canaries.len(), 1);
+        assert_eq!(canaries[0].member_id, "member-b");
+        assert!(census_group_one.update_canaries(CanarySize::Count(2)).is_empty());
+    }
+
+    #[test]
+    fn config_history() {
+        let sys_info = SysInfo::new();
+        let pg_id = PackageIdent::new(
+            "starkandwayne",
+            "shield",
+            Some("0.10.4"),
+            Some("20170419115548"),
+        );
+        let sg = ServiceGroup::new(None, "shield", "one", None).unwrap();
+        let service_store: RumorStore<ServiceRumor> = RumorStore::default();
+        service_store.insert(ServiceRumor::new(
+            "member-a".to_string(),
+            &pg_id,
+            &sg,
+            &sys_info,
+            None,
+        ));
+        let election_store: RumorStore<ElectionRumor> = RumorStore::default();
+        let election_update_store: RumorStore<ElectionUpdateRumor> = RumorStore::default();
+        let member_list = MemberList::new();
+        let service_config_store: RumorStore<ServiceConfigRumor> = RumorStore::default();
+        let service_file_store: RumorStore<ServiceFileRumor> = RumorStore::default();
+        let mut ring = CensusRing::new("member-a".to_string());
+
+        let last = CONFIG_HISTORY_SIZE as u64 + 2;
+        for incarnation in 1..(last + 1) {
+            let mut config = ServiceConfigRumor::new(
+                "member-a",
+                sg.clone(),
+                format!("port = {}", incarnation).into_bytes(),
+            );
+            config.set_incarnation(incarnation);
+            service_config_store.insert(config);
+            ring.update_from_rumors(
+                &service_store,
+                &election_store,
+                &election_update_store,
+                &member_list,
+                &service_config_store,
+                &service_file_store,
+            );
+        }
+
+        let census_group = ring.census_group_for(&sg).unwrap();
+        let incarnations: Vec<u64> = census_group
+            .config_history
+            .iter()
+            .map(|c| c.incarnation)
+            .collect();
+        assert_eq!(incarnations, (3..(last + 1)).collect::<Vec<u64>>());
+        assert_eq!(
+            census_group.service_config.as_ref().unwrap().incarnation,
+            last
+        );
+        let oldest = census_group.config_history[0].value.as_table().unwrap();
+        assert_eq!(oldest["port"].as_integer(), Some(3));
+
+        ring.reject_config(&sg, last);
+        let census_group = ring.census_group_for(&sg).unwrap();
+        assert_eq!(census_group.config_history.len(), CONFIG_HISTORY_SIZE - 1);
+        assert_eq!(
+            census_group.config_history.back().unwrap().incarnation,
+            last - 1
+        );
+    }
+
+    #[test]
+    fn config_history_with_secrets_cant_be_rolled_back_to() {
+        let sys_info = SysInfo::new();
+        let pg_id = PackageIdent::new(
+            "starkandwayne",
+            "shield",
+            Some("0.10.4"),
+            Some("20170419115548"),
+        );
+        let sg = ServiceGroup::new(None, "shield", "one", None).unwrap();
+        let service_store: RumorStore<ServiceRumor> = RumorStore::default();
+        service_store.insert(ServiceRumor::new(
+            "member-a".to_string(),
+            &pg_id,
+            &sg,
+            &sys_info,
+            None,
+        ));
+        let election_store: RumorStore<ElectionRumor> = RumorStore::default();
+        let election_update_store: RumorStore<ElectionUpdateRumor> = RumorStore::default();
+        let member_list = MemberList::new();
+        let service_config_store: RumorStore<ServiceConfigRumor> = RumorStore::default();
+        let service_file_store: RumorStore<ServiceFileRumor> = RumorStore::default();
+        let mut ring = CensusRing::new("member-a".to_string());
+
+        let bodies = vec!["port = 1", "port = 2\npassword = \"hab-secret:Qk9YLTE=\""];
+        for (i, body) in bodies.into_iter().enumerate() {
+            let mut config =
+                ServiceConfigRumor::new("member-a", sg.clone(), body.to_string().into_bytes());
+            config.set_incarnation(i as u64 + 1);
+            service_config_store.insert(config);
+            ring.update_from_rumors(
+                &service_store,
+                &election_store,
+                &election_update_store,
+                &member_list,
+                &service_config_store,
+                &service_file_store,
+            );
+        }
+
+        // The history is read back as `hab config rollback` does, through the HTTP gateway's JSON
+        let census_group = ring.census_group_for(&sg).unwrap();
+        let history = serde_json::to_value(&census_group.config_history).unwrap();
+        let values: Vec<toml::Value> = history
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| serde_json::from_value(c["value"].clone()).unwrap())
+            .collect();
+        assert!(!config_secret::has_redacted(&values[0]));
+        assert!(config_secret::has_redacted(&values[1]));
+        assert_eq!(
+            values[1].get("password").and_then(|v| v.as_str()),
+            Some(config_secret::REDACTED)
+        );
     }
 }
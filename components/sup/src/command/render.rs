@@ -0,0 +1,468 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Renders the configuration files and hooks of an installed package without loading it as a
+//! service.
+//!
+//! The ring the service would join is described by a mocked census, a JSON object keyed by
+//! service group:
+//!
+//! ```json
+//! {
+//!   "redis.default": {
+//!     "leader": "redis-a",
+//!     "config": { "tcp-backlog": 128 },
+//!     "members": [
+//!       {
+//!         "member_id": "redis-a",
+//!         "pkg": "core/redis/3.2.4/20170514150022",
+//!         "sys": { "ip": "10.0.0.10", "hostname": "redis-a" },
+//!         "cfg": { "port": 6379 }
+//!       }
+//!     ]
+//!   }
+//! }
+//! ```
+//!
+//! Every field is optional. Members are alive unless `"alive": false` is given, `config` is
+//! applied as if it were gossiped with `hab config apply`, and the rendered service itself joins
+//! its own group as the member `local` unless the census already describes that member.
+//!
+//! Only the package's default configuration and the given `user.toml` are used, never the
+//! service's `user.toml` on this machine or configuration in the environment, so a package renders
+//! the same everywhere.
+
+use std::collections::HashMap;
+use std::fs::{self as stdfs, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use butterfly::member::{Health, Member, MemberList};
+use butterfly::rumor::RumorStore;
+use butterfly::rumor::election::Election as ElectionRumor;
+use butterfly::rumor::election::ElectionUpdate as ElectionUpdateRumor;
+use butterfly::rumor::service::{Service as ServiceRumor, SysInfo};
+use butterfly::rumor::service_config::ServiceConfig as ServiceConfigRumor;
+use butterfly::rumor::service_file::ServiceFile as ServiceFileRumor;
+use hcore::fs::FS_ROOT_PATH;
+use hcore::package::{PackageIdent, PackageInstall};
+use hcore::service::ServiceGroup;
+use serde_json;
+use toml;
+
+use census::CensusRing;
+use config::GossipListenAddr;
+use error::{Error, Result};
+use fs;
+use http_gateway::ListenAddr;
+use manager::Sys;
+use manager::service::{Cfg, CfgRenderer, Pkg, ServiceBind};
+use manager::service::hooks::HookTable;
+use templating::RenderContext;
+
+/// Our output key
+static LOGKEY: &'static str = "RD";
+
+/// Member ID of the rendered service in the mocked census.
+pub const LOCAL_MEMBER_ID: &'static str = "local";
+
+type MockCensus = HashMap<String, MockGroup>;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct MockGroup {
+    leader: Option<String>,
+    update_leader: Option<String>,
+    config: Option<toml::value::Table>,
+    members: Vec<MockMember>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct MockMember {
+    member_id: String,
+    pkg: Option<String>,
+    alive: bool,
+    sys: MockSys,
+    cfg: toml::value::Table,
+}
+
+impl Default for MockMember {
+    fn default() -> Self {
+        MockMember {
+            member_id: String::new(),
+            pkg: None,
+            alive: true,
+            sys: MockSys::default(),
+            cfg: toml::value::Table::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct MockSys {
+    ip: String,
+    hostname: String,
+    gossip_ip: String,
+    gossip_port: u32,
+    http_gateway_ip: String,
+    http_gateway_port: u32,
+}
+
+impl Default for MockSys {
+    fn default() -> Self {
+        MockSys {
+            ip: "127.0.0.1".to_string(),
+            hostname: "localhost".to_string(),
+            gossip_ip: "127.0.0.1".to_string(),
+            gossip_port: 9638,
+            http_gateway_ip: "127.0.0.1".to_string(),
+            http_gateway_port: 9631,
+        }
+    }
+}
+
+impl MockSys {
+    fn as_sys_info(&self) -> SysInfo {
+        let mut sys_info = SysInfo::new();
+        sys_info.set_ip(self.ip.clone());
+        sys_info.set_hostname(self.hostname.clone());
+        sys_info.set_gossip_ip(self.gossip_ip.clone());
+        sys_info.set_gossip_port(self.gossip_port);
+        sys_info.set_http_gateway_ip(self.http_gateway_ip.clone());
+        sys_info.set_http_gateway_port(self.http_gateway_port);
+        sys_info
+    }
+}
+
+/// The rumors a ring would have gossiped to produce a census.
+struct Rumors {
+    services: RumorStore<ServiceRumor>,
+    elections: RumorStore<ElectionRumor>,
+    election_updates: RumorStore<ElectionUpdateRumor>,
+    members: MemberList,
+    service_configs: RumorStore<ServiceConfigRumor>,
+    service_files: RumorStore<ServiceFileRumor>,
+}
+
+impl Rumors {
+    fn new() -> Self {
+        Rumors {
+            services: RumorStore::default(),
+            elections: RumorStore::default(),
+            election_updates: RumorStore::default(),
+            members: MemberList::new(),
+            service_configs: RumorStore::default(),
+            service_files: RumorStore::default(),
+        }
+    }
+
+    fn census(&self) -> CensusRing {
+        let mut census = CensusRing::new(LOCAL_MEMBER_ID);
+        census.update_from_rumors(
+            &self.services,
+            &self.elections,
+            &self.election_updates,
+            &self.members,
+            &self.service_configs,
+            &self.service_files,
+        );
+        census
+    }
+
+    fn insert_member(
+        &self,
+        member_id: &str,
+        ident: &PackageIdent,
+        service_group: &ServiceGroup,
+        sys_info: &SysInfo,
+        cfg: &toml::value::Table,
+        alive: bool,
+    ) {
+        self.services.insert(ServiceRumor::new(
+            member_id,
+            ident,
+            service_group,
+            sys_info,
+            Some(cfg),
+        ));
+        let mut member = Member::default();
+        member.set_id(member_id.to_string());
+        member.set_address(sys_info.get_gossip_ip().to_string());
+        member.set_gossip_port(sys_info.get_gossip_port() as i32);
+        let health = if alive {
+            Health::Alive
+        } else {
+            Health::Confirmed
+        };
+        self.members.insert(member, health);
+    }
+}
+
+/// Render the configuration files and hooks of an installed package as the Supervisor would when
+/// running it in the given group, printing each of them and, if an output directory is given,
+/// writing them to its `config` and `hooks` directories.
+pub fn start(
+    ident: &PackageIdent,
+    group: &str,
+    org: Option<&str>,
+    binds: &[ServiceBind],
+    user_toml: Option<&Path>,
+    census_file: Option<&Path>,
+    output: Option<&Path>,
+) -> Result<()> {
+    let install = PackageInstall::load(ident, Some(Path::new(&*FS_ROOT_PATH)))?;
+    let pkg = Pkg::from_install(install)?;
+    let service_group = ServiceGroup::new(None, &pkg.name, group, org)?;
+    let mut sys = Sys::new(false, GossipListenAddr::default(), ListenAddr::default());
+    sys.member_id = LOCAL_MEMBER_ID.to_string();
+
+    let mut cfg = Cfg::from_defaults(&pkg)?;
+    if let Some(path) = user_toml {
+        let mut config = String::new();
+        File::open(path)?.read_to_string(&mut config)?;
+        cfg.user = Some(toml::Value::Table(toml::de::from_str(&config)?));
+    }
+
+    let mock = match census_file {
+        Some(path) => read_census(path)?,
+        None => MockCensus::new(),
+    };
+    let rumors = rumors_from_mock(census_file, &mock, &pkg.ident)?;
+    let local_is_mocked = mock.get(&service_group.to_string()).map_or(false, |g| {
+        g.members.iter().any(|m| m.member_id == LOCAL_MEMBER_ID)
+    });
+    if !local_is_mocked {
+        rumors.insert_member(
+            LOCAL_MEMBER_ID,
+            &pkg.ident,
+            &service_group,
+            &sys.as_sys_info(),
+            &cfg.to_exported(&pkg)?,
+            true,
+        );
+    }
+    let census = rumors.census();
+    if let Some(census_group) = census.census_group_for(&service_group) {
+        // `update` would skip a mocked configuration which doesn't match the package's schema,
+        // rendering as if it had never been given.
+        if let Some(ref config) = census_group.service_config {
+            cfg.validate_gossip(&config.value)?;
+        }
+        cfg.update(census_group);
+    }
+
+    let ctx = RenderContext::new(&service_group, &sys, &pkg, &cfg, &census, binds.iter());
+    let config = CfgRenderer::new(pkg.path.join("config"))?.render(&ctx)?;
+    let hooks = HookTable::load(
+        &service_group,
+        pkg.path.join("hooks"),
+        fs::svc_hooks_path(service_group.service()),
+        &pkg.periodic_hooks,
+    ).render(&ctx)?;
+
+    for (name, content) in config.iter() {
+        print_rendered("config", name, content);
+    }
+    for (name, content) in hooks.iter() {
+        print_rendered("hooks", name, content);
+    }
+    if let Some(output) = output {
+        for (name, content) in config.iter() {
+            write_rendered(&output.join("config"), name, content)?;
+        }
+        for (name, content) in hooks.iter() {
+            write_rendered(&output.join("hooks"), name, content)?;
+        }
+        outputln!("Rendered files written to {}", output.display());
+    }
+    Ok(())
+}
+
+fn read_census(path: &Path) -> Result<MockCensus> {
+    let file = File::open(path)?;
+    serde_json::from_reader(file).map_err(|e| {
+        sup_error!(Error::BadCensusMock(path.to_path_buf(), e.to_string()))
+    })
+}
+
+fn rumors_from_mock(
+    path: Option<&Path>,
+    mock: &MockCensus,
+    local_ident: &PackageIdent,
+) -> Result<Rumors> {
+    let bad_mock = |msg: String| {
+        sup_error!(Error::BadCensusMock(
+            path.map(|p| p.to_path_buf()).unwrap_or(PathBuf::new()),
+            msg,
+        ))
+    };
+    let rumors = Rumors::new();
+    for (name, group) in mock.iter() {
+        let service_group = ServiceGroup::from_str(name).map_err(
+            |e| bad_mock(e.to_string()),
+        )?;
+        for member in group.members.iter() {
+            let ident = match member.pkg {
+                Some(ref pkg) => {
+                    PackageIdent::from_str(pkg).map_err(|e| bad_mock(e.to_string()))?
+                }
+                None if local_ident.name == service_group.service() => local_ident.clone(),
+                None => {
+                    PackageIdent::new(
+                        "mock",
+                        service_group.service(),
+                        Some("0.0.0"),
+                        Some("19700101000000"),
+                    )
+                }
+            };
+            if !ident.fully_qualified() || ident.name != service_group.service() {
+                return Err(bad_mock(format!(
+                    "member {} of {} must run a fully qualified package named {}, not {}",
+                    member.member_id,
+                    service_group,
+                    service_group.service(),
+                    ident
+                )));
+            }
+            rumors.insert_member(
+                &member.member_id,
+                &ident,
+                &service_group,
+                &member.sys.as_sys_info(),
+                &member.cfg,
+                member.alive,
+            );
+        }
+        if let Some(ref leader) = group.leader {
+            let mut election = ElectionRumor::new(leader.as_str(), service_group.clone(), 0);
+            election.finish();
+            rumors.elections.insert(election);
+        }
+        if let Some(ref leader) = group.update_leader {
+            let mut election =
+                ElectionUpdateRumor::new(leader.as_str(), service_group.clone(), 0);
+            election.finish();
+            rumors.election_updates.insert(election);
+        }
+        if let Some(ref config) = group.config {
+            let mut service_config = ServiceConfigRumor::new(
+                LOCAL_MEMBER_ID,
+                service_group.clone(),
+                toml::to_string(config)?.into_bytes(),
+            );
+            service_config.set_incarnation(1);
+            rumors.service_configs.insert(service_config);
+        }
+    }
+    Ok(rumors)
+}
+
+fn print_rendered(kind: &str, name: &str, content: &str) {
+    println!("### {}/{}", kind, name);
+    print!("{}", content);
+    if !content.ends_with('\n') {
+        println!("");
+    }
+    println!("");
+}
+
+fn write_rendered(dir: &Path, name: &str, content: &str) -> Result<()> {
+    let path = dir.join(name);
+    stdfs::create_dir_all(path.parent().unwrap_or(dir))?;
+    let mut file = File::create(path)?;
+    file.write_all(content.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn mock_census_defaults() {
+        let mock: MockCensus = serde_json::from_str(
+            r#"{
+                "redis.default": {
+                    "leader": "redis-a",
+                    "members": [
+                        { "member_id": "redis-a", "sys": { "ip": "10.0.0.10" } },
+                        { "member_id": "redis-b", "alive": false }
+                    ]
+                }
+            }"#,
+        ).unwrap();
+        let group = mock.get("redis.default").unwrap();
+        assert_eq!(group.leader, Some("redis-a".to_string()));
+        assert!(group.config.is_none());
+        assert!(group.members[0].alive);
+        assert_eq!(group.members[0].sys.ip, "10.0.0.10");
+        assert_eq!(group.members[0].sys.gossip_port, 9638);
+        assert!(!group.members[1].alive);
+    }
+
+    #[test]
+    fn census_from_mock() {
+        let mock: MockCensus = serde_json::from_str(
+            r#"{
+                "redis.default": {
+                    "leader": "redis-b",
+                    "config": { "port": 6380 },
+                    "members": [
+                        { "member_id": "redis-a", "cfg": { "port": 6379 } },
+                        { "member_id": "redis-b", "alive": false }
+                    ]
+                }
+            }"#,
+        ).unwrap();
+        let ident = PackageIdent::from_str("core/web/1.0.0/20170101000000").unwrap();
+        let census = rumors_from_mock(None, &mock, &ident).unwrap().census();
+        let sg = ServiceGroup::from_str("redis.default").unwrap();
+        let group = census.census_group_for(&sg).unwrap();
+
+        assert_eq!(group.members().len(), 2);
+        assert_eq!(group.leader().unwrap().member_id, "redis-b");
+        assert!(!group.leader().unwrap().alive());
+        let config = group.service_config.as_ref().unwrap();
+        assert_eq!(config.incarnation, 1);
+        assert_eq!(config.value.as_table().unwrap()["port"].as_integer(), Some(6380));
+        let member = group.members().into_iter().find(|m| m.member_id == "redis-a").unwrap();
+        assert_eq!(member.pkg.as_ref().unwrap().name, "redis");
+        assert_eq!(member.cfg["port"].as_integer(), Some(6379));
+    }
+
+    #[test]
+    fn census_from_mock_with_mismatched_package() {
+        let mock: MockCensus = serde_json::from_str(
+            r#"{
+                "redis.default": {
+                    "members": [{ "member_id": "redis-a", "pkg": "core/nginx/1.0.0/20170101" }]
+                }
+            }"#,
+        ).unwrap();
+        let ident = PackageIdent::from_str("core/web/1.0.0/20170101000000").unwrap();
+        match rumors_from_mock(None, &mock, &ident) {
+            Err(e) => {
+                match e.err {
+                    Error::BadCensusMock(_, _) => (),
+                    e => panic!("Unexpected error returned: {:?}", e),
+                }
+            }
+            Ok(_) => panic!("Mocked member running the wrong package should fail"),
+        }
+    }
+}
@@ -105,40 +105,57 @@ impl SupError {
 #[derive(Debug)]
 pub enum Error {
     Departed,
+    BadCanarySize(String),
+    BadCensusMock(PathBuf, String),
     BadCompositesPath(PathBuf, io::Error),
     BadDataFile(PathBuf, io::Error),
     BadDataPath(PathBuf, io::Error),
     BadDesiredState(String),
     BadElectionStatus(String),
+    BadHealthCheck(String),
+    BadHealthCheckTimeoutStatus(String),
     BadPackage(PackageInstall, hcore::error::Error),
+    BadRestartPolicy(String),
+    BadRunMode(String),
     BadSpecsPath(PathBuf, io::Error),
     BadStartStyle(String),
+    BadUpdateWindow(String),
     BadEnvConfig(String),
     ButterflyError(butterfly::error::Error),
     DepotClient(depot_client::Error),
     EnvJoinPathsError(env::JoinPathsError),
     ExecCommandNotFound(String),
+    ExportedSecret(String),
     FileNotFound(String),
     FileWatcherFileIsRoot,
     HabitatCommon(common::Error),
     HabitatCore(hcore::Error),
+    HttpGatewayControlDisabled,
+    HttpGatewayTls(String),
+    HttpGatewayUnauthorized,
     TemplateFileError(handlebars::TemplateFileError),
     TemplateRenderError(handlebars::RenderError),
     InvalidBinding(String),
     InvalidBinds(Vec<String>),
     InvalidCompositeBinding(String),
+    InvalidHealthProbe(String),
+    InvalidHookTimeout(String),
+    InvalidResourceLimit(String),
     InvalidKeyParameter(String),
     InvalidPidFile,
     InvalidTopology(String),
     InvalidUpdateStrategy(String),
     Io(io::Error),
     IPFailed,
+    KillTreeFailed(u32, String),
     Launcher(launcher_client::Error),
     MissingRequiredBind(Vec<String>),
     MissingRequiredIdent,
     NameLookup(io::Error),
     NetParseError(net::AddrParseError),
     NoLauncher,
+    NoInstalledRelease(package::PackageIdent, package::VersionReq),
+    NoMatchingRelease(package::PackageIdent, package::VersionReq, String),
     NotifyCreateError(notify::Error),
     NotifyError(notify::Error),
     NulError(ffi::NulError),
@@ -151,6 +168,7 @@ pub enum Error {
     ProcessLockIO(PathBuf, io::Error),
     RecvError(mpsc::RecvError),
     RenderContextSerialization(serde_json::Error),
+    ResourceLimitsUnsupported,
     ServiceDeserializationError(serde_json::Error),
     ServiceLoaded(package::PackageIdent),
     ServiceNotLoaded(package::PackageIdent),
@@ -205,6 +223,13 @@ impl fmt::Display for SupError {
                 format!("Unknown service desired state style '{}'", state)
             }
             Error::BadElectionStatus(ref status) => format!("Unknown election status '{}'", status),
+            Error::BadHealthCheck(ref status) => format!("Unknown health check status '{}'", status),
+            Error::BadHealthCheckTimeoutStatus(ref status) => {
+                format!(
+                    "Invalid health check timeout status '{}', must be unknown or critical",
+                    status
+                )
+            }
             Error::BadPackage(ref pkg, ref err) => format!("Bad package, {}, {}", pkg, err),
             Error::BadSpecsPath(ref path, ref err) => {
                 format!(
@@ -213,7 +238,26 @@ impl fmt::Display for SupError {
                     err
                 )
             }
+            Error::BadRestartPolicy(ref policy) => {
+                format!("Unknown service restart policy '{}'", policy)
+            }
+            Error::BadRunMode(ref mode) => format!("Unknown service run mode '{}'", mode),
+            Error::BadCensusMock(ref path, ref err) => {
+                format!("Unable to parse mocked census, {}, {}", path.display(), err)
+            }
+            Error::BadCanarySize(ref size) => {
+                format!(
+                    "Canary size '{}' must be a number of members or a percentage, e.g. 2 or 10%",
+                    size
+                )
+            }
             Error::BadStartStyle(ref style) => format!("Unknown service start style '{}'", style),
+            Error::BadUpdateWindow(ref window) => {
+                format!(
+                    "Invalid update window '{}', expected e.g. 'mon-fri 02:00-04:00 UTC'",
+                    window
+                )
+            }
             Error::BadEnvConfig(ref varname) => {
                 format!("Unable to find valid TOML or JSON in {} ENVVAR", varname)
             }
@@ -221,9 +265,23 @@ impl fmt::Display for SupError {
             Error::ExecCommandNotFound(ref c) => {
                 format!("`{}' was not found on the filesystem or in PATH", c)
             }
+            Error::ExportedSecret(ref key) => {
+                format!(
+                    "Export '{}' holds an encrypted secret, which can't be exported to other \
+                     service groups",
+                    key
+                )
+            }
             Error::Permissions(ref err) => format!("{}", err),
             Error::HabitatCommon(ref err) => format!("{}", err),
             Error::HabitatCore(ref err) => format!("{}", err),
+            Error::HttpGatewayControlDisabled => {
+                format!("Control routes are disabled, no gateway auth token was configured")
+            }
+            Error::HttpGatewayTls(ref e) => {
+                format!("Unable to load the HTTP gateway TLS certificate or key, {}", e)
+            }
+            Error::HttpGatewayUnauthorized => format!("Missing or invalid gateway auth token"),
             Error::TemplateFileError(ref err) => format!("{:?}", err),
             Error::TemplateRenderError(ref err) => format!("{}", err),
             Error::DepotClient(ref err) => format!("{}", err),
@@ -246,6 +304,17 @@ impl fmt::Display for SupError {
                     binding
                 )
             }
+            Error::InvalidHealthProbe(ref err) => format!("Invalid health probe, {}", err),
+            Error::InvalidHookTimeout(ref hook) => {
+                format!(
+                    "Invalid hook timeout for '{}', timeouts may be set for any hook except run \
+                     and health_check",
+                    hook
+                )
+            }
+            Error::InvalidResourceLimit(ref limit) => {
+                format!("Invalid resource limit, {} must be greater than zero", limit)
+            }
             Error::InvalidKeyParameter(ref e) => {
                 format!("Invalid parameter for key generation: {:?}", e)
             }
@@ -254,6 +323,9 @@ impl fmt::Display for SupError {
             Error::InvalidUpdateStrategy(ref s) => format!("Invalid update strategy: {}", s),
             Error::Io(ref err) => format!("{}", err),
             Error::IPFailed => format!("Failed to discover this hosts outbound IP address"),
+            Error::KillTreeFailed(pid, ref err) => {
+                format!("Unable to kill process tree of pid {}, {}", pid, err)
+            }
             Error::Launcher(ref err) => format!("{}", err),
             Error::MissingRequiredBind(ref e) => {
                 format!("Missing required bind(s), {}", e.join(", "))
@@ -264,6 +336,21 @@ impl fmt::Display for SupError {
             Error::NameLookup(ref e) => format!("Error resolving a name or IP address: {}", e),
             Error::NetParseError(ref e) => format!("Can't parse ip:port: {}", e),
             Error::NoLauncher => format!("Supervisor must be run from `hab-launch`"),
+            Error::NoInstalledRelease(ref ident, ref req) => {
+                format!(
+                    "No installed release of {} matches the version requirement {}",
+                    ident,
+                    req
+                )
+            }
+            Error::NoMatchingRelease(ref ident, ref req, ref channel) => {
+                format!(
+                    "No release of {} in the {} channel matches the version requirement {}",
+                    ident,
+                    channel,
+                    req
+                )
+            }
             Error::NotifyCreateError(ref e) => format!("Notify create error: {}", e),
             Error::NotifyError(ref e) => format!("Notify error: {}", e),
             Error::NulError(ref e) => format!("{}", e),
@@ -302,6 +389,9 @@ impl fmt::Display for SupError {
             Error::RenderContextSerialization(ref e) => {
                 format!("Unable to serialize rendering context, {}", e)
             }
+            Error::ResourceLimitsUnsupported => {
+                format!("Resource limits are only supported on Linux")
+            }
             Error::ServiceDeserializationError(ref e) => {
                 format!("Can't deserialize service status: {}", e)
             }
@@ -365,16 +455,29 @@ impl error::Error for SupError {
             Error::BadDataPath(_, _) => "Unable to read or write to data directory",
             Error::BadElectionStatus(_) => "Unknown election status",
             Error::BadDesiredState(_) => "Unknown desired state in service spec",
+            Error::BadHealthCheck(_) => "Unknown health check status",
+            Error::BadHealthCheckTimeoutStatus(_) => {
+                "Health check timeout status must be unknown or critical"
+            }
             Error::BadPackage(_, _) => "Package was malformed or contained malformed contents",
+            Error::BadRestartPolicy(_) => "Unknown restart policy in service spec",
+            Error::BadRunMode(_) => "Unknown run mode in service spec",
+            Error::BadCanarySize(_) => "Canary size must be a number of members or a percentage",
+            Error::BadCensusMock(_, _) => "Unable to parse a mocked census",
             Error::BadSpecsPath(_, _) => "Unable to create the specs directory",
             Error::BadStartStyle(_) => "Unknown start style in service spec",
+            Error::BadUpdateWindow(_) => "Invalid update window in service spec",
             Error::BadEnvConfig(_) => "Unknown syntax in Env Configuration",
             Error::ButterflyError(ref err) => err.description(),
             Error::ExecCommandNotFound(_) => "Exec command was not found on filesystem or in PATH",
+            Error::ExportedSecret(_) => "Export holds an encrypted secret",
             Error::TemplateFileError(ref err) => err.description(),
             Error::TemplateRenderError(ref err) => err.description(),
             Error::HabitatCommon(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
+            Error::HttpGatewayControlDisabled => "HTTP gateway control routes are disabled",
+            Error::HttpGatewayTls(_) => "Unable to load the HTTP gateway TLS certificate or key",
+            Error::HttpGatewayUnauthorized => "HTTP gateway request was not authorized",
             Error::DepotClient(ref err) => err.description(),
             Error::EnvJoinPathsError(ref err) => err.description(),
             Error::FileNotFound(_) => "File not found",
@@ -384,12 +487,16 @@ impl error::Error for SupError {
                 "Service binds detected that are neither required nor optional package binds"
             }
             Error::InvalidCompositeBinding(_) => "Invalid binding parameter",
+            Error::InvalidHealthProbe(_) => "Invalid health probe in service spec",
+            Error::InvalidHookTimeout(_) => "Invalid hook timeout in service spec",
+            Error::InvalidResourceLimit(_) => "Invalid resource limit in service spec",
             Error::InvalidKeyParameter(_) => "Key parameter error",
             Error::InvalidPidFile => "Invalid child process PID file",
             Error::InvalidTopology(_) => "Invalid topology",
             Error::InvalidUpdateStrategy(_) => "Invalid update strategy",
             Error::Io(ref err) => err.description(),
             Error::IPFailed => "Failed to discover the outbound IP address",
+            Error::KillTreeFailed(_, _) => "Unable to kill process tree",
             Error::Launcher(ref err) => err.description(),
             Error::MissingRequiredBind(_) => {
                 "A service to start without specifying a service group for all required binds"
@@ -400,6 +507,10 @@ impl error::Error for SupError {
             Error::NetParseError(_) => "Can't parse IP:port",
             Error::NameLookup(_) => "Error resolving a name or IP address",
             Error::NoLauncher => "Supervisor must be run from `hab-launch`",
+            Error::NoInstalledRelease(_, _) => {
+                "No installed release matches the version requirement"
+            }
+            Error::NoMatchingRelease(_, _, _) => "No release matches the version requirement",
             Error::NotifyCreateError(_) => "Notify create error",
             Error::NotifyError(_) => "Notify error",
             Error::NulError(_) => {
@@ -416,6 +527,7 @@ impl error::Error for SupError {
             Error::ProcessLockIO(_, _) => "Unable to read or write to a process lock",
             Error::RecvError(_) => "A channel failed to receive a response",
             Error::RenderContextSerialization(_) => "Unable to serialize rendering context",
+            Error::ResourceLimitsUnsupported => "Resource limits are unsupported on this platform",
             Error::ServiceDeserializationError(_) => "Can't deserialize service status",
             Error::ServiceNotLoaded(_) => "Service status called when service not loaded",
             Error::ServiceLoaded(_) => "Service load or start called when service already loaded",
@@ -12,35 +12,68 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
 use std::collections::HashMap;
 use std::fmt;
-use std::fs::File;
-use std::io::{self, Read};
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom, Write};
 use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs, SocketAddr, SocketAddrV4};
 use std::ops::{Deref, DerefMut};
 use std::option;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::result;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
 use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
+use common::ui::UI;
+use hcore::package::{PackageIdent, PackageInstall, VersionReq};
+use hcore::package::metadata::PackageType;
 use hcore::service::{ApplicationEnvironment, ServiceGroup};
+use hyper_openssl::OpensslServer;
 use iron::prelude::*;
 use iron::{headers, status, typemap};
+use iron::headers::{Authorization, Bearer, CacheControl, CacheDirective};
+use iron::middleware::{BeforeMiddleware, Handler};
+use iron::mime::Mime;
 use iron::modifiers::Header;
+use iron::response::WriteBody;
 use persistent;
 use prometheus::{self, CounterVec, HistogramVec, TextEncoder, Encoder};
 use router::Router;
 use serde_json::{self, Value as Json};
+use url;
 
 use error::{Result, Error, SupError};
 use manager;
-use manager::service::HealthCheck;
+use manager::change_feed::{ChangeEvent, ChangeFeed};
+use manager::service::{DesiredState, HealthCheck, ServiceBind, ServiceSpec, StartStyle, Topology,
+                       UpdateStrategy};
 use manager::service::hooks::{self, HealthCheckHook};
+use util;
 
 static LOGKEY: &'static str = "HG";
 const APIDOCS: &'static str = include_str!(concat!(env!("OUT_DIR"), "/api.html"));
+/// How often an idle event stream writes a comment line, so that clients which went away are
+/// noticed and their connection released.
+const EVENT_STREAM_KEEPALIVE_MS: u64 = 15_000;
+/// Most responses which stream to their client for as long as it stays connected. Each one holds
+/// one of the gateway's fixed number of worker threads, so requests for more are turned away to
+/// keep the rest of the gateway responsive.
+const MAX_STREAMS: usize = 4;
+/// Number of lines returned by the logs routes when the request does not ask for a number.
+const DEFAULT_LOG_LINES: usize = 100;
+/// Most lines returned by the logs routes, however many the request asks for.
+const MAX_LOG_LINES: usize = 10_000;
+/// How often a followed log file is checked for new output.
+const LOG_FOLLOW_INTERVAL_MS: u64 = 1_000;
+/// How long a followed log may go without new output before the response ends. A client which
+/// went away is only noticed when output is written to it, so this bounds how long one holds a
+/// worker thread of the gateway.
+const LOG_FOLLOW_IDLE_TIMEOUT_SECS: u64 = 300;
 
 // Simple macro to encapsulate the HTTP metrics for each endpoint
 macro_rules! with_metrics {
@@ -135,21 +168,197 @@ impl fmt::Display for ListenAddr {
     }
 }
 
+/// Certificate chain and private key, both PEM encoded, used to serve the gateway over TLS.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TlsCfg {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+}
+
 struct ManagerFs;
 
 impl typemap::Key for ManagerFs {
     type Value = manager::FsCfg;
 }
 
-pub struct Server(Iron<Chain>, ListenAddr);
+struct ManagerChangeFeed;
+
+impl typemap::Key for ManagerChangeFeed {
+    type Value = ChangeFeed;
+}
+
+struct ActiveStreams;
+
+impl typemap::Key for ActiveStreams {
+    type Value = AtomicUsize;
+}
+
+/// One of the `MAX_STREAMS` streaming responses, given back when the response is dropped.
+struct StreamSlot(Arc<AtomicUsize>);
+
+impl StreamSlot {
+    /// Take a free slot out of the given count of active streams, if there is one.
+    fn claim(active: Arc<AtomicUsize>) -> Option<Self> {
+        let mut current = active.load(Ordering::SeqCst);
+        loop {
+            if current >= MAX_STREAMS {
+                return None;
+            }
+            let previous = active.compare_and_swap(current, current + 1, Ordering::SeqCst);
+            if previous == current {
+                return Some(StreamSlot(active));
+            }
+            current = previous;
+        }
+    }
+}
+
+impl Drop for StreamSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Response body of the `/events` route. Writes each `ChangeEvent` as a Server-Sent Event until
+/// the client disconnects.
+struct EventStream {
+    events: Receiver<Arc<ChangeEvent>>,
+    _slot: StreamSlot,
+}
+
+impl WriteBody for EventStream {
+    fn write_body(&mut self, res: &mut Write) -> io::Result<()> {
+        loop {
+            match self.events.recv_timeout(
+                Duration::from_millis(EVENT_STREAM_KEEPALIVE_MS),
+            ) {
+                Ok(event) => {
+                    write!(
+                        res,
+                        "id: {}\nevent: {}\ndata: {}\n\n",
+                        event.id,
+                        event.name(),
+                        serde_json::to_string(&*event).unwrap()
+                    )?
+                }
+                Err(RecvTimeoutError::Timeout) => res.write_all(b": keep-alive\n\n")?,
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+            res.flush()?;
+        }
+    }
+}
+
+/// Response body of the logs routes when following a log. Writes the requested tail of the log
+/// and then any output appended to it until the client disconnects, or until the log has had no
+/// new output for `LOG_FOLLOW_IDLE_TIMEOUT_SECS`.
+struct LogFollower {
+    path: PathBuf,
+    pos: u64,
+    tail: Vec<u8>,
+    _slot: StreamSlot,
+}
+
+impl WriteBody for LogFollower {
+    fn write_body(&mut self, res: &mut Write) -> io::Result<()> {
+        res.write_all(&self.tail)?;
+        res.flush()?;
+        let idle_timeout = Duration::from_secs(LOG_FOLLOW_IDLE_TIMEOUT_SECS);
+        let mut last_output = Instant::now();
+        loop {
+            if last_output.elapsed() >= idle_timeout {
+                return Ok(());
+            }
+            thread::sleep(Duration::from_millis(LOG_FOLLOW_INTERVAL_MS));
+            let mut file = match File::open(&self.path) {
+                Ok(file) => file,
+                // The log may be briefly missing while it is being rotated.
+                Err(_) => continue,
+            };
+            let len = file.metadata()?.len();
+            if len < self.pos {
+                // The log was truncated or replaced, start over from its beginning.
+                self.pos = 0;
+            }
+            if len == self.pos {
+                continue;
+            }
+            file.seek(SeekFrom::Start(self.pos))?;
+            self.pos += io::copy(&mut file.take(len - self.pos), res)?;
+            res.flush()?;
+            last_output = Instant::now();
+        }
+    }
+}
+
+/// Requires requests to carry an `Authorization: Bearer <token>` header matching the token the
+/// Supervisor was started with.
+///
+/// When a token is configured this guards every route of the gateway. The write-capable control
+/// routes are additionally guarded on their own so that, if no token was configured, they are
+/// disabled entirely and every request to them is rejected.
+#[derive(Clone)]
+struct Authenticated(Option<String>);
+
+impl BeforeMiddleware for Authenticated {
+    fn before(&self, req: &mut Request) -> IronResult<()> {
+        let expected = match self.0 {
+            Some(ref token) => token,
+            None => {
+                let err = sup_error!(Error::HttpGatewayControlDisabled);
+                return Err(IronError::new(err, status::Forbidden));
+            }
+        };
+        match req.headers.get::<Authorization<Bearer>>() {
+            Some(&Authorization(Bearer { ref token })) if tokens_match(token, expected) => Ok(()),
+            _ => {
+                let err = sup_error!(Error::HttpGatewayUnauthorized);
+                Err(IronError::new(err, status::Unauthorized))
+            }
+        }
+    }
+}
+
+/// Compare a given token to the expected one in a time which doesn't depend on where they
+/// differ, so that the token can't be guessed one byte at a time from how long rejections take.
+fn tokens_match(given: &str, expected: &str) -> bool {
+    let (given, expected) = (given.as_bytes(), expected.as_bytes());
+    if given.len() != expected.len() {
+        return false;
+    }
+    given.iter().zip(expected).fold(
+        0,
+        |diff, (a, b)| diff | (a ^ b),
+    ) == 0
+}
+
+/// Wrap a handler in a `Chain` which requires the request to be authenticated first.
+fn authenticated<H>(handler: H, auth: &Authenticated) -> Chain
+where
+    H: Handler,
+{
+    let mut chain = Chain::new(handler);
+    chain.link_before(auth.clone());
+    chain
+}
+
+pub struct Server(Iron<Chain>, ListenAddr, Option<TlsCfg>);
 
 impl Server {
-    pub fn new(manager_state: Arc<manager::FsCfg>, listen_addr: ListenAddr) -> Self {
+    pub fn new(
+        manager_state: Arc<manager::FsCfg>,
+        listen_addr: ListenAddr,
+        auth_token: Option<String>,
+        tls: Option<TlsCfg>,
+        change_feed: ChangeFeed,
+    ) -> Self {
+        let auth = Authenticated(auth_token);
         let router =
             router!(
             doc: get "/" => with_metrics!(doc, "doc"),
             butterfly: get "/butterfly" => with_metrics!(butterfly, "butterfly"),
             census: get "/census" => with_metrics!(census, "census"),
+            events: get "/events" => with_metrics!(events, "events"),
             metrics: get "/metrics" => with_metrics!(metrics, "metrics"),
             services: get "/services" => with_metrics!(services, "services"),
             service: get "/services/:svc/:group" => {
@@ -165,22 +374,75 @@ impl Server {
             service_config_org: get "/services/:svc/:group/:org/config" => {
                 with_metrics!(config, "config")
             },
+            service_config_history: get "/services/:svc/:group/config/history" => {
+                with_metrics!(config_history, "config_history")
+            },
+            service_config_history_org: get "/services/:svc/:group/:org/config/history" => {
+                with_metrics!(config_history, "config_history")
+            },
             service_health_org: get "/services/:svc/:group/:org/health" => {
                 with_metrics!(health, "config")
+            },
+            service_logs: get "/services/:svc/:group/logs/:log" => with_metrics!(logs, "logs"),
+            service_logs_org: get "/services/:svc/:group/:org/logs/:log" => {
+                with_metrics!(logs, "logs")
+            },
+            service_load: post "/services" => {
+                authenticated(with_metrics!(service_load, "service_load"), &auth)
+            },
+            service_reconfigure: put "/services/:svc/:group" => {
+                authenticated(with_metrics!(service_reconfigure, "service_reconfigure"), &auth)
+            },
+            service_reconfigure_org: put "/services/:svc/:group/:org" => {
+                authenticated(with_metrics!(service_reconfigure, "service_reconfigure"), &auth)
+            },
+            service_unload: delete "/services/:svc/:group" => {
+                authenticated(with_metrics!(service_unload, "service_unload"), &auth)
+            },
+            service_unload_org: delete "/services/:svc/:group/:org" => {
+                authenticated(with_metrics!(service_unload, "service_unload"), &auth)
+            },
+            service_start: post "/services/:svc/:group/start" => {
+                authenticated(with_metrics!(service_start, "service_start"), &auth)
+            },
+            service_start_org: post "/services/:svc/:group/:org/start" => {
+                authenticated(with_metrics!(service_start, "service_start"), &auth)
+            },
+            service_stop: post "/services/:svc/:group/stop" => {
+                authenticated(with_metrics!(service_stop, "service_stop"), &auth)
+            },
+            service_stop_org: post "/services/:svc/:group/:org/stop" => {
+                authenticated(with_metrics!(service_stop, "service_stop"), &auth)
             }
         );
         let mut chain = Chain::new(router);
         chain.link(persistent::Read::<ManagerFs>::both(manager_state));
-        Server(Iron::new(chain), listen_addr)
+        chain.link(persistent::Read::<ManagerChangeFeed>::both(change_feed));
+        chain.link(persistent::Read::<ActiveStreams>::both(AtomicUsize::new(0)));
+        if auth.0.is_some() {
+            chain.link_before(auth);
+        }
+        Server(Iron::new(chain), listen_addr, tls)
     }
 
     pub fn start(self) -> Result<JoinHandle<()>> {
+        let Server(iron, listen_addr, tls) = self;
+        let ssl = match tls {
+            Some(tls) => {
+                let ssl = OpensslServer::from_files(&tls.key_path, &tls.cert_path)
+                    .map_err(|err| sup_error!(Error::HttpGatewayTls(err.to_string())))?;
+                Some(ssl)
+            }
+            None => None,
+        };
         let handle = thread::Builder::new()
             .name("http-gateway".to_string())
             .spawn(move || {
-                self.0.http(*self.1).expect(
-                    "unable to start http-gateway thread",
-                );
+                let listening = match ssl {
+                    Some(ssl) => iron.https(*listen_addr, ssl),
+                    None => iron.http(*listen_addr),
+                };
+                listening.expect("unable to start http-gateway thread");
             })?;
         Ok(handle)
     }
@@ -192,6 +454,84 @@ struct HealthCheckBody {
     stderr: String,
 }
 
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+/// Body of a load request, which holds the only fields of a spec a load through the gateway may
+/// set. The others, such as the command of a health probe or `config_from`, would let a caller
+/// run commands or read files on the Supervisor's host, so a body with any of them is rejected.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ServiceSpecLoad {
+    ident: String,
+    group: Option<String>,
+    application_environment: Option<String>,
+    channel: Option<String>,
+    version_req: Option<VersionReq>,
+    topology: Option<Topology>,
+    update_strategy: Option<UpdateStrategy>,
+    binds: Option<Vec<ServiceBind>>,
+}
+
+impl ServiceSpecLoad {
+    /// The spec of a persistent service with the loaded fields and defaults for the rest.
+    fn into_spec(self) -> result::Result<ServiceSpec, String> {
+        let ident = PackageIdent::from_str(&self.ident).map_err(|e| e.to_string())?;
+        let mut spec = ServiceSpec::default_for(ident);
+        if let Some(group) = self.group {
+            spec.group = group;
+        }
+        if let Some(environment) = self.application_environment {
+            spec.application_environment = Some(ApplicationEnvironment::from_str(&environment)
+                .map_err(|e| e.to_string())?);
+        }
+        spec.version_req = self.version_req;
+        spec.start_style = StartStyle::Persistent;
+        ServiceSpecUpdate {
+            bldr_url: None,
+            channel: self.channel,
+            topology: self.topology,
+            update_strategy: self.update_strategy,
+            binds: self.binds,
+        }.apply(&mut spec);
+        Ok(spec)
+    }
+}
+
+/// Body of a reconfigure request. Only the fields present in the request are changed on the
+/// service's spec.
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct ServiceSpecUpdate {
+    bldr_url: Option<String>,
+    channel: Option<String>,
+    topology: Option<Topology>,
+    update_strategy: Option<UpdateStrategy>,
+    binds: Option<Vec<ServiceBind>>,
+}
+
+impl ServiceSpecUpdate {
+    fn apply(self, spec: &mut ServiceSpec) {
+        if let Some(bldr_url) = self.bldr_url {
+            spec.bldr_url = bldr_url;
+        }
+        if let Some(channel) = self.channel {
+            spec.channel = channel;
+        }
+        if let Some(topology) = self.topology {
+            spec.topology = topology;
+        }
+        if let Some(update_strategy) = self.update_strategy {
+            spec.update_strategy = update_strategy;
+        }
+        if let Some(binds) = self.binds {
+            spec.binds = binds;
+        }
+    }
+}
+
 fn butterfly(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
     match File::open(&state.butterfly_data_path) {
@@ -212,6 +552,148 @@ fn census(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn events(req: &mut Request) -> IronResult<Response> {
+    let slot = match StreamSlot::claim(req.get::<persistent::Read<ActiveStreams>>().unwrap()) {
+        Some(slot) => slot,
+        None => return Ok(too_many_streams()),
+    };
+    let feed = req.get::<persistent::Read<ManagerChangeFeed>>().unwrap();
+    let mime: Mime = "text/event-stream".parse().unwrap();
+    let mut response = Response::with((
+        status::Ok,
+        Header(headers::ContentType(mime)),
+        Header(CacheControl(vec![CacheDirective::NoCache])),
+    ));
+    response.body = Some(Box::new(EventStream {
+        events: feed.subscribe(),
+        _slot: slot,
+    }));
+    Ok(response)
+}
+
+fn too_many_streams() -> Response {
+    error_response(
+        status::ServiceUnavailable,
+        format!("Already streaming to {} clients, try again later", MAX_STREAMS),
+    )
+}
+
+fn logs(req: &mut Request) -> IronResult<Response> {
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let log = req.extensions
+        .get::<Router>()
+        .unwrap()
+        .find("log")
+        .unwrap()
+        .to_string();
+    let paths = match hooks::log_paths(&service_group, &log) {
+        Some(paths) => Some(paths),
+        None => {
+            let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+            match service_from_file(&service_group, &state.services_data_path) {
+                Ok(Some(service)) => periodic_log_paths(&service, &log),
+                _ => None,
+            }
+        }
+    };
+    let (stdout_path, stderr_path) = match paths {
+        Some(paths) => paths,
+        None => {
+            return Ok(error_response(
+                status::NotFound,
+                format!("Unknown log '{}'", log),
+            ))
+        }
+    };
+    let mut lines = DEFAULT_LOG_LINES;
+    let mut path = stdout_path;
+    let mut follow = false;
+    let query = req.url.query().unwrap_or("").to_string();
+    for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+        match key.as_ref() {
+            "lines" => {
+                lines = match value.parse() {
+                    Ok(lines) => cmp::min(lines, MAX_LOG_LINES),
+                    Err(_) => {
+                        return Ok(error_response(
+                            status::BadRequest,
+                            format!("Invalid number of lines '{}'", value),
+                        ))
+                    }
+                }
+            }
+            "stream" => {
+                match value.as_ref() {
+                    "stdout" => (),
+                    "stderr" => path = stderr_path.clone(),
+                    _ => {
+                        return Ok(error_response(
+                            status::BadRequest,
+                            format!("Invalid stream '{}', must be stdout or stderr", value),
+                        ))
+                    }
+                }
+            }
+            "follow" => follow = value == "true" || value == "1",
+            _ => (),
+        }
+    }
+    let (tail, pos) = match File::open(&path) {
+        Ok(mut file) => {
+            match util::tail::last_lines(&mut file, lines) {
+                Ok(tail) => tail,
+                Err(err) => {
+                    return Ok(error_response(
+                        status::InternalServerError,
+                        err.to_string(),
+                    ))
+                }
+            }
+        }
+        Err(_) => return Ok(Response::with(status::NotFound)),
+    };
+    let mut response = Response::with((status::Ok, Header(headers::ContentType::plaintext())));
+    if follow {
+        let slot = match StreamSlot::claim(req.get::<persistent::Read<ActiveStreams>>().unwrap()) {
+            Some(slot) => slot,
+            None => return Ok(too_many_streams()),
+        };
+        response.headers.set(CacheControl(vec![CacheDirective::NoCache]));
+        response.body = Some(Box::new(LogFollower {
+            path: path,
+            pos: pos,
+            tail: tail,
+            _slot: slot,
+        }));
+    } else {
+        response.body = Some(Box::new(tail));
+    }
+    Ok(response)
+}
+
+/// Paths to the standard output and standard error logs of a periodic hook named like
+/// `periodic-backup`, found among the periodic hooks of the service's `HookTable`.
+fn periodic_log_paths(service: &Json, log: &str) -> Option<(PathBuf, PathBuf)> {
+    let prefix = format!("{}-", hooks::PERIODIC_HOOKS_DIR);
+    if !log.starts_with(&prefix) {
+        return None;
+    }
+    let name = &log[prefix.len()..];
+    let periodic = match service["hooks"]["periodic"].as_array() {
+        Some(periodic) => periodic,
+        None => return None,
+    };
+    periodic.iter().find(|hook| hook["name"] == name).and_then(|hook| {
+        match (hook["stdout_log_path"].as_str(), hook["stderr_log_path"].as_str()) {
+            (Some(stdout), Some(stderr)) => Some((PathBuf::from(stdout), PathBuf::from(stderr))),
+            _ => None,
+        }
+    })
+}
+
 fn config(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
     let service_group = match build_service_group(req) {
@@ -231,6 +713,33 @@ fn config(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn config_history(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let census: Json = match File::open(&state.census_data_path) {
+        Ok(file) => {
+            match serde_json::from_reader(file) {
+                Ok(census) => census,
+                Err(_) => return Ok(Response::with(status::ServiceUnavailable)),
+            }
+        }
+        Err(_) => return Ok(Response::with(status::ServiceUnavailable)),
+    };
+    match census["census_groups"].get(service_group.as_ref()) {
+        Some(census_group) => {
+            Ok(Response::with((
+                status::Ok,
+                Header(headers::ContentType::json()),
+                census_group["config_history"].to_string(),
+            )))
+        }
+        None => Ok(Response::with(status::NotFound)),
+    }
+}
+
 fn health(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
     let (health_file, stdout_path, stderr_path) = match build_service_group(req) {
@@ -295,6 +804,183 @@ fn services(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn service_load(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let load: ServiceSpecLoad = match serde_json::from_reader(&mut req.body) {
+        Ok(load) => load,
+        Err(err) => return Ok(error_response(status::BadRequest, err.to_string())),
+    };
+    let spec = match load.into_spec() {
+        Ok(spec) => spec,
+        Err(err) => return Ok(error_response(status::BadRequest, err)),
+    };
+    let spec_file = state.spec_path_for(&spec);
+    // Spares installing the package of a loaded service. The spec file is still only ever
+    // created, never replaced, by `load_spec` should the service be loaded in the meantime.
+    if spec_file.is_file() {
+        return Ok(error_response(status::Conflict, already_loaded(&spec.ident)));
+    }
+    let package = match util::pkg::installed_matching(&spec.ident, spec.version_req.as_ref()) {
+        Some(package) => package,
+        None => {
+            // Installing can take minutes, which would hold one of the gateway's few worker
+            // threads for as long. The package is installed in the background instead and the
+            // service is loaded once it is in place.
+            outputln!("Missing package for {}, installing it in the background", &spec.ident);
+            let body = serde_json::to_string(&spec).unwrap();
+            let install = thread::Builder::new()
+                .name(format!("http-gateway-install-{}", spec.ident.name))
+                .spawn(move || install_and_load(spec, spec_file));
+            return match install {
+                Ok(_) => Ok(Response::with(
+                    (status::Accepted, Header(headers::ContentType::json()), body),
+                )),
+                Err(err) => Ok(error_response(
+                    status::InternalServerError,
+                    err.to_string(),
+                )),
+            };
+        }
+    };
+    match load_spec(&spec, &spec_file, &package) {
+        Ok(()) => Ok(Response::with((
+            status::Created,
+            Header(headers::ContentType::json()),
+            serde_json::to_string(&spec).unwrap(),
+        ))),
+        Err((status, err)) => Ok(error_response(status, err)),
+    }
+}
+
+/// Install the package of a service loaded through the gateway and then load the service.
+fn install_and_load(spec: ServiceSpec, spec_file: PathBuf) {
+    let package = match util::pkg::install_matching(
+        &mut UI::with_sinks(),
+        &spec.bldr_url,
+        &spec.ident.clone().into(),
+        spec.version_req.as_ref(),
+        &spec.channel,
+    ) {
+        Ok(package) => package,
+        Err(err) => {
+            outputln!("Unable to install {} for the http-gateway, {}", &spec.ident, err);
+            return;
+        }
+    };
+    if let Err((_, err)) = load_spec(&spec, &spec_file, &package) {
+        outputln!("Unable to load {} through the http-gateway, {}", &spec.ident, err);
+    }
+}
+
+/// Validate the spec of a service against its installed package and create the given spec file,
+/// which the Manager picks up to load the service, unless the service is already loaded. The
+/// `Err` holds the status and message of the response to return.
+///
+/// Only standalone packages are loaded. Unlike `hab svc load`, the gateway doesn't write the
+/// `CompositeSpec` and the specs of every service in a composite, so a composite package is
+/// refused.
+fn load_spec(
+    spec: &ServiceSpec,
+    spec_file: &Path,
+    package: &PackageInstall,
+) -> result::Result<(), (status::Status, String)> {
+    match package.pkg_type() {
+        Ok(PackageType::Standalone) => (),
+        Ok(PackageType::Composite) => {
+            return Err((
+                status::UnprocessableEntity,
+                "Composite packages cannot be loaded through the HTTP gateway, load them with \
+                 `hab svc load` on the Supervisor's host"
+                    .to_string(),
+            ))
+        }
+        Err(err) => return Err((status::InternalServerError, err.to_string())),
+    }
+    spec.validate(package).map_err(|err| {
+        (status::UnprocessableEntity, err.to_string())
+    })?;
+    match spec.create_file(spec_file) {
+        Ok(true) => (),
+        Ok(false) => return Err((status::Conflict, already_loaded(&spec.ident))),
+        Err(err) => return Err((status::InternalServerError, err.to_string())),
+    }
+    outputln!("Loaded {} through the http-gateway", &spec.ident);
+    Ok(())
+}
+
+fn already_loaded(ident: &PackageIdent) -> String {
+    format!("Service already loaded, unload '{}' and try again", ident)
+}
+
+fn service_reconfigure(req: &mut Request) -> IronResult<Response> {
+    let update: ServiceSpecUpdate = match serde_json::from_reader(&mut req.body) {
+        Ok(update) => update,
+        Err(err) => return Ok(error_response(status::BadRequest, err.to_string())),
+    };
+    let (spec_file, mut spec) = match spec_from_request(req) {
+        Ok(found) => found,
+        Err(response) => return Ok(response),
+    };
+    update.apply(&mut spec);
+    if let Some(package) = util::pkg::installed_matching(&spec.ident, spec.version_req.as_ref()) {
+        if let Err(err) = spec.validate(&package) {
+            return Ok(error_response(
+                status::UnprocessableEntity,
+                err.to_string(),
+            ));
+        }
+    }
+    write_spec(&spec_file, &spec)
+}
+
+fn service_start(req: &mut Request) -> IronResult<Response> {
+    set_desired_state(req, DesiredState::Up)
+}
+
+fn service_stop(req: &mut Request) -> IronResult<Response> {
+    set_desired_state(req, DesiredState::Down)
+}
+
+fn service_unload(req: &mut Request) -> IronResult<Response> {
+    let (spec_file, spec) = match spec_from_request(req) {
+        Ok(found) => found,
+        Err(response) => return Ok(response),
+    };
+    match fs::remove_file(&spec_file) {
+        Ok(()) => {
+            outputln!("Unloaded {} through the http-gateway", &spec.ident);
+            Ok(Response::with(status::NoContent))
+        }
+        Err(err) => Ok(error_response(
+            status::InternalServerError,
+            err.to_string(),
+        )),
+    }
+}
+
+fn set_desired_state(req: &mut Request, desired_state: DesiredState) -> IronResult<Response> {
+    let (spec_file, mut spec) = match spec_from_request(req) {
+        Ok(found) => found,
+        Err(response) => return Ok(response),
+    };
+    spec.desired_state = desired_state;
+    write_spec(&spec_file, &spec)
+}
+
+fn write_spec(spec_file: &Path, spec: &ServiceSpec) -> IronResult<Response> {
+    match spec.to_file(spec_file) {
+        Ok(()) => Ok(Response::with((
+            status::Ok,
+            Header(headers::ContentType::json()),
+            serde_json::to_string(spec).unwrap(),
+        ))),
+        Err(err) => Ok(error_response(
+            status::InternalServerError,
+            err.to_string(),
+        )),
+    }
+}
+
 fn doc(_req: &mut Request) -> IronResult<Response> {
     Ok(Response::with(
         (status::Ok, Header(headers::ContentType::html()), APIDOCS),
@@ -358,6 +1044,43 @@ fn build_service_group(req: &mut Request) -> Result<ServiceGroup> {
     Ok(sg)
 }
 
+/// Load the spec of the loaded service addressed by the request's service group, along with the
+/// path of its spec file. If no such service is loaded, the `Err` holds the response to return.
+fn spec_from_request(req: &mut Request) -> result::Result<(PathBuf, ServiceSpec), Response> {
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Err(Response::with(status::BadRequest)),
+    };
+    let spec_file = state.spec_path_for_service(service_group.service());
+    let spec = match ServiceSpec::from_file(&spec_file) {
+        Ok(spec) => spec,
+        Err(_) => return Err(Response::with(status::NotFound)),
+    };
+    let spec_group = ServiceGroup::new(
+        spec.application_environment.as_ref(),
+        &spec.ident.name,
+        &spec.group,
+        service_group.org(),
+    );
+    match spec_group {
+        Ok(ref sg) if *sg == service_group => Ok((spec_file, spec)),
+        _ => Err(Response::with(status::NotFound)),
+    }
+}
+
+fn error_response<T>(status: status::Status, error: T) -> Response
+where
+    T: Into<String>,
+{
+    let body = ErrorBody { error: error.into() };
+    Response::with((
+        status,
+        Header(headers::ContentType::json()),
+        serde_json::to_string(&body).unwrap(),
+    ))
+}
+
 fn service_from_file<T>(
     service_group: &ServiceGroup,
     services_data_path: T,
@@ -379,3 +1102,291 @@ where
         Err(err) => Err(err),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use hyper::client::{Client, RequestBuilder, Response as ClientResponse};
+    use hyper::server::Listening;
+    use tempdir::TempDir;
+
+    use manager::FsCfg;
+    use super::*;
+
+    const TOKEN: &'static str = "sekrit";
+
+    /// A gateway serving the Supervisor state in a temporary directory on a free local port.
+    struct TestGateway {
+        root: TempDir,
+        listening: Listening,
+    }
+
+    impl TestGateway {
+        fn new(auth_token: Option<&str>) -> Self {
+            let root = TempDir::new("http-gateway").unwrap();
+            let Server(iron, _, _) = Server::new(
+                Arc::new(FsCfg::new(root.path())),
+                ListenAddr::default(),
+                auth_token.map(|t| t.to_string()),
+                None,
+                ChangeFeed::default(),
+            );
+            let listening = iron.http("127.0.0.1:0").unwrap();
+            TestGateway {
+                root: root,
+                listening: listening,
+            }
+        }
+
+        fn fs_cfg(&self) -> FsCfg {
+            FsCfg::new(self.root.path())
+        }
+
+        fn url(&self, path: &str) -> String {
+            let addr: SocketAddr = self.listening.socket;
+            format!("http://{}{}", addr, path)
+        }
+
+        /// Write the spec of a loaded `core/redis` service in the default group.
+        fn load_redis(&self) -> ServiceSpec {
+            let spec = ServiceSpec::default_for(PackageIdent::from_str("core/redis").unwrap());
+            spec.to_file(self.fs_cfg().spec_path_for(&spec)).unwrap();
+            spec
+        }
+
+        fn redis_spec(&self) -> ServiceSpec {
+            ServiceSpec::from_file(self.fs_cfg().spec_path_for_service("redis")).unwrap()
+        }
+    }
+
+    impl Drop for TestGateway {
+        fn drop(&mut self) {
+            // Otherwise dropping the listener waits for its thread, which serves forever.
+            let _ = self.listening.close();
+        }
+    }
+
+    fn with_token<'a>(req: RequestBuilder<'a>, token: &str) -> RequestBuilder<'a> {
+        req.header(Authorization(Bearer { token: token.to_string() }))
+    }
+
+    fn body(mut res: ClientResponse) -> String {
+        let mut body = String::new();
+        res.read_to_string(&mut body).unwrap();
+        body
+    }
+
+    #[test]
+    fn stream_slots_are_limited_and_given_back() {
+        let active = Arc::new(AtomicUsize::new(0));
+        let slots: Vec<StreamSlot> = (0..MAX_STREAMS)
+            .map(|_| StreamSlot::claim(active.clone()).unwrap())
+            .collect();
+        assert!(StreamSlot::claim(active.clone()).is_none());
+        drop(slots);
+        assert_eq!(active.load(Ordering::SeqCst), 0);
+        assert!(StreamSlot::claim(active.clone()).is_some());
+    }
+
+    #[test]
+    fn event_streams_past_the_limit_are_unavailable() {
+        let gateway = TestGateway::new(None);
+        let client = Client::new();
+        let streams: Vec<ClientResponse> = (0..MAX_STREAMS)
+            .map(|_| client.get(&gateway.url("/events")).send().unwrap())
+            .collect();
+        assert!(streams.iter().all(|res| res.status == status::Ok));
+        let res = client.get(&gateway.url("/events")).send().unwrap();
+        assert_eq!(res.status, status::ServiceUnavailable);
+    }
+
+    #[test]
+    fn tokens_match_only_when_equal() {
+        assert!(tokens_match("sekrit", "sekrit"));
+        assert!(!tokens_match("sekrib", "sekrit"));
+        assert!(!tokens_match("sekri", "sekrit"));
+        assert!(!tokens_match("", "sekrit"));
+    }
+
+    #[test]
+    fn control_routes_are_forbidden_without_a_token() {
+        let gateway = TestGateway::new(None);
+        gateway.load_redis();
+        let client = Client::new();
+        let res = with_token(client.post(&gateway.url("/services/redis/default/stop")), TOKEN)
+            .send()
+            .unwrap();
+        assert_eq!(res.status, status::Forbidden);
+        assert_eq!(gateway.redis_spec().desired_state, DesiredState::Up);
+    }
+
+    #[test]
+    fn control_routes_require_the_token() {
+        let gateway = TestGateway::new(Some(TOKEN));
+        gateway.load_redis();
+        let client = Client::new();
+        let url = gateway.url("/services/redis/default/stop");
+        let res = client.post(&url).send().unwrap();
+        assert_eq!(res.status, status::Unauthorized);
+        let res = with_token(client.post(&url), "sekrix").send().unwrap();
+        assert_eq!(res.status, status::Unauthorized);
+        assert_eq!(gateway.redis_spec().desired_state, DesiredState::Up);
+    }
+
+    #[test]
+    fn read_routes_require_the_token_when_one_is_set() {
+        let gateway = TestGateway::new(Some(TOKEN));
+        let client = Client::new();
+        let res = client.get(&gateway.url("/services")).send().unwrap();
+        assert_eq!(res.status, status::Unauthorized);
+    }
+
+    #[test]
+    fn control_routes_of_unloaded_services_are_not_found() {
+        let gateway = TestGateway::new(Some(TOKEN));
+        let client = Client::new();
+        for path in &["/services/redis/default/start", "/services/redis/default/stop"] {
+            let res = with_token(client.post(&gateway.url(path)), TOKEN)
+                .send()
+                .unwrap();
+            assert_eq!(res.status, status::NotFound);
+        }
+        let res = with_token(client.delete(&gateway.url("/services/redis/default")), TOKEN)
+            .send()
+            .unwrap();
+        assert_eq!(res.status, status::NotFound);
+    }
+
+    #[test]
+    fn control_routes_of_another_group_are_not_found() {
+        let gateway = TestGateway::new(Some(TOKEN));
+        gateway.load_redis();
+        let client = Client::new();
+        let res = with_token(client.post(&gateway.url("/services/redis/other/stop")), TOKEN)
+            .send()
+            .unwrap();
+        assert_eq!(res.status, status::NotFound);
+        assert_eq!(gateway.redis_spec().desired_state, DesiredState::Up);
+    }
+
+    #[test]
+    fn stop_and_start_write_the_desired_state() {
+        let gateway = TestGateway::new(Some(TOKEN));
+        gateway.load_redis();
+        let client = Client::new();
+        let res = with_token(client.post(&gateway.url("/services/redis/default/stop")), TOKEN)
+            .send()
+            .unwrap();
+        assert_eq!(res.status, status::Ok);
+        assert!(body(res).contains(r#""desired_state":"down""#));
+        assert_eq!(gateway.redis_spec().desired_state, DesiredState::Down);
+        let res = with_token(client.post(&gateway.url("/services/redis/default/start")), TOKEN)
+            .send()
+            .unwrap();
+        assert_eq!(res.status, status::Ok);
+        assert_eq!(gateway.redis_spec().desired_state, DesiredState::Up);
+    }
+
+    #[test]
+    fn reconfigure_writes_only_the_given_fields() {
+        let gateway = TestGateway::new(Some(TOKEN));
+        let loaded = gateway.load_redis();
+        let client = Client::new();
+        let res = with_token(client.put(&gateway.url("/services/redis/default")), TOKEN)
+            .body(r#"{"topology": "leader"}"#)
+            .send()
+            .unwrap();
+        assert_eq!(res.status, status::Ok);
+        let spec = gateway.redis_spec();
+        assert_eq!(spec.topology, Topology::Leader);
+        assert_eq!(spec.update_strategy, loaded.update_strategy);
+        assert_eq!(spec.channel, loaded.channel);
+    }
+
+    #[test]
+    fn reconfigure_rejects_a_malformed_body() {
+        let gateway = TestGateway::new(Some(TOKEN));
+        gateway.load_redis();
+        let client = Client::new();
+        let res = with_token(client.put(&gateway.url("/services/redis/default")), TOKEN)
+            .body(r#"{"topology": "mesh"}"#)
+            .send()
+            .unwrap();
+        assert_eq!(res.status, status::BadRequest);
+        assert_eq!(gateway.redis_spec().topology, Topology::Standalone);
+    }
+
+    #[test]
+    fn unload_removes_the_spec_file() {
+        let gateway = TestGateway::new(Some(TOKEN));
+        gateway.load_redis();
+        let client = Client::new();
+        let res = with_token(client.delete(&gateway.url("/services/redis/default")), TOKEN)
+            .send()
+            .unwrap();
+        assert_eq!(res.status, status::NoContent);
+        assert!(!gateway.fs_cfg().spec_path_for_service("redis").exists());
+    }
+
+    #[test]
+    fn load_rejects_a_spec_without_an_ident() {
+        let gateway = TestGateway::new(Some(TOKEN));
+        let client = Client::new();
+        let res = with_token(client.post(&gateway.url("/services")), TOKEN)
+            .body(r#"{"group": "default"}"#)
+            .send()
+            .unwrap();
+        assert_eq!(res.status, status::BadRequest);
+    }
+
+    #[test]
+    fn load_rejects_fields_it_does_not_whitelist() {
+        let gateway = TestGateway::new(Some(TOKEN));
+        let client = Client::new();
+        for body in &[
+            r#"{"ident": "core/redis", "config_from": "/etc"}"#,
+            r#"{"ident": "core/redis", "health_probe": {"type": "exec", "command": "id"}}"#,
+            r#"{"ident": "core/redis", "svc_encrypted_password": "hunter2"}"#,
+        ]
+        {
+            let res = with_token(client.post(&gateway.url("/services")), TOKEN)
+                .body(*body)
+                .send()
+                .unwrap();
+            assert_eq!(res.status, status::BadRequest);
+        }
+        assert!(!gateway.fs_cfg().spec_path_for_service("redis").exists());
+    }
+
+    #[test]
+    fn load_rejects_a_loaded_service() {
+        let gateway = TestGateway::new(Some(TOKEN));
+        gateway.load_redis();
+        let client = Client::new();
+        let res = with_token(client.post(&gateway.url("/services")), TOKEN)
+            .body(r#"{"ident": "core/redis", "topology": "leader"}"#)
+            .send()
+            .unwrap();
+        assert_eq!(res.status, status::Conflict);
+        assert_eq!(gateway.redis_spec().topology, Topology::Standalone);
+    }
+
+    #[test]
+    fn periodic_hook_logs_are_found_by_hook_name() {
+        let service: Json = serde_json::from_str(
+            r#"{"hooks": {"periodic": [{
+                "name": "backup",
+                "stdout_log_path": "/hab/svc/redis/logs/periodic-backup.stdout.log",
+                "stderr_log_path": "/hab/svc/redis/logs/periodic-backup.stderr.log"
+            }]}}"#,
+        ).unwrap();
+        assert_eq!(
+            periodic_log_paths(&service, "periodic-backup"),
+            Some((
+                PathBuf::from("/hab/svc/redis/logs/periodic-backup.stdout.log"),
+                PathBuf::from("/hab/svc/redis/logs/periodic-backup.stderr.log"),
+            ))
+        );
+        assert_eq!(periodic_log_paths(&service, "periodic-vacuum"), None);
+        assert_eq!(periodic_log_paths(&service, "backup"), None);
+    }
+}
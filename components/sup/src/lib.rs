@@ -40,6 +40,7 @@
 //! * [The Habitat Supervisor Sidecar; http interface to promises](sidecar)
 
 extern crate ansi_term;
+extern crate base64;
 #[macro_use]
 extern crate bitflags;
 extern crate byteorder;
@@ -56,6 +57,8 @@ extern crate habitat_depot_client as depot_client;
 extern crate habitat_eventsrv_client as eventsrv_client;
 extern crate habitat_launcher_client as launcher_client;
 extern crate handlebars;
+extern crate hyper;
+extern crate hyper_openssl;
 extern crate iron;
 #[macro_use]
 extern crate lazy_static;
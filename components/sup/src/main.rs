@@ -46,12 +46,12 @@ use hcore::crypto::{self, default_cache_key_path, SymKey};
 use hcore::crypto::dpapi::encrypt;
 use hcore::env as henv;
 use hcore::fs;
-use hcore::package::PackageIdent;
+use hcore::package::{PackageIdent, VersionReq};
 use hcore::package::install::PackageInstall;
 use hcore::package::metadata::{BindMapping, PackageType};
 use hcore::service::{ApplicationEnvironment, ServiceGroup};
 use hcore::url::{bldr_url_from_env, default_bldr_url};
-use launcher_client::{LauncherCli, ERR_NO_RETRY_EXCODE, OK_NO_RETRY_EXCODE};
+use launcher_client::{LauncherCli, ERR_NO_RETRY_EXCODE, OK_NO_RETRY_EXCODE, PROTOCOL_VERSION};
 use url::Url;
 
 use sup::VERSION;
@@ -61,8 +61,9 @@ use sup::feat;
 use sup::command;
 use sup::http_gateway;
 use sup::manager::{Manager, ManagerConfig};
-use sup::manager::service::{DesiredState, ServiceBind, Topology, UpdateStrategy};
-use sup::manager::service::{CompositeSpec, ServiceSpec, StartStyle};
+use sup::manager::service::{DesiredState, HealthCheck, ServiceBind, Topology, UpdateStrategy};
+use sup::manager::service::{CanarySize, CompositeSpec, RestartPolicy, RunMode, ServiceSpec,
+                            StartStyle, UpdateWindow};
 use sup::util;
 
 /// Our output key
@@ -70,6 +71,7 @@ static LOGKEY: &'static str = "MN";
 
 static RING_ENVVAR: &'static str = "HAB_RING";
 static RING_KEY_ENVVAR: &'static str = "HAB_RING_KEY";
+static HTTP_AUTH_TOKEN_ENVVAR: &'static str = "HAB_SUP_GATEWAY_AUTH_TOKEN";
 
 fn main() {
     if let Err(err) = start() {
@@ -87,6 +89,9 @@ fn main() {
 fn boot() -> Option<LauncherCli> {
     env_logger::init().unwrap();
     enable_features_from_env();
+    if henv::var(hcore::output::JSON_LOGGING_ENVVAR).is_ok() {
+        hcore::output::set_json(true);
+    }
     if !crypto::init() {
         println!("Crypto initialization failed!");
         process::exit(1);
@@ -94,7 +99,17 @@ fn boot() -> Option<LauncherCli> {
     match launcher_client::env_pipe() {
         Some(pipe) => {
             match LauncherCli::connect(pipe) {
-                Ok(launcher) => Some(launcher),
+                Ok(launcher) => {
+                    if launcher.version() < PROTOCOL_VERSION {
+                        warn!(
+                            "Launcher speaks protocol version {} rather than {}, exit codes and \
+                            resource usage of services are unavailable until it is restarted",
+                            launcher.version(),
+                            PROTOCOL_VERSION
+                        );
+                    }
+                    Some(launcher)
+                }
                 Err(err) => {
                     println!("{}", err);
                     process::exit(1);
@@ -119,6 +134,7 @@ fn start() -> Result<()> {
         ("bash", Some(m)) => sub_bash(m),
         ("config", Some(m)) => sub_config(m),
         ("load", Some(m)) => sub_load(m),
+        ("render", Some(m)) => sub_render(m),
         ("run", Some(m)) => {
             let launcher = launcher.ok_or(sup_error!(Error::NoLauncher))?;
             sub_run(m, launcher)
@@ -146,6 +162,8 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
         (@setting SubcommandRequiredElseHelp)
         (@arg VERBOSE: -v +global "Verbose output; shows line numbers")
         (@arg NO_COLOR: --("no-color") +global "Turn ANSI color off")
+        (@arg JSON_LOGGING: --("json-logging") +global
+            "Print every line of output as a JSON object (also set with HAB_SUP_JSON_LOGGING)")
         (@subcommand bash =>
             (about: "Start an interactive Bash-like shell")
             (aliases: &["b", "ba", "bas"])
@@ -173,6 +191,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "Environment name; [default: not set].")
             (@arg CHANNEL: --channel +takes_value
                 "Receive package updates from the specified release channel [default: stable]")
+            (@arg VERSION_REQ: --("version-req") +takes_value {valid_version_req}
+                "Only run and update to versions of the package satisfying this requirement, \
+                e.g. '~1.4' or '>=2.0, <3.0' [default: any version]")
             (@arg GROUP: --group +takes_value
                 "The service group; shared config and topology [default: default].")
             (@arg BLDR_URL: --url -u +takes_value {valid_url}
@@ -181,9 +202,70 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
                 "Service topology; [default: none]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] [values: none, at-once, rolling, canary]")
+            (@arg CANARY_SIZE: --("canary-size") +takes_value {valid_canary_size}
+                "The number or percentage of members updated first by the canary update \
+                strategy, e.g. 2 or 10% [default: 1]")
+            (@arg CANARY_SOAK: --("canary-soak") +takes_value {valid_seconds}
+                "Seconds the canaries must stay healthy before the rest of the service group is \
+                updated [default: 300]")
+            (@arg UPDATE_VERIFY_WINDOW: --("update-verify-window") +takes_value {valid_seconds}
+                "Roll back to the previous package if the service stays critical or crash-loops \
+                within this many seconds of an update [default: no rollback]")
+            (@arg UPDATE_WINDOW: --("update-window") +takes_value {valid_update_window}
+                "Only apply updates during this window, e.g. 'mon-fri 02:00-04:00 UTC' \
+                [default: any time]")
+            (@arg HEALTH_CHECK_INTERVAL: --("health-check-interval") +takes_value
+                {valid_seconds} "The interval in seconds between health checks [default: 30]")
+            (@arg HEALTH_CHECK_TIMEOUT: --("health-check-timeout") +takes_value {valid_seconds}
+                "Kill a health check which runs for longer than this many seconds [default: no \
+                timeout, 30 for a health probe]")
+            (@arg HEALTH_CHECK_TIMEOUT_STATUS: --("health-check-timeout-status") +takes_value
+                {valid_health_check_timeout_status} "The health recorded for a timed out health \
+                check [default: unknown] [values: unknown, critical]")
+            (@arg HOOK_TIMEOUT: --("hook-timeout") +takes_value +multiple {valid_hook_timeout}
+                "Kill a hook which runs for longer than the given number of seconds, as \
+                <HOOK>=<SECONDS> (ex: init=60, periodic-backup=600)")
+            (@arg RUN_MODE: --("run-mode") +takes_value {valid_run_mode}
+                "Keep the service's process up until it is stopped, or run it to completion \
+                again whenever its package, configuration or hooks change [default: \
+                long-running] [values: long-running, one-shot]")
+            (@arg RESTART_POLICY: --("restart-policy") +takes_value {valid_restart_policy}
+                "When to restart the service's process after it exits [default: always] \
+                [values: always, on-failure, never]")
+            (@arg MAX_RESTARTS: --("max-restarts") +takes_value {valid_max_restarts}
+                "Mark the service as failed after this many restarts within the restart window \
+                [default: no limit]")
+            (@arg RESTART_WINDOW: --("restart-window") +takes_value {valid_seconds}
+                "The number of seconds over which restarts are counted [default: 300]")
+            (@arg RESTART_BACKOFF: --("restart-backoff") +takes_value {valid_seconds}
+                "Seconds to wait before restarting, doubled for every restart within the restart \
+                window [default: 1]")
+            (@arg RESTART_BACKOFF_MAX: --("restart-backoff-max") +takes_value {valid_seconds}
+                "The longest wait in seconds before restarting [default: 60]")
+            (@arg MEMORY_LIMIT: --("memory-limit") +takes_value {valid_resource_limit}
+                "The most memory in bytes the service may use (Linux only) [default: no limit]")
+            (@arg CPU_LIMIT: --("cpu-limit") +takes_value {valid_cpu_limit}
+                "The CPU time the service may use in thousandths of a CPU, ex: 500 for half a \
+                CPU (Linux only) [default: no limit]")
+            (@arg PIDS_LIMIT: --("pids-limit") +takes_value {valid_resource_limit}
+                "The most processes and threads the service may run (Linux only) \
+                [default: no limit]")
+            (@arg LOG_MAX_SIZE: --("log-max-size") +takes_value {valid_log_max_size}
+                "The size in bytes at which the service's and its hooks' logs are rotated \
+                [default: 10485760]")
+            (@arg LOG_MAX_AGE: --("log-max-age") +takes_value {valid_seconds}
+                "The age in seconds at which the service's log is rotated \
+                [default: no limit]")
+            (@arg LOG_KEEP: --("log-keep") +takes_value {valid_log_keep}
+                "The number of rotated logs to keep [default: 5]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
+            (@arg WAIT_FOR_BINDS: --("wait-for-binds") "Hold the service before it starts or \
+                restarts until every service group it binds to has an alive member")
+            (@arg BIND_WAIT_TIMEOUT: --("bind-wait-timeout") +takes_value {valid_seconds}
+                "Mark the service as failed if its binds are not available within this many \
+                seconds [default: no timeout]")
             (@arg FORCE: --force -f "Load or reload an already loaded service. If the service was \
                 previously loaded and running this operation will also restart the service")
         )
@@ -197,6 +279,27 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The name for the state directory if there is more than one Supervisor running \
                 [default: default]")
         )
+        (@subcommand render =>
+            (about: "Renders the configuration files and hooks of an installed package as the \
+                Supervisor would, without loading it as a service")
+            (aliases: &["re", "ren", "rend", "rende"])
+            (@arg PKG_IDENT: +required +takes_value
+                "A Habitat package identifier (ex: core/redis)")
+            (@arg GROUP: --group +takes_value
+                "The service group; shared config and topology [default: default].")
+            (@arg ORGANIZATION: --org +takes_value
+                "The organization the service is part of [default: not set]")
+            (@arg BIND: --bind +takes_value +multiple
+                "One or more service groups to bind to a configuration")
+            (@arg USER_TOML: --("user-toml") +takes_value {file_exists}
+                "Path to a user.toml overriding the package's default configuration. The \
+                service's installed user.toml and environment configuration are not used")
+            (@arg CENSUS: --census +takes_value {file_exists}
+                "Path to a JSON file describing the members, leaders and gossiped configuration \
+                of the service groups in the ring [default: only this service]")
+            (@arg OUTPUT: --output -o +takes_value
+                "Also write the rendered files to this directory's config and hooks directories")
+        )
         (@subcommand run =>
             (about: "Run the Habitat Supervisor")
             (aliases: &["r", "ru"])
@@ -204,6 +307,10 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The listen address for the gossip system [default: 0.0.0.0:9638]")
             (@arg LISTEN_HTTP: --("listen-http") +takes_value
                 "The listen address for the HTTP gateway [default: 0.0.0.0:9631]")
+            (@arg HTTP_TLS_CERT: --("http-tls-cert") +takes_value requires[HTTP_TLS_KEY]
+                {file_exists} "Serve the HTTP gateway over TLS using this PEM certificate chain")
+            (@arg HTTP_TLS_KEY: --("http-tls-key") +takes_value requires[HTTP_TLS_CERT]
+                {file_exists} "The PEM private key for the HTTP gateway's TLS certificate")
             (@arg NAME: --("override-name") +takes_value
                 "The name of the Supervisor if launching more than one [default: default]")
             (@arg ORGANIZATION: --org +takes_value
@@ -239,6 +346,10 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The listen address for the gossip system [default: 0.0.0.0:9638]")
             (@arg LISTEN_HTTP: --("listen-http") +takes_value
                 "The listen address for the HTTP gateway [default: 0.0.0.0:9631]")
+            (@arg HTTP_TLS_CERT: --("http-tls-cert") +takes_value requires[HTTP_TLS_KEY]
+                {file_exists} "Serve the HTTP gateway over TLS using this PEM certificate chain")
+            (@arg HTTP_TLS_KEY: --("http-tls-key") +takes_value requires[HTTP_TLS_CERT]
+                {file_exists} "The PEM private key for the HTTP gateway's TLS certificate")
             (@arg NAME: --("override-name") +takes_value
                 "The name for the state directory if launching more than one Supervisor \
                 [default: default]")
@@ -261,6 +372,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "Environment name; [default: not set].")
             (@arg CHANNEL: --channel +takes_value
                 "Receive package updates from the specified release channel [default: stable]")
+            (@arg VERSION_REQ: --("version-req") +takes_value {valid_version_req}
+                "Only run and update to versions of the package satisfying this requirement, \
+                e.g. '~1.4' or '>=2.0, <3.0' [default: any version]")
             (@arg GROUP: --group +takes_value
                 "The service group; shared config and topology [default: default]")
             (@arg BLDR_URL: --url -u +takes_value {valid_url}
@@ -269,9 +383,70 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
                 "Service topology; [default: none]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] [values: none, at-once, rolling, canary]")
+            (@arg CANARY_SIZE: --("canary-size") +takes_value {valid_canary_size}
+                "The number or percentage of members updated first by the canary update \
+                strategy, e.g. 2 or 10% [default: 1]")
+            (@arg CANARY_SOAK: --("canary-soak") +takes_value {valid_seconds}
+                "Seconds the canaries must stay healthy before the rest of the service group is \
+                updated [default: 300]")
+            (@arg UPDATE_VERIFY_WINDOW: --("update-verify-window") +takes_value {valid_seconds}
+                "Roll back to the previous package if the service stays critical or crash-loops \
+                within this many seconds of an update [default: no rollback]")
+            (@arg UPDATE_WINDOW: --("update-window") +takes_value {valid_update_window}
+                "Only apply updates during this window, e.g. 'mon-fri 02:00-04:00 UTC' \
+                [default: any time]")
+            (@arg HEALTH_CHECK_INTERVAL: --("health-check-interval") +takes_value
+                {valid_seconds} "The interval in seconds between health checks [default: 30]")
+            (@arg HEALTH_CHECK_TIMEOUT: --("health-check-timeout") +takes_value {valid_seconds}
+                "Kill a health check which runs for longer than this many seconds [default: no \
+                timeout, 30 for a health probe]")
+            (@arg HEALTH_CHECK_TIMEOUT_STATUS: --("health-check-timeout-status") +takes_value
+                {valid_health_check_timeout_status} "The health recorded for a timed out health \
+                check [default: unknown] [values: unknown, critical]")
+            (@arg HOOK_TIMEOUT: --("hook-timeout") +takes_value +multiple {valid_hook_timeout}
+                "Kill a hook which runs for longer than the given number of seconds, as \
+                <HOOK>=<SECONDS> (ex: init=60, periodic-backup=600)")
+            (@arg RUN_MODE: --("run-mode") +takes_value {valid_run_mode}
+                "Keep the service's process up until it is stopped, or run it to completion \
+                again whenever its package, configuration or hooks change [default: \
+                long-running] [values: long-running, one-shot]")
+            (@arg RESTART_POLICY: --("restart-policy") +takes_value {valid_restart_policy}
+                "When to restart the service's process after it exits [default: always] \
+                [values: always, on-failure, never]")
+            (@arg MAX_RESTARTS: --("max-restarts") +takes_value {valid_max_restarts}
+                "Mark the service as failed after this many restarts within the restart window \
+                [default: no limit]")
+            (@arg RESTART_WINDOW: --("restart-window") +takes_value {valid_seconds}
+                "The number of seconds over which restarts are counted [default: 300]")
+            (@arg RESTART_BACKOFF: --("restart-backoff") +takes_value {valid_seconds}
+                "Seconds to wait before restarting, doubled for every restart within the restart \
+                window [default: 1]")
+            (@arg RESTART_BACKOFF_MAX: --("restart-backoff-max") +takes_value {valid_seconds}
+                "The longest wait in seconds before restarting [default: 60]")
+            (@arg MEMORY_LIMIT: --("memory-limit") +takes_value {valid_resource_limit}
+                "The most memory in bytes the service may use (Linux only) [default: no limit]")
+            (@arg CPU_LIMIT: --("cpu-limit") +takes_value {valid_cpu_limit}
+                "The CPU time the service may use in thousandths of a CPU, ex: 500 for half a \
+                CPU (Linux only) [default: no limit]")
+            (@arg PIDS_LIMIT: --("pids-limit") +takes_value {valid_resource_limit}
+                "The most processes and threads the service may run (Linux only) \
+                [default: no limit]")
+            (@arg LOG_MAX_SIZE: --("log-max-size") +takes_value {valid_log_max_size}
+                "The size in bytes at which the service's and its hooks' logs are rotated \
+                [default: 10485760]")
+            (@arg LOG_MAX_AGE: --("log-max-age") +takes_value {valid_seconds}
+                "The age in seconds at which the service's log is rotated \
+                [default: no limit]")
+            (@arg LOG_KEEP: --("log-keep") +takes_value {valid_log_keep}
+                "The number of rotated logs to keep [default: 5]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
+            (@arg WAIT_FOR_BINDS: --("wait-for-binds") "Hold the service before it starts or \
+                restarts until every service group it binds to has an alive member")
+            (@arg BIND_WAIT_TIMEOUT: --("bind-wait-timeout") +takes_value {valid_seconds}
+                "Mark the service as failed if its binds are not available within this many \
+                seconds [default: no timeout]")
             (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
                 "Use package config from this path, rather than the package itself")
             (@arg AUTO_UPDATE: --("auto-update") -A "Enable automatic updates for the Supervisor \
@@ -313,6 +488,8 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
         (@setting SubcommandRequiredElseHelp)
         (@arg VERBOSE: -v +global "Verbose output; shows line numbers")
         (@arg NO_COLOR: --("no-color") +global "Turn ANSI color off")
+        (@arg JSON_LOGGING: --("json-logging") +global
+            "Print every line of output as a JSON object (also set with HAB_SUP_JSON_LOGGING)")
         (@subcommand bash =>
             (about: "Start an interactive Bash-like shell")
             (aliases: &["b", "ba", "bas"])
@@ -340,6 +517,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "Environment name; [default: not set].")
             (@arg CHANNEL: --channel +takes_value
                 "Receive package updates from the specified release channel [default: stable]")
+            (@arg VERSION_REQ: --("version-req") +takes_value {valid_version_req}
+                "Only run and update to versions of the package satisfying this requirement, \
+                e.g. '~1.4' or '>=2.0, <3.0' [default: any version]")
             (@arg GROUP: --group +takes_value
                 "The service group; shared config and topology [default: default].")
             (@arg BLDR_URL: --url -u +takes_value {valid_url}
@@ -348,9 +528,70 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
                 "Service topology; [default: none]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] [values: none, at-once, rolling, canary]")
+            (@arg CANARY_SIZE: --("canary-size") +takes_value {valid_canary_size}
+                "The number or percentage of members updated first by the canary update \
+                strategy, e.g. 2 or 10% [default: 1]")
+            (@arg CANARY_SOAK: --("canary-soak") +takes_value {valid_seconds}
+                "Seconds the canaries must stay healthy before the rest of the service group is \
+                updated [default: 300]")
+            (@arg UPDATE_VERIFY_WINDOW: --("update-verify-window") +takes_value {valid_seconds}
+                "Roll back to the previous package if the service stays critical or crash-loops \
+                within this many seconds of an update [default: no rollback]")
+            (@arg UPDATE_WINDOW: --("update-window") +takes_value {valid_update_window}
+                "Only apply updates during this window, e.g. 'mon-fri 02:00-04:00 UTC' \
+                [default: any time]")
+            (@arg HEALTH_CHECK_INTERVAL: --("health-check-interval") +takes_value
+                {valid_seconds} "The interval in seconds between health checks [default: 30]")
+            (@arg HEALTH_CHECK_TIMEOUT: --("health-check-timeout") +takes_value {valid_seconds}
+                "Kill a health check which runs for longer than this many seconds [default: no \
+                timeout, 30 for a health probe]")
+            (@arg HEALTH_CHECK_TIMEOUT_STATUS: --("health-check-timeout-status") +takes_value
+                {valid_health_check_timeout_status} "The health recorded for a timed out health \
+                check [default: unknown] [values: unknown, critical]")
+            (@arg HOOK_TIMEOUT: --("hook-timeout") +takes_value +multiple {valid_hook_timeout}
+                "Kill a hook which runs for longer than the given number of seconds, as \
+                <HOOK>=<SECONDS> (ex: init=60, periodic-backup=600)")
+            (@arg RUN_MODE: --("run-mode") +takes_value {valid_run_mode}
+                "Keep the service's process up until it is stopped, or run it to completion \
+                again whenever its package, configuration or hooks change [default: \
+                long-running] [values: long-running, one-shot]")
+            (@arg RESTART_POLICY: --("restart-policy") +takes_value {valid_restart_policy}
+                "When to restart the service's process after it exits [default: always] \
+                [values: always, on-failure, never]")
+            (@arg MAX_RESTARTS: --("max-restarts") +takes_value {valid_max_restarts}
+                "Mark the service as failed after this many restarts within the restart window \
+                [default: no limit]")
+            (@arg RESTART_WINDOW: --("restart-window") +takes_value {valid_seconds}
+                "The number of seconds over which restarts are counted [default: 300]")
+            (@arg RESTART_BACKOFF: --("restart-backoff") +takes_value {valid_seconds}
+                "Seconds to wait before restarting, doubled for every restart within the restart \
+                window [default: 1]")
+            (@arg RESTART_BACKOFF_MAX: --("restart-backoff-max") +takes_value {valid_seconds}
+                "The longest wait in seconds before restarting [default: 60]")
+            (@arg MEMORY_LIMIT: --("memory-limit") +takes_value {valid_resource_limit}
+                "The most memory in bytes the service may use (Linux only) [default: no limit]")
+            (@arg CPU_LIMIT: --("cpu-limit") +takes_value {valid_cpu_limit}
+                "The CPU time the service may use in thousandths of a CPU, ex: 500 for half a \
+                CPU (Linux only) [default: no limit]")
+            (@arg PIDS_LIMIT: --("pids-limit") +takes_value {valid_resource_limit}
+                "The most processes and threads the service may run (Linux only) \
+                [default: no limit]")
+            (@arg LOG_MAX_SIZE: --("log-max-size") +takes_value {valid_log_max_size}
+                "The size in bytes at which the service's and its hooks' logs are rotated \
+                [default: 10485760]")
+            (@arg LOG_MAX_AGE: --("log-max-age") +takes_value {valid_seconds}
+                "The age in seconds at which the service's log is rotated \
+                [default: no limit]")
+            (@arg LOG_KEEP: --("log-keep") +takes_value {valid_log_keep}
+                "The number of rotated logs to keep [default: 5]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
+            (@arg WAIT_FOR_BINDS: --("wait-for-binds") "Hold the service before it starts or \
+                restarts until every service group it binds to has an alive member")
+            (@arg BIND_WAIT_TIMEOUT: --("bind-wait-timeout") +takes_value {valid_seconds}
+                "Mark the service as failed if its binds are not available within this many \
+                seconds [default: no timeout]")
             (@arg FORCE: --force -f "Load or reload an already loaded service. If the service was \
                 previously loaded and running this operation will also restart the service")
                 (@arg PASSWORD: --password +takes_value
@@ -366,6 +607,27 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The name for the state directory if there is more than one Supervisor running \
                 [default: default]")
         )
+        (@subcommand render =>
+            (about: "Renders the configuration files and hooks of an installed package as the \
+                Supervisor would, without loading it as a service")
+            (aliases: &["re", "ren", "rend", "rende"])
+            (@arg PKG_IDENT: +required +takes_value
+                "A Habitat package identifier (ex: core/redis)")
+            (@arg GROUP: --group +takes_value
+                "The service group; shared config and topology [default: default].")
+            (@arg ORGANIZATION: --org +takes_value
+                "The organization the service is part of [default: not set]")
+            (@arg BIND: --bind +takes_value +multiple
+                "One or more service groups to bind to a configuration")
+            (@arg USER_TOML: --("user-toml") +takes_value {file_exists}
+                "Path to a user.toml overriding the package's default configuration. The \
+                service's installed user.toml and environment configuration are not used")
+            (@arg CENSUS: --census +takes_value {file_exists}
+                "Path to a JSON file describing the members, leaders and gossiped configuration \
+                of the service groups in the ring [default: only this service]")
+            (@arg OUTPUT: --output -o +takes_value
+                "Also write the rendered files to this directory's config and hooks directories")
+        )
         (@subcommand run =>
             (about: "Run the Habitat Supervisor")
             (aliases: &["r", "ru"])
@@ -373,6 +635,10 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The listen address for the gossip system [default: 0.0.0.0:9638]")
             (@arg LISTEN_HTTP: --("listen-http") +takes_value
                 "The listen address for the HTTP gateway [default: 0.0.0.0:9631]")
+            (@arg HTTP_TLS_CERT: --("http-tls-cert") +takes_value requires[HTTP_TLS_KEY]
+                {file_exists} "Serve the HTTP gateway over TLS using this PEM certificate chain")
+            (@arg HTTP_TLS_KEY: --("http-tls-key") +takes_value requires[HTTP_TLS_CERT]
+                {file_exists} "The PEM private key for the HTTP gateway's TLS certificate")
             (@arg NAME: --("override-name") +takes_value
                 "The name of the Supervisor if launching more than one [default: default]")
             (@arg ORGANIZATION: --org +takes_value
@@ -408,6 +674,10 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The listen address for the gossip system [default: 0.0.0.0:9638]")
             (@arg LISTEN_HTTP: --("listen-http") +takes_value
                 "The listen address for the HTTP gateway [default: 0.0.0.0:9631]")
+            (@arg HTTP_TLS_CERT: --("http-tls-cert") +takes_value requires[HTTP_TLS_KEY]
+                {file_exists} "Serve the HTTP gateway over TLS using this PEM certificate chain")
+            (@arg HTTP_TLS_KEY: --("http-tls-key") +takes_value requires[HTTP_TLS_CERT]
+                {file_exists} "The PEM private key for the HTTP gateway's TLS certificate")
             (@arg NAME: --("override-name") +takes_value
                 "The name for the state directory if launching more than one Supervisor \
                 [default: default]")
@@ -430,6 +700,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "Environment name; [default: not set].")
             (@arg CHANNEL: --channel +takes_value
                 "Receive package updates from the specified release channel [default: stable]")
+            (@arg VERSION_REQ: --("version-req") +takes_value {valid_version_req}
+                "Only run and update to versions of the package satisfying this requirement, \
+                e.g. '~1.4' or '>=2.0, <3.0' [default: any version]")
             (@arg GROUP: --group +takes_value
                 "The service group; shared config and topology [default: default]")
             (@arg BLDR_URL: --url -u +takes_value {valid_url}
@@ -438,9 +711,70 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
                 "Service topology; [default: none]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] [values: none, at-once, rolling, canary]")
+            (@arg CANARY_SIZE: --("canary-size") +takes_value {valid_canary_size}
+                "The number or percentage of members updated first by the canary update \
+                strategy, e.g. 2 or 10% [default: 1]")
+            (@arg CANARY_SOAK: --("canary-soak") +takes_value {valid_seconds}
+                "Seconds the canaries must stay healthy before the rest of the service group is \
+                updated [default: 300]")
+            (@arg UPDATE_VERIFY_WINDOW: --("update-verify-window") +takes_value {valid_seconds}
+                "Roll back to the previous package if the service stays critical or crash-loops \
+                within this many seconds of an update [default: no rollback]")
+            (@arg UPDATE_WINDOW: --("update-window") +takes_value {valid_update_window}
+                "Only apply updates during this window, e.g. 'mon-fri 02:00-04:00 UTC' \
+                [default: any time]")
+            (@arg HEALTH_CHECK_INTERVAL: --("health-check-interval") +takes_value
+                {valid_seconds} "The interval in seconds between health checks [default: 30]")
+            (@arg HEALTH_CHECK_TIMEOUT: --("health-check-timeout") +takes_value {valid_seconds}
+                "Kill a health check which runs for longer than this many seconds [default: no \
+                timeout, 30 for a health probe]")
+            (@arg HEALTH_CHECK_TIMEOUT_STATUS: --("health-check-timeout-status") +takes_value
+                {valid_health_check_timeout_status} "The health recorded for a timed out health \
+                check [default: unknown] [values: unknown, critical]")
+            (@arg HOOK_TIMEOUT: --("hook-timeout") +takes_value +multiple {valid_hook_timeout}
+                "Kill a hook which runs for longer than the given number of seconds, as \
+                <HOOK>=<SECONDS> (ex: init=60, periodic-backup=600)")
+            (@arg RUN_MODE: --("run-mode") +takes_value {valid_run_mode}
+                "Keep the service's process up until it is stopped, or run it to completion \
+                again whenever its package, configuration or hooks change [default: \
+                long-running] [values: long-running, one-shot]")
+            (@arg RESTART_POLICY: --("restart-policy") +takes_value {valid_restart_policy}
+                "When to restart the service's process after it exits [default: always] \
+                [values: always, on-failure, never]")
+            (@arg MAX_RESTARTS: --("max-restarts") +takes_value {valid_max_restarts}
+                "Mark the service as failed after this many restarts within the restart window \
+                [default: no limit]")
+            (@arg RESTART_WINDOW: --("restart-window") +takes_value {valid_seconds}
+                "The number of seconds over which restarts are counted [default: 300]")
+            (@arg RESTART_BACKOFF: --("restart-backoff") +takes_value {valid_seconds}
+                "Seconds to wait before restarting, doubled for every restart within the restart \
+                window [default: 1]")
+            (@arg RESTART_BACKOFF_MAX: --("restart-backoff-max") +takes_value {valid_seconds}
+                "The longest wait in seconds before restarting [default: 60]")
+            (@arg MEMORY_LIMIT: --("memory-limit") +takes_value {valid_resource_limit}
+                "The most memory in bytes the service may use (Linux only) [default: no limit]")
+            (@arg CPU_LIMIT: --("cpu-limit") +takes_value {valid_cpu_limit}
+                "The CPU time the service may use in thousandths of a CPU, ex: 500 for half a \
+                CPU (Linux only) [default: no limit]")
+            (@arg PIDS_LIMIT: --("pids-limit") +takes_value {valid_resource_limit}
+                "The most processes and threads the service may run (Linux only) \
+                [default: no limit]")
+            (@arg LOG_MAX_SIZE: --("log-max-size") +takes_value {valid_log_max_size}
+                "The size in bytes at which the service's and its hooks' logs are rotated \
+                [default: 10485760]")
+            (@arg LOG_MAX_AGE: --("log-max-age") +takes_value {valid_seconds}
+                "The age in seconds at which the service's log is rotated \
+                [default: no limit]")
+            (@arg LOG_KEEP: --("log-keep") +takes_value {valid_log_keep}
+                "The number of rotated logs to keep [default: 5]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
+            (@arg WAIT_FOR_BINDS: --("wait-for-binds") "Hold the service before it starts or \
+                restarts until every service group it binds to has an alive member")
+            (@arg BIND_WAIT_TIMEOUT: --("bind-wait-timeout") +takes_value {valid_seconds}
+                "Mark the service as failed if its binds are not available within this many \
+                seconds [default: no timeout]")
             (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
                 "Use package config from this path, rather than the package itself")
             (@arg AUTO_UPDATE: --("auto-update") -A "Enable automatic updates for the Supervisor \
@@ -475,6 +809,9 @@ fn sub_bash(m: &ArgMatches) -> Result<()> {
     if m.is_present("NO_COLOR") {
         hcore::output::set_no_color(true);
     }
+    if m.is_present("JSON_LOGGING") {
+        hcore::output::set_json(true);
+    }
 
     command::shell::bash()
 }
@@ -493,6 +830,9 @@ fn sub_load(m: &ArgMatches) -> Result<()> {
     if m.is_present("NO_COLOR") {
         hcore::output::set_no_color(true);
     }
+    if m.is_present("JSON_LOGGING") {
+        hcore::output::set_json(true);
+    }
     let cfg = mgrcfg_from_matches(m)?;
     let install_source = install_source_from_input(m)?;
 
@@ -506,11 +846,13 @@ fn sub_load(m: &ArgMatches) -> Result<()> {
             // We don't have any record of this thing; let's set it
             // up!
             //
-            // This will install the latest version from Builder
-            let installed = util::pkg::install(
+            // This will install the latest version from Builder which
+            // satisfies the version requirement, if one was given
+            let installed = util::pkg::install_matching(
                 &mut UI::default(),
                 &bldr_url(m),
                 &install_source,
+                version_req_from_input(m).as_ref(),
                 &channel(m),
             )?;
 
@@ -564,6 +906,7 @@ fn sub_load(m: &ArgMatches) -> Result<()> {
                     install_package_if_not_present(
                         &install_source,
                         &service_spec.bldr_url,
+                        service_spec.version_req.as_ref(),
                         &service_spec.channel,
                     )?;
 
@@ -614,6 +957,7 @@ fn sub_load(m: &ArgMatches) -> Result<()> {
                             // composites don't auto-update themselves
                             // like services can.
                             &bldr_url(m),
+                            None,
                             &channel(m),
                         )?;
 
@@ -684,6 +1028,9 @@ fn sub_unload(m: &ArgMatches) -> Result<()> {
     if m.is_present("NO_COLOR") {
         hcore::output::set_no_color(true);
     }
+    if m.is_present("JSON_LOGGING") {
+        hcore::output::set_json(true);
+    }
 
     let cfg = mgrcfg_from_matches(m)?;
     let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
@@ -713,7 +1060,38 @@ fn sub_unload(m: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
+fn sub_render(m: &ArgMatches) -> Result<()> {
+    if m.is_present("VERBOSE") {
+        hcore::output::set_verbose(true);
+    }
+    if m.is_present("NO_COLOR") {
+        hcore::output::set_no_color(true);
+    }
+    if m.is_present("JSON_LOGGING") {
+        hcore::output::set_json(true);
+    }
+    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
+    let mut binds = Vec::new();
+    if let Some(bind_strs) = m.values_of("BIND") {
+        for bind_str in bind_strs {
+            binds.push(ServiceBind::from_str(bind_str)?);
+        }
+    }
+    command::render::start(
+        &ident,
+        m.value_of("GROUP").unwrap_or("default"),
+        m.value_of("ORGANIZATION"),
+        &binds,
+        m.value_of("USER_TOML").map(Path::new),
+        m.value_of("CENSUS").map(Path::new),
+        m.value_of("OUTPUT").map(Path::new),
+    )
+}
+
 fn sub_run(m: &ArgMatches, launcher: LauncherCli) -> Result<()> {
+    if m.is_present("JSON_LOGGING") {
+        hcore::output::set_json(true);
+    }
     let cfg = mgrcfg_from_matches(m)?;
     let mut manager = Manager::load(cfg, launcher)?;
     manager.run()
@@ -726,6 +1104,9 @@ fn sub_sh(m: &ArgMatches) -> Result<()> {
     if m.is_present("NO_COLOR") {
         hcore::output::set_no_color(true);
     }
+    if m.is_present("JSON_LOGGING") {
+        hcore::output::set_json(true);
+    }
 
     command::shell::sh()
 }
@@ -737,6 +1118,9 @@ fn sub_start(m: &ArgMatches, launcher: LauncherCli) -> Result<()> {
     if m.is_present("NO_COLOR") {
         hcore::output::set_no_color(true);
     }
+    if m.is_present("JSON_LOGGING") {
+        hcore::output::set_json(true);
+    }
 
     let cfg = mgrcfg_from_matches(m)?;
 
@@ -783,8 +1167,12 @@ fn sub_start(m: &ArgMatches, launcher: LauncherCli) -> Result<()> {
             // we'll install the latest thing that will
             // suffice. Otherwise, we'll just use what we find in the
             // local cache of software.
-            let installed_package =
-                install_package_if_not_present(&install_source, &bldr_url(m), &channel(m))?;
+            let installed_package = install_package_if_not_present(
+                &install_source,
+                &bldr_url(m),
+                version_req_from_input(m).as_ref(),
+                &channel(m),
+            )?;
             let new_specs =
                 generate_new_specs_from_package(&original_ident, &installed_package, m)?;
 
@@ -832,6 +1220,9 @@ fn sub_status(m: &ArgMatches) -> Result<()> {
     if m.is_present("NO_COLOR") {
         hcore::output::set_no_color(true);
     }
+    if m.is_present("JSON_LOGGING") {
+        hcore::output::set_json(true);
+    }
     let cfg = mgrcfg_from_matches(m)?;
     if !Manager::is_running(&cfg)? {
         println!("The Supervisor is not running.");
@@ -877,6 +1268,9 @@ fn sub_stop(m: &ArgMatches) -> Result<()> {
     if m.is_present("NO_COLOR") {
         hcore::output::set_no_color(true);
     }
+    if m.is_present("JSON_LOGGING") {
+        hcore::output::set_json(true);
+    }
     let cfg = mgrcfg_from_matches(m)?;
 
     // PKG_IDENT is required, so unwrap() is safe
@@ -979,6 +1373,12 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
     if let Some(addr_str) = m.value_of("LISTEN_HTTP") {
         cfg.http_listen = http_gateway::ListenAddr::from_str(addr_str)?;
     }
+    if let (Some(cert), Some(key)) = (m.value_of("HTTP_TLS_CERT"), m.value_of("HTTP_TLS_KEY")) {
+        cfg.http_tls = Some(http_gateway::TlsCfg {
+            cert_path: PathBuf::from(cert),
+            key_path: PathBuf::from(key),
+        });
+    }
     if let Some(name_str) = m.value_of("NAME") {
         cfg.name = Some(String::from(name_str));
         outputln!("");
@@ -1060,6 +1460,7 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
     if let Some(events) = m.value_of("EVENTS") {
         cfg.eventsrv_group = ServiceGroup::from_str(events).ok();
     }
+    cfg.http_auth_token = henv::var(HTTP_AUTH_TOKEN_ENVVAR).ok();
     Ok(cfg)
 }
 
@@ -1094,6 +1495,12 @@ fn channel_from_input(m: &ArgMatches) -> Option<String> {
     m.value_of("CHANNEL").and_then(|c| Some(c.to_string()))
 }
 
+/// A version requirement, but *only* if the user specified it via CLI args.
+fn version_req_from_input(m: &ArgMatches) -> Option<VersionReq> {
+    // unwrap() is safe, because the input is validated by `valid_version_req`
+    m.value_of("VERSION_REQ").map(|r| VersionReq::from_str(r).unwrap())
+}
+
 fn install_source_from_input(m: &ArgMatches) -> Result<InstallSource> {
     // PKG_IDENT_OR_ARTIFACT is required in subcommands that use it,
     // so unwrap() is safe here.
@@ -1148,6 +1555,12 @@ fn set_channel_from_input(spec: &mut ServiceSpec, m: &ArgMatches) {
     }
 }
 
+fn set_version_req_from_input(spec: &mut ServiceSpec, m: &ArgMatches) {
+    if let Some(version_req) = version_req_from_input(m) {
+        spec.version_req = Some(version_req)
+    }
+}
+
 /// Set a spec's channel from CLI values, falling back
 /// to a default value.
 fn set_channel(spec: &mut ServiceSpec, m: &ArgMatches) {
@@ -1164,13 +1577,127 @@ fn set_topology_from_input(spec: &mut ServiceSpec, m: &ArgMatches) {
     }
 }
 
-/// Set an update strategy only if specified by the user as a CLI
-/// argument.
+/// Set an update strategy, the canary size and soak, the update verification window and the
+/// update window only if specified by the user as CLI arguments.
 fn set_strategy_from_input(spec: &mut ServiceSpec, m: &ArgMatches) {
     if let Some(s) = m.value_of("STRATEGY") {
         // unwrap() is safe, because the input is validated by `valid_update_strategy`
         spec.update_strategy = UpdateStrategy::from_str(s).unwrap();
     }
+    // unwrap()s are safe, because the input is validated by `valid_canary_size`,
+    // `valid_seconds` and `valid_update_window`
+    if let Some(c) = m.value_of("CANARY_SIZE") {
+        spec.canary_size = CanarySize::from_str(c).unwrap();
+    }
+    if let Some(c) = m.value_of("CANARY_SOAK") {
+        spec.canary_soak = c.parse().unwrap();
+    }
+    if let Some(w) = m.value_of("UPDATE_VERIFY_WINDOW") {
+        spec.update_verify_window = Some(w.parse().unwrap());
+    }
+    if let Some(w) = m.value_of("UPDATE_WINDOW") {
+        spec.update_window = Some(UpdateWindow::from_str(w).unwrap());
+    }
+}
+
+/// Set the health check interval, timeout and timeout status only if specified by the user as
+/// CLI arguments.
+fn set_health_check_from_input(spec: &mut ServiceSpec, m: &ArgMatches) {
+    // unwrap()s are safe, because the input is validated by `valid_seconds` and
+    // `valid_health_check_timeout_status`
+    if let Some(i) = m.value_of("HEALTH_CHECK_INTERVAL") {
+        spec.health_check_interval = i.parse().unwrap();
+    }
+    if let Some(t) = m.value_of("HEALTH_CHECK_TIMEOUT") {
+        spec.health_check_timeout = Some(t.parse().unwrap());
+    }
+    if let Some(s) = m.value_of("HEALTH_CHECK_TIMEOUT_STATUS") {
+        spec.health_check_timeout_status = HealthCheck::from_str(s).unwrap();
+    }
+}
+
+/// Set hook timeouts if given on the command line, keeping the timeouts of any other hooks.
+fn set_hook_timeouts_from_input(spec: &mut ServiceSpec, m: &ArgMatches) {
+    if let Some(timeouts) = m.values_of("HOOK_TIMEOUT") {
+        for timeout in timeouts {
+            // unwrap() is safe, because the input is validated by `valid_hook_timeout`
+            let (hook, secs) = parse_hook_timeout(timeout).unwrap();
+            spec.hook_timeouts.insert(hook, secs);
+        }
+    }
+}
+
+fn set_run_mode_from_input(spec: &mut ServiceSpec, m: &ArgMatches) {
+    // unwrap() is safe, because the input is validated by `valid_run_mode`
+    if let Some(mode) = m.value_of("RUN_MODE") {
+        spec.run_mode = RunMode::from_str(mode).unwrap();
+    }
+}
+
+fn set_restart_policy_from_input(spec: &mut ServiceSpec, m: &ArgMatches) {
+    // unwrap()s are safe, because the input is validated by `valid_restart_policy`,
+    // `valid_max_restarts` and `valid_seconds`
+    if let Some(p) = m.value_of("RESTART_POLICY") {
+        spec.restart_policy = RestartPolicy::from_str(p).unwrap();
+    }
+    if let Some(n) = m.value_of("MAX_RESTARTS") {
+        spec.max_restarts = Some(n.parse().unwrap());
+    }
+    if let Some(w) = m.value_of("RESTART_WINDOW") {
+        spec.restart_window = w.parse().unwrap();
+    }
+    if let Some(b) = m.value_of("RESTART_BACKOFF") {
+        spec.restart_backoff = b.parse().unwrap();
+    }
+    if let Some(b) = m.value_of("RESTART_BACKOFF_MAX") {
+        spec.restart_backoff_max = b.parse().unwrap();
+    }
+}
+
+fn set_resource_limits_from_input(spec: &mut ServiceSpec, m: &ArgMatches) {
+    // unwrap()s are safe, because the input is validated by `valid_resource_limit` and
+    // `valid_cpu_limit`
+    if let Some(l) = m.value_of("MEMORY_LIMIT") {
+        spec.memory_limit = Some(l.parse().unwrap());
+    }
+    if let Some(l) = m.value_of("CPU_LIMIT") {
+        spec.cpu_limit = Some(l.parse().unwrap());
+    }
+    if let Some(l) = m.value_of("PIDS_LIMIT") {
+        spec.pids_limit = Some(l.parse().unwrap());
+    }
+}
+
+fn set_log_rotation_from_input(spec: &mut ServiceSpec, m: &ArgMatches) {
+    // unwrap()s are safe, because the input is validated by `valid_log_max_size`,
+    // `valid_seconds` and `valid_log_keep`
+    if let Some(s) = m.value_of("LOG_MAX_SIZE") {
+        spec.log_max_size = s.parse().unwrap();
+    }
+    if let Some(a) = m.value_of("LOG_MAX_AGE") {
+        spec.log_max_age = Some(a.parse().unwrap());
+    }
+    if let Some(k) = m.value_of("LOG_KEEP") {
+        spec.log_keep = k.parse().unwrap();
+    }
+}
+
+fn set_bind_wait_from_input(spec: &mut ServiceSpec, m: &ArgMatches) {
+    if m.is_present("WAIT_FOR_BINDS") {
+        spec.wait_for_binds = true;
+    }
+    // unwrap() is safe, because the input is validated by `valid_seconds`
+    if let Some(t) = m.value_of("BIND_WAIT_TIMEOUT") {
+        spec.bind_wait_timeout = Some(t.parse().unwrap());
+    }
+}
+
+fn parse_hook_timeout(val: &str) -> Option<(String, u64)> {
+    let parts: Vec<&str> = val.splitn(2, '=').collect();
+    if parts.len() != 2 {
+        return None;
+    }
+    parts[1].parse().ok().map(|secs| (parts[0].to_string(), secs))
 }
 
 /// Set bind values if given on the command line.
@@ -1291,10 +1818,18 @@ fn new_service_spec(ident: PackageIdent, m: &ArgMatches) -> Result<ServiceSpec>
 
     set_bldr_url(&mut spec, m);
     set_channel(&mut spec, m);
+    set_version_req_from_input(&mut spec, m);
 
     set_app_env_from_input(&mut spec, m)?;
     set_group_from_input(&mut spec, m);
     set_strategy_from_input(&mut spec, m);
+    set_health_check_from_input(&mut spec, m);
+    set_hook_timeouts_from_input(&mut spec, m);
+    set_run_mode_from_input(&mut spec, m);
+    set_restart_policy_from_input(&mut spec, m);
+    set_resource_limits_from_input(&mut spec, m);
+    set_log_rotation_from_input(&mut spec, m);
+    set_bind_wait_from_input(&mut spec, m);
     set_topology_from_input(&mut spec, m);
     set_binds_from_input(&mut spec, m)?;
     set_config_from_input(&mut spec, m)?;
@@ -1307,10 +1842,18 @@ fn update_spec_from_input(mut spec: &mut ServiceSpec, m: &ArgMatches) -> Result<
     // change them if the user specified something!
     set_bldr_url_from_input(&mut spec, m);
     set_channel_from_input(&mut spec, m);
+    set_version_req_from_input(&mut spec, m);
 
     set_app_env_from_input(&mut spec, m)?;
     set_group_from_input(&mut spec, m);
     set_strategy_from_input(&mut spec, m);
+    set_health_check_from_input(&mut spec, m);
+    set_hook_timeouts_from_input(&mut spec, m);
+    set_run_mode_from_input(&mut spec, m);
+    set_restart_policy_from_input(&mut spec, m);
+    set_resource_limits_from_input(&mut spec, m);
+    set_log_rotation_from_input(&mut spec, m);
+    set_bind_wait_from_input(&mut spec, m);
     set_topology_from_input(&mut spec, m);
 
     // TODO (CM): Remove these for composite-member specs
@@ -1346,9 +1889,17 @@ fn base_composite_service_spec(composite_name: &str, m: &ArgMatches) -> Result<S
     set_group_from_input(&mut spec, m);
 
     // For now, all a composite's services will also share the same
-    // update strategy and topology, though we may want to revisit
-    // this in the future (particularly for topology).
+    // update strategy, health checks, hook timeouts, restart policy, resource limits, log
+    // rotation and topology, though we may want to revisit this in the future (particularly
+    // for topology).
     set_strategy_from_input(&mut spec, m);
+    set_health_check_from_input(&mut spec, m);
+    set_hook_timeouts_from_input(&mut spec, m);
+    set_run_mode_from_input(&mut spec, m);
+    set_restart_policy_from_input(&mut spec, m);
+    set_resource_limits_from_input(&mut spec, m);
+    set_log_rotation_from_input(&mut spec, m);
+    set_bind_wait_from_input(&mut spec, m);
     set_topology_from_input(&mut spec, m);
 
     // TODO (CM): Not dealing with service passwords for now, since
@@ -1446,6 +1997,14 @@ fn dir_exists(val: String) -> result::Result<(), String> {
     }
 }
 
+fn file_exists(val: String) -> result::Result<(), String> {
+    if Path::new(&val).is_file() {
+        Ok(())
+    } else {
+        Err(format!("File: '{}' cannot be found", &val))
+    }
+}
+
 fn valid_service_group(val: String) -> result::Result<(), String> {
     match ServiceGroup::validate(&val) {
         Ok(()) => Ok(()),
@@ -1467,6 +2026,110 @@ fn valid_update_strategy(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_canary_size(val: String) -> result::Result<(), String> {
+    match CanarySize::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("'{}' is not a valid canary size", &val)),
+    }
+}
+
+fn valid_update_window(val: String) -> result::Result<(), String> {
+    match UpdateWindow::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!(
+            "Update window: '{}' is not valid, e.g. 'mon-fri 02:00-04:00 UTC'",
+            &val
+        )),
+    }
+}
+
+fn valid_version_req(val: String) -> result::Result<(), String> {
+    match VersionReq::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!(
+            "Version requirement: '{}' is not valid, e.g. '~1.4' or '>=2.0, <3.0'",
+            &val
+        )),
+    }
+}
+
+fn valid_seconds(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("'{}' is not a valid number of seconds", &val)),
+    }
+}
+
+fn valid_hook_timeout(val: String) -> result::Result<(), String> {
+    match parse_hook_timeout(&val) {
+        Some(_) => Ok(()),
+        None => Err(format!(
+            "Hook timeout: '{}' is not valid, must be <HOOK>=<SECONDS>",
+            &val
+        )),
+    }
+}
+
+fn valid_health_check_timeout_status(val: String) -> result::Result<(), String> {
+    match HealthCheck::from_str(&val) {
+        Ok(HealthCheck::Unknown) |
+        Ok(HealthCheck::Critical) => Ok(()),
+        _ => Err(format!(
+            "Health check timeout status: '{}' is not valid",
+            &val
+        )),
+    }
+}
+
+fn valid_run_mode(val: String) -> result::Result<(), String> {
+    match RunMode::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Run mode: '{}' is not valid", &val)),
+    }
+}
+
+fn valid_restart_policy(val: String) -> result::Result<(), String> {
+    match RestartPolicy::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Restart policy: '{}' is not valid", &val)),
+    }
+}
+
+fn valid_max_restarts(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("'{}' is not a valid number of restarts", &val)),
+    }
+}
+
+fn valid_resource_limit(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(limit) if limit > 0 => Ok(()),
+        _ => Err(format!("'{}' is not a valid resource limit", &val)),
+    }
+}
+
+fn valid_log_max_size(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(size) if size > 0 => Ok(()),
+        _ => Err(format!("'{}' is not a valid log size", &val)),
+    }
+}
+
+fn valid_log_keep(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("'{}' is not a valid number of logs", &val)),
+    }
+}
+
+fn valid_cpu_limit(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(limit) if limit > 0 => Ok(()),
+        _ => Err(format!("'{}' is not a valid CPU limit", &val)),
+    }
+}
+
 fn valid_url(val: String) -> result::Result<(), String> {
     match Url::parse(&val) {
         Ok(_) => Ok(()),
@@ -1499,23 +2162,28 @@ fn enable_features_from_env() {
 }
 
 /// Given an InstallSource, install a new package only if an existing
-/// one that can satisfy the package identifier is not already
-/// present.
+/// one that can satisfy the package identifier (and the version
+/// requirement, if given) is not already present.
 ///
 /// Return the PackageInstall corresponding to the package that was
 /// installed, or was pre-existing.
 fn install_package_if_not_present(
     install_source: &InstallSource,
     bldr_url: &str,
+    version_req: Option<&VersionReq>,
     channel: &str,
 ) -> Result<PackageInstall> {
-    match util::pkg::installed(install_source.as_ref()) {
-        Some(package) => Ok(package),
-        None => {
-            outputln!("Missing package for {}", install_source.as_ref());
-            util::pkg::install(&mut UI::default(), bldr_url, install_source, channel)
-        }
+    if let Some(package) = util::pkg::installed_matching(install_source.as_ref(), version_req) {
+        return Ok(package);
     }
+    outputln!("Missing package for {}", install_source.as_ref());
+    util::pkg::install_matching(
+        &mut UI::default(),
+        bldr_url,
+        install_source,
+        version_req,
+        channel,
+    )
 }
 
 /// Given an installed package, generate a spec (or specs, in the case
@@ -1576,6 +2244,13 @@ fn update_composite_service_specs(
         set_app_env_from_input(spec, m)?;
         set_group_from_input(spec, m);
         set_strategy_from_input(spec, m);
+        set_health_check_from_input(spec, m);
+        set_hook_timeouts_from_input(spec, m);
+        set_run_mode_from_input(spec, m);
+        set_restart_policy_from_input(spec, m);
+        set_resource_limits_from_input(spec, m);
+        set_log_rotation_from_input(spec, m);
+        set_bind_wait_from_input(spec, m);
         set_topology_from_input(spec, m);
 
         // No setting of config or password either; see notes in
@@ -0,0 +1,344 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Streams changes to the census and to the Supervisor's services to subscribers of the HTTP
+//! gateway's `/events` route.
+//!
+//! The Manager owns a `ChangeTracker` which keeps the last seen state of every census member
+//! and every running service. On each pass through the run loop the tracker compares the
+//! current state against that snapshot and publishes one `ChangeEvent` per added, removed or
+//! changed entry to the `ChangeFeed`. Each event carries the fields which changed along with
+//! their old and new values.
+
+use std::collections::{BTreeMap, HashMap};
+use std::mem;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+use serde::Serialize;
+use serde_json::{self, Value as Json};
+use time;
+
+use census::CensusRing;
+use manager::service::Service;
+
+/// Where a change was observed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ChangeSource {
+    #[serde(rename = "census")]
+    Census,
+    #[serde(rename = "service")]
+    Service,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ChangeKind {
+    #[serde(rename = "added")]
+    Added,
+    #[serde(rename = "removed")]
+    Removed,
+    #[serde(rename = "changed")]
+    Changed,
+}
+
+/// Old and new value of a single field. A value which did not exist is `null`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FieldDiff {
+    pub old: Json,
+    pub new: Json,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ChangeEvent {
+    pub id: u64,
+    pub timestamp: i64,
+    pub source: ChangeSource,
+    pub kind: ChangeKind,
+    pub service_group: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_id: Option<String>,
+    pub diff: BTreeMap<String, FieldDiff>,
+}
+
+impl ChangeEvent {
+    /// Name of the event, such as `service.changed`, used as the Server-Sent Event type.
+    pub fn name(&self) -> String {
+        format!(
+            "{}.{}",
+            serde_json::to_value(&self.source).unwrap().as_str().unwrap(),
+            serde_json::to_value(&self.kind).unwrap().as_str().unwrap()
+        )
+    }
+}
+
+/// Fan-out of `ChangeEvent`s to every connected subscriber.
+///
+/// Subscribers which have gone away are dropped the next time an event is published.
+#[derive(Clone, Default)]
+pub struct ChangeFeed {
+    subscribers: Arc<Mutex<Vec<Sender<Arc<ChangeEvent>>>>>,
+}
+
+impl ChangeFeed {
+    pub fn subscribe(&self) -> Receiver<Arc<ChangeEvent>> {
+        let (tx, rx) = channel();
+        self.subscribers
+            .lock()
+            .expect("ChangeFeed subscribers lock is poisoned!")
+            .push(tx);
+        rx
+    }
+
+    fn publish(&self, event: ChangeEvent) {
+        let event = Arc::new(event);
+        self.subscribers
+            .lock()
+            .expect("ChangeFeed subscribers lock is poisoned!")
+            .retain(|tx| tx.send(event.clone()).is_ok());
+    }
+}
+
+type Snapshot = BTreeMap<String, Json>;
+
+pub struct ChangeTracker {
+    feed: ChangeFeed,
+    census: HashMap<(String, String), Snapshot>,
+    services: HashMap<String, Snapshot>,
+    next_id: u64,
+}
+
+impl ChangeTracker {
+    pub fn new(feed: ChangeFeed) -> Self {
+        ChangeTracker {
+            feed: feed,
+            census: HashMap::new(),
+            services: HashMap::new(),
+            next_id: 1,
+        }
+    }
+
+    /// Publish the differences between the census members we last saw and the members of the
+    /// given census. This should be called whenever `CensusRing::changed()` is true.
+    pub fn census_changed(&mut self, census_ring: &CensusRing) {
+        let mut current = HashMap::new();
+        for group in census_ring.groups() {
+            for member in group.members() {
+                let key = (group.service_group.to_string(), member.member_id.clone());
+                current.insert(key, snapshot(member));
+            }
+        }
+        let previous = mem::replace(&mut self.census, current);
+        let mut events = Vec::new();
+        for (&(ref sg, ref member_id), new) in self.census.iter() {
+            let old = previous.get(&(sg.clone(), member_id.clone()));
+            if let Some((kind, diff)) = compare(old, Some(new)) {
+                events.push((kind, sg.clone(), Some(member_id.clone()), diff));
+            }
+        }
+        for (&(ref sg, ref member_id), old) in previous.iter() {
+            if !self.census.contains_key(&(sg.clone(), member_id.clone())) {
+                let (kind, diff) = compare(Some(old), None).unwrap();
+                events.push((kind, sg.clone(), Some(member_id.clone()), diff));
+            }
+        }
+        for (kind, sg, member_id, diff) in events {
+            self.publish(ChangeSource::Census, kind, sg, member_id, diff);
+        }
+    }
+
+    /// Publish any changes to the process state, health, package or last rollback of the given
+    /// services, as well as services which have been added or removed since the last call.
+    pub fn services_changed(&mut self, services: &[Service]) {
+        let mut current = HashMap::new();
+        for service in services {
+            let mut fields = Snapshot::new();
+            fields.insert(
+                "process_state".to_string(),
+                serde_json::to_value(service.process_state()).unwrap_or(Json::Null),
+            );
+            fields.insert(
+                "health_check".to_string(),
+                serde_json::to_value(&service.health_check()).unwrap_or(Json::Null),
+            );
+            fields.insert(
+                "pkg".to_string(),
+                Json::String(service.pkg.ident.to_string()),
+            );
+            fields.insert(
+                "last_rollback".to_string(),
+                serde_json::to_value(&service.last_rollback).unwrap_or(Json::Null),
+            );
+            current.insert(service.service_group.to_string(), fields);
+        }
+        let previous = mem::replace(&mut self.services, current);
+        let mut events = Vec::new();
+        for (sg, new) in self.services.iter() {
+            if let Some((kind, diff)) = compare(previous.get(sg), Some(new)) {
+                events.push((kind, sg.clone(), diff));
+            }
+        }
+        for (sg, old) in previous.iter() {
+            if !self.services.contains_key(sg) {
+                let (kind, diff) = compare(Some(old), None).unwrap();
+                events.push((kind, sg.clone(), diff));
+            }
+        }
+        for (kind, sg, diff) in events {
+            self.publish(ChangeSource::Service, kind, sg, None, diff);
+        }
+    }
+
+    fn publish(
+        &mut self,
+        source: ChangeSource,
+        kind: ChangeKind,
+        service_group: String,
+        member_id: Option<String>,
+        diff: BTreeMap<String, FieldDiff>,
+    ) {
+        let event = ChangeEvent {
+            id: self.next_id,
+            timestamp: time::get_time().sec,
+            source: source,
+            kind: kind,
+            service_group: service_group,
+            member_id: member_id,
+            diff: diff,
+        };
+        self.next_id += 1;
+        debug!("Publishing change event {:?}", &event);
+        self.feed.publish(event);
+    }
+}
+
+fn snapshot<T>(value: &T) -> Snapshot
+where
+    T: Serialize,
+{
+    match serde_json::to_value(value) {
+        Ok(Json::Object(map)) => map.into_iter().collect(),
+        _ => Snapshot::new(),
+    }
+}
+
+/// Compare two snapshots of the same entry, returning the kind of change and the fields which
+/// differ, or `None` if nothing changed.
+fn compare(
+    old: Option<&Snapshot>,
+    new: Option<&Snapshot>,
+) -> Option<(ChangeKind, BTreeMap<String, FieldDiff>)> {
+    let empty = Snapshot::new();
+    let kind = match (old, new) {
+        (None, Some(_)) => ChangeKind::Added,
+        (Some(_), None) => ChangeKind::Removed,
+        (Some(_), Some(_)) => ChangeKind::Changed,
+        (None, None) => return None,
+    };
+    let old = old.unwrap_or(&empty);
+    let new = new.unwrap_or(&empty);
+    let mut diff = BTreeMap::new();
+    for (key, old_value) in old.iter() {
+        let new_value = new.get(key).cloned().unwrap_or(Json::Null);
+        if *old_value != new_value {
+            diff.insert(
+                key.clone(),
+                FieldDiff {
+                    old: old_value.clone(),
+                    new: new_value,
+                },
+            );
+        }
+    }
+    for (key, new_value) in new.iter().filter(|&(k, _)| !old.contains_key(k)) {
+        diff.insert(
+            key.clone(),
+            FieldDiff {
+                old: Json::Null,
+                new: new_value.clone(),
+            },
+        );
+    }
+    if kind == ChangeKind::Changed && diff.is_empty() {
+        None
+    } else {
+        Some((kind, diff))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::Value as Json;
+
+    use super::*;
+
+    fn snapshot_of(fields: &[(&str, &str)]) -> Snapshot {
+        fields
+            .iter()
+            .map(|&(k, v)| (k.to_string(), Json::String(v.to_string())))
+            .collect()
+    }
+
+    #[test]
+    fn compare_unchanged() {
+        let old = snapshot_of(&[("process_state", "Up")]);
+        let new = old.clone();
+        assert_eq!(compare(Some(&old), Some(&new)), None);
+    }
+
+    #[test]
+    fn compare_changed() {
+        let old = snapshot_of(&[("process_state", "Up"), ("pkg", "core/redis/3.2.4/1")]);
+        let new = snapshot_of(&[("process_state", "Down"), ("pkg", "core/redis/3.2.4/1")]);
+        let (kind, diff) = compare(Some(&old), Some(&new)).unwrap();
+        assert_eq!(kind, ChangeKind::Changed);
+        assert_eq!(diff.len(), 1);
+        assert_eq!(
+            diff["process_state"],
+            FieldDiff {
+                old: Json::String("Up".to_string()),
+                new: Json::String("Down".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn compare_added_and_removed() {
+        let entry = snapshot_of(&[("process_state", "Up")]);
+
+        let (kind, diff) = compare(None, Some(&entry)).unwrap();
+        assert_eq!(kind, ChangeKind::Added);
+        assert_eq!(diff["process_state"].old, Json::Null);
+
+        let (kind, diff) = compare(Some(&entry), None).unwrap();
+        assert_eq!(kind, ChangeKind::Removed);
+        assert_eq!(diff["process_state"].new, Json::Null);
+    }
+
+    #[test]
+    fn feed_drops_closed_subscribers() {
+        let feed = ChangeFeed::default();
+        let rx = feed.subscribe();
+        drop(feed.subscribe());
+        let mut tracker = ChangeTracker::new(feed.clone());
+        tracker.publish(
+            ChangeSource::Service,
+            ChangeKind::Added,
+            "redis.default".to_string(),
+            None,
+            BTreeMap::new(),
+        );
+        assert_eq!(rx.recv().unwrap().name(), "service.added");
+        assert_eq!(feed.subscribers.lock().unwrap().len(), 1);
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod change_feed;
 pub mod service;
 #[macro_use]
 mod debug;
@@ -47,6 +48,7 @@ use hcore::fs::FS_ROOT_PATH;
 use hcore::service::ServiceGroup;
 use hcore::os::process::{self, Pid, Signal};
 use hcore::package::{Identifiable, PackageIdent, PackageInstall};
+use hcore::util::deserialize_using_from_str;
 use launcher_client::{LAUNCHER_LOCK_CLEAN_ENV, LAUNCHER_PID_ENV, LauncherCli};
 use serde;
 use serde_json;
@@ -54,8 +56,9 @@ use time::{self, Timespec, Duration as TimeDuration};
 
 pub use self::service::{CompositeSpec, Service, ServiceBind, ServiceSpec, UpdateStrategy, Topology};
 pub use self::sys::Sys;
+use self::change_feed::{ChangeFeed, ChangeTracker};
 use self::self_updater::{SUP_PKG_IDENT, SelfUpdater};
-use self::service::{DesiredState, Pkg, ProcessState, StartStyle};
+use self::service::{DesiredState, Pkg, ProcessState, RunMode, RunResult, StartStyle};
 use self::service_updater::ServiceUpdater;
 use self::spec_watcher::{SpecWatcher, SpecWatcherEvent};
 use self::peer_watcher::PeerWatcher;
@@ -95,7 +98,7 @@ pub struct FsCfg {
 }
 
 impl FsCfg {
-    fn new<T>(sup_svc_root: T) -> Self
+    pub fn new<T>(sup_svc_root: T) -> Self
     where
         T: Into<PathBuf>,
     {
@@ -118,6 +121,21 @@ impl FsCfg {
             format!("{}.health", service_group.service()),
         )
     }
+
+    /// Releases blocked after a failed update are kept per service group, as the same service may
+    /// be loaded in several groups which fail independently.
+    pub fn blocked_releases(&self, service_group: &ServiceGroup) -> PathBuf {
+        self.data_path.join(format!("{}.blocked", service_group))
+    }
+
+    pub fn spec_path_for(&self, spec: &ServiceSpec) -> PathBuf {
+        self.specs_path.join(spec.file_name())
+    }
+
+    /// Path to the spec file of the service with the given name, such as `redis`.
+    pub fn spec_path_for_service(&self, service_name: &str) -> PathBuf {
+        self.specs_path.join(format!("{}.spec", service_name))
+    }
 }
 
 #[derive(Clone, Default)]
@@ -134,6 +152,11 @@ pub struct ManagerConfig {
     pub name: Option<String>,
     pub organization: Option<String>,
     pub watch_peer_file: Option<String>,
+    /// Bearer token required by the HTTP gateway's control routes. The control routes are
+    /// disabled when no token is set.
+    pub http_auth_token: Option<String>,
+    /// Serve the HTTP gateway over TLS with this certificate and key.
+    pub http_tls: Option<http_gateway::TlsCfg>,
 
     custom_state_path: Option<PathBuf>,
 }
@@ -141,8 +164,12 @@ pub struct ManagerConfig {
 pub struct Manager {
     butterfly: butterfly::Server,
     census_ring: CensusRing,
+    change_tracker: ChangeTracker,
+    change_feed: ChangeFeed,
     events_group: Option<ServiceGroup>,
     fs_cfg: Arc<FsCfg>,
+    http_auth_token: Option<String>,
+    http_tls: Option<http_gateway::TlsCfg>,
     launcher: LauncherCli,
     services: Arc<RwLock<Vec<Service>>>,
     updater: ServiceUpdater,
@@ -310,8 +337,11 @@ impl Manager {
         } else {
             None
         };
+        let change_feed = ChangeFeed::default();
         Ok(Manager {
             self_updater: self_updater,
+            change_tracker: ChangeTracker::new(change_feed.clone()),
+            change_feed: change_feed,
             updater: ServiceUpdater::new(server.clone()),
             census_ring: CensusRing::new(sys.member_id.clone()),
             butterfly: server,
@@ -320,6 +350,8 @@ impl Manager {
             services: services,
             watcher: SpecWatcher::run(&fs_cfg.specs_path)?,
             fs_cfg: Arc::new(fs_cfg),
+            http_auth_token: cfg.http_auth_token,
+            http_tls: cfg.http_tls,
             organization: cfg.organization,
             service_states: HashMap::new(),
             sys: Arc::new(sys),
@@ -539,8 +571,13 @@ impl Manager {
         self.persist_state();
         let http_listen_addr = self.sys.http_listen();
         outputln!("Starting http-gateway on {}", &http_listen_addr);
-        http_gateway::Server::new(self.fs_cfg.clone(), http_listen_addr)
-            .start()?;
+        http_gateway::Server::new(
+            self.fs_cfg.clone(),
+            http_listen_addr,
+            self.http_auth_token.clone(),
+            self.http_tls.clone(),
+            self.change_feed.clone(),
+        ).start()?;
         debug!("http-gateway started");
         let events = match self.events_group {
             Some(ref evg) => Some(events::EventsMgr::start(evg.clone())),
@@ -583,6 +620,7 @@ impl Manager {
 
             if self.census_ring.changed() {
                 self.persist_state();
+                self.change_tracker.census_changed(&self.census_ring);
                 events.as_ref().map(|events| {
                     events.try_connect(&self.census_ring)
                 });
@@ -604,6 +642,7 @@ impl Manager {
                 }
             }
 
+            let mut rejected_config = false;
             for service in self.services
                 .write()
                 .expect("Services lock is poisoned!")
@@ -612,7 +651,18 @@ impl Manager {
                 if service.tick(&self.census_ring, &self.launcher) {
                     self.gossip_latest_service_rumor(&service);
                 }
+                service.update_metrics(&self.census_ring);
+                for incarnation in service.cfg.take_rejected() {
+                    self.census_ring.reject_config(&service.service_group, incarnation);
+                    rejected_config = true;
+                }
+            }
+            if rejected_config {
+                self.persist_census_state();
             }
+            self.change_tracker.services_changed(
+                &self.services.read().expect("Services lock is poisoned!"),
+            );
             let time_to_wait = (next_check - time::get_time()).num_milliseconds();
             if time_to_wait > 0 {
                 thread::sleep(Duration::from_millis(time_to_wait as u64));
@@ -836,6 +886,7 @@ impl Manager {
         if term {
             service.stop(&self.launcher);
         }
+        service.remove_metrics();
         if service.start_style == StartStyle::Transient {
             // JW TODO: If we cleanup our Service structure to hold the ServiceSpec instead of
             // deconstruct it (see my comments in `add_service()` in this module) then we could
@@ -1011,6 +1062,12 @@ pub struct ServiceStatus {
     pub service_group: ServiceGroup,
     pub start_style: StartStyle,
     pub composite: Option<String>,
+    #[serde(default)]
+    pub pending_update: Option<PackageIdent>,
+    #[serde(default, deserialize_with = "deserialize_using_from_str")]
+    pub run_mode: RunMode,
+    #[serde(default)]
+    pub last_run: Option<RunResult>,
 }
 
 impl fmt::Display for ServiceStatus {
@@ -1023,7 +1080,17 @@ impl fmt::Display for ServiceStatus {
             self.process,
             self.service_group,
             self.start_style
-        )
+        )?;
+        if let Some(ref ident) = self.pending_update {
+            write!(f, ", update pending:{}", ident)?;
+        }
+        if self.run_mode == RunMode::OneShot {
+            write!(f, ", mode:{}", self.run_mode)?;
+            if let Some(ref run) = self.last_run {
+                write!(f, ", last run:{}", run)?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -1153,7 +1220,9 @@ where
 mod test {
     use std::path::PathBuf;
 
-    use super::{Manager, ManagerConfig, STATE_PATH_PREFIX};
+    use hcore::service::ServiceGroup;
+
+    use super::{FsCfg, Manager, ManagerConfig, STATE_PATH_PREFIX};
 
     #[test]
     fn manager_state_path_default() {
@@ -1196,4 +1265,19 @@ mod test {
 
         assert_eq!(PathBuf::from("/tmp/partay"), path);
     }
+
+    #[test]
+    fn blocked_releases_per_service_group() {
+        let fs_cfg = FsCfg::new("/hab/sup/default");
+        let one = ServiceGroup::new(None, "redis", "one", None).unwrap();
+        let two = ServiceGroup::new(None, "redis", "two", Some("acme")).unwrap();
+        assert_eq!(
+            fs_cfg.blocked_releases(&one),
+            PathBuf::from("/hab/sup/default/data/redis.one.blocked")
+        );
+        assert_eq!(
+            fs_cfg.blocked_releases(&two),
+            PathBuf::from("/hab/sup/default/data/redis.two@acme.blocked")
+        );
+    }
 }
@@ -16,6 +16,7 @@
 
 use std;
 use std::ascii::AsciiExt;
+use std::collections::BTreeMap;
 use std::env;
 use std::fs::File;
 use std::io::prelude::*;
@@ -23,9 +24,12 @@ use std::path::{Path, PathBuf};
 use std::result;
 
 use ansi_term::Colour::Purple;
+use common::config_schema::ConfigSchema;
+use common::config_secret;
 use hcore::crypto;
+use hcore::crypto::default_cache_key_path;
 use serde::{Serialize, Serializer};
-use serde::ser::SerializeMap;
+use serde::ser::{Error as SerError, SerializeMap};
 use serde_json;
 use toml;
 
@@ -53,9 +57,13 @@ pub struct Cfg {
     pub gossip: Option<toml::Value>,
     /// Environment level configuration loaded by the Supervisor's process environment
     pub environment: Option<toml::Value>,
+    /// Schema loaded by a Package's `config_schema.toml`, which gossiped configuration must match
+    pub schema: Option<ConfigSchema>,
 
     /// Last known incarnation number of the census group's service config
     gossip_incarnation: u64,
+    /// Incarnations of the census group's service config rejected since they were last taken
+    rejected: Vec<u64>,
 }
 
 impl Cfg {
@@ -64,14 +72,26 @@ impl Cfg {
             &package.path,
         );
         let mut cfg = Cfg::default();
+        cfg.schema = ConfigSchema::load(&pkg_root)?;
         cfg.load_default(&pkg_root)?;
         cfg.load_user(&package)?;
         cfg.load_environment(&package)?;
         Ok(cfg)
     }
 
+    /// Load only the package's default configuration and schema, leaving out the service's
+    /// `user.toml` and any configuration in the environment, so that the result is the same on
+    /// every machine.
+    pub fn from_defaults(package: &Pkg) -> Result<Cfg> {
+        let mut cfg = Cfg::default();
+        cfg.schema = ConfigSchema::load(&package.path)?;
+        cfg.load_default(&package.path)?;
+        Ok(cfg)
+    }
+
     /// Updates the service configuration with data from a census group if the census group has
-    /// newer data than the current configuration.
+    /// newer data than the current configuration. Newer data which doesn't match the package's
+    /// schema is rejected.
     ///
     /// Returns true if the configuration was updated.
     pub fn update(&mut self, census_group: &CensusGroup) -> bool {
@@ -81,6 +101,16 @@ impl Cfg {
                     return false;
                 }
                 self.gossip_incarnation = config.incarnation;
+                if let Err(err) = self.validate_gossip(&config.value) {
+                    outputln!(
+                        "Rejecting configuration incarnation {} for {}, {}",
+                        config.incarnation,
+                        census_group.service_group,
+                        err
+                    );
+                    self.rejected.push(config.incarnation);
+                    return false;
+                }
                 self.gossip = Some(config.value.clone());
                 true
             }
@@ -88,10 +118,56 @@ impl Cfg {
         }
     }
 
+    /// Returns the incarnations `update` rejected since this was last called.
+    pub fn take_rejected(&mut self) -> Vec<u64> {
+        std::mem::replace(&mut self.rejected, Vec::new())
+    }
+
+    /// Validates the configuration which results from merging the given gossip level over the
+    /// other levels against the package's schema, if it has one.
+    pub fn validate_gossip(&self, gossip: &toml::Value) -> Result<()> {
+        match self.schema {
+            Some(ref schema) => {
+                let mut candidate = self.clone();
+                candidate.gossip = Some(gossip.clone());
+                schema.validate(&candidate.merged(), false)?;
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Returns every level of configuration merged into a single table.
+    fn merged(&self) -> toml::value::Table {
+        let mut table = toml::value::Table::new();
+        if let Some(toml::Value::Table(ref default_cfg)) = self.default {
+            if let Err(err) = toml_merge(&mut table, default_cfg) {
+                outputln!("Error merging default-cfg into config, {}", err);
+            }
+        }
+        if let Some(toml::Value::Table(ref env_cfg)) = self.environment {
+            if let Err(err) = toml_merge(&mut table, env_cfg) {
+                outputln!("Error merging environment-cfg into config, {}", err);
+            }
+        }
+        if let Some(toml::Value::Table(ref user_cfg)) = self.user {
+            if let Err(err) = toml_merge(&mut table, user_cfg) {
+                outputln!("Error merging user-cfg into config, {}", err);
+            }
+        }
+        if let Some(toml::Value::Table(ref gossip_cfg)) = self.gossip {
+            if let Err(err) = toml_merge(&mut table, gossip_cfg) {
+                outputln!("Error merging gossip-cfg into config, {}", err);
+            }
+        }
+        table
+    }
+
     /// Returns a subset of the overall configuration whitelisted by the given package's exports.
+    /// Secrets are encrypted for this service group alone, so exporting one is an error.
     pub fn to_exported(&self, pkg: &Pkg) -> Result<toml::value::Table> {
         let mut map = toml::value::Table::default();
-        let cfg = toml::Value::try_from(&self).unwrap();
+        let cfg = toml::Value::Table(self.merged());
         for (key, path) in pkg.exports.iter() {
             let fields: Vec<&str> = path.split('.').collect();
             let mut curr = &cfg;
@@ -112,6 +188,9 @@ impl Cfg {
             }
 
             if found {
+                if config_secret::has_secret(curr) {
+                    return Err(sup_error!(Error::ExportedSecret(key.clone())));
+                }
                 map.insert(key.clone(), curr.clone());
             }
         }
@@ -211,51 +290,61 @@ impl Serialize for Cfg {
     where
         S: Serializer,
     {
-        let mut table = toml::value::Table::new();
-        if let Some(toml::Value::Table(ref default_cfg)) = self.default {
-            if let Err(err) = toml_merge(&mut table, default_cfg) {
-                outputln!("Error merging default-cfg into config, {}", err);
-            }
-        }
-        if let Some(toml::Value::Table(ref env_cfg)) = self.environment {
-            if let Err(err) = toml_merge(&mut table, env_cfg) {
-                outputln!("Error merging environment-cfg into config, {}", err);
-            }
-        }
-        if let Some(toml::Value::Table(ref user_cfg)) = self.user {
-            if let Err(err) = toml_merge(&mut table, user_cfg) {
-                outputln!("Error merging user-cfg into config, {}", err);
-            }
-        }
-        if let Some(toml::Value::Table(ref gossip_cfg)) = self.gossip {
-            if let Err(err) = toml_merge(&mut table, gossip_cfg) {
-                outputln!("Error merging gossip-cfg into config, {}", err);
-            }
-        }
+        let mut table = self.merged();
+        config_secret::redact_secrets(&mut table);
+        serialize_table(&table, serializer)
+    }
+}
 
-        // Be sure to visit non-tables first (and also non
-        // array-of-tables) as all keys must be emitted first.
-        let mut map = serializer.serialize_map(Some(table.len()))?;
-        for (k, v) in &table {
-            if !v.is_array() && !v.is_table() {
-                map.serialize_key(&k)?;
-                map.serialize_value(&v)?;
-            }
+/// The view of a service's configuration given to templates and hooks. Unlike `Cfg` itself, which
+/// redacts secrets whenever it's serialized, this decrypts them with the service group's key.
+#[derive(Clone, Debug)]
+pub struct RenderCfg<'a>(&'a Cfg);
+
+impl<'a> RenderCfg<'a> {
+    pub fn new(cfg: &'a Cfg) -> Self {
+        RenderCfg(cfg)
+    }
+}
+
+impl<'a> Serialize for RenderCfg<'a> {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut table = self.0.merged();
+        config_secret::decrypt_secrets(&mut table, &default_cache_key_path(None))
+            .map_err(|e| S::Error::custom(e))?;
+        serialize_table(&table, serializer)
+    }
+}
+
+fn serialize_table<S>(table: &toml::value::Table, serializer: S) -> result::Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    // Be sure to visit non-tables first (and also non
+    // array-of-tables) as all keys must be emitted first.
+    let mut map = serializer.serialize_map(Some(table.len()))?;
+    for (k, v) in table {
+        if !v.is_array() && !v.is_table() {
+            map.serialize_key(&k)?;
+            map.serialize_value(&v)?;
         }
-        for (k, v) in &table {
-            if v.is_array() {
-                map.serialize_key(&k)?;
-                map.serialize_value(&v)?;
-            }
+    }
+    for (k, v) in table {
+        if v.is_array() {
+            map.serialize_key(&k)?;
+            map.serialize_value(&v)?;
         }
-        for (k, v) in &table {
-            if v.is_table() {
-                map.serialize_key(&k)?;
-                map.serialize_value(&v)?;
-            }
+    }
+    for (k, v) in table {
+        if v.is_table() {
+            map.serialize_key(&k)?;
+            map.serialize_value(&v)?;
         }
-        map.end()
     }
+    map.end()
 }
 
 #[derive(Debug)]
@@ -295,6 +384,16 @@ impl CfgRenderer {
         Ok(CfgRenderer(template))
     }
 
+    /// Render all configuration files without writing them to disk, returning the content of
+    /// each file keyed by its name.
+    pub fn render(&self, ctx: &RenderContext) -> Result<BTreeMap<String, String>> {
+        let mut rendered = BTreeMap::new();
+        for (template, _) in self.0.get_templates() {
+            rendered.insert(template.clone(), self.0.render(&template, ctx)?);
+        }
+        Ok(rendered)
+    }
+
     /// Compile and write all configuration files to the configuration directory.
     pub fn compile(&self, pkg: &Pkg, ctx: &RenderContext) -> Result<bool> {
         // JW TODO: This function is loaded with IO errors that will be converted a Supervisor
@@ -302,8 +401,7 @@ impl CfgRenderer {
         // through this and pipe the service group through to let people know which service is
         // having issues and be more descriptive about what happened.
         let mut changed = false;
-        for (template, _) in self.0.get_templates() {
-            let compiled = self.0.render(&template, ctx)?;
+        for (template, compiled) in self.render(ctx)? {
             let compiled_hash = crypto::hash::hash_string(&compiled);
             let cfg_dest = pkg.svc_config_path.join(&template);
             let file_hash = match crypto::hash::hash_file(&cfg_dest) {
@@ -621,4 +719,79 @@ mod test {
 
         assert_eq!(default_toml, toml::to_string(&cfg).unwrap());
     }
+
+    #[test]
+    fn validate_gossip_against_schema() {
+        let mut cfg = Cfg::default();
+        cfg.default = Some(toml::Value::Table(toml_from_str("port = 6379\nloglevel = \"notice\"")));
+        assert!(cfg.validate_gossip(&toml::Value::Table(toml_from_str("port = \"abc\"")))
+            .is_ok());
+
+        cfg.schema = Some(
+            ConfigSchema::from_str(
+                r#"
+                [keys.port]
+                type = "integer"
+                required = true
+
+                [keys.loglevel]
+                type = "string"
+                allowed = ["debug", "notice", "warning"]
+                "#,
+            ).unwrap(),
+        );
+        assert!(cfg.validate_gossip(&toml::Value::Table(toml_from_str("port = 6380")))
+            .is_ok());
+        assert!(cfg.validate_gossip(&toml::Value::Table(toml_from_str("port = \"abc\"")))
+            .is_err());
+        assert!(cfg.validate_gossip(&toml::Value::Table(toml_from_str("loglevel = \"loud\"")))
+            .is_err());
+    }
+
+    #[test]
+    fn serialize_redacts_secrets() {
+        let mut cfg = Cfg::default();
+        cfg.default = Some(toml::Value::Table(toml_from_str("port = 6379")));
+        cfg.user = Some(toml::Value::Table(
+            toml_from_str("[db]\npassword = \"hab-secret:Qk9YLTE=\""),
+        ));
+
+        let serialized = toml::Value::try_from(&cfg).unwrap();
+        assert_eq!(
+            serialized,
+            toml::Value::Table(toml_from_str("port = 6379\n[db]\npassword = \"<redacted>\""))
+        );
+        // Secrets that can't be decrypted fail rendering rather than leaking the ciphertext
+        assert!(serde_json::to_value(&RenderCfg::new(&cfg)).is_err());
+    }
+
+    #[test]
+    fn to_exported_refuses_secrets() {
+        let pkg_id = PackageIdent::new("testing", "testing", Some("1.0.0"), Some("20170712000000"));
+        let pkg_install = PackageInstall::new_from_parts(
+            pkg_id.clone(),
+            PathBuf::from("/tmp"),
+            PathBuf::from("/tmp"),
+            PathBuf::from("/tmp"),
+        );
+        let mut pkg = Pkg::from_install(pkg_install).expect("Could not create package!");
+        pkg.exports.insert("port".to_string(), "port".to_string());
+        let mut cfg = Cfg::default();
+        cfg.default = Some(toml::Value::Table(toml_from_str("port = 6379")));
+        cfg.user = Some(toml::Value::Table(
+            toml_from_str("[db]\npassword = \"hab-secret:Qk9YLTE=\""),
+        ));
+        assert_eq!(cfg.to_exported(&pkg).unwrap(), toml_from_str("port = 6379"));
+
+        pkg.exports.insert("password".to_string(), "db.password".to_string());
+        match cfg.to_exported(&pkg) {
+            Err(e) => {
+                match e.err {
+                    Error::ExportedSecret(ref key) => assert_eq!(key, "password"),
+                    _ => panic!("Should fail with Error::ExportedSecret"),
+                }
+            }
+            Ok(_) => panic!("Should not export a secret"),
+        }
+    }
 }
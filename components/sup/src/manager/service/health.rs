@@ -13,8 +13,18 @@
 // limitations under the License.
 
 use std::fmt;
+use std::result;
+use std::str::FromStr;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+use error::{Error, SupError};
+
+static LOGKEY: &'static str = "HC";
+
+/// Number of consecutive health checks a changed health status must hold for before it is
+/// gossiped, so that a flapping service doesn't flood the ring with rumors.
+pub const HEALTH_GOSSIP_CHECKS: u32 = 3;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum HealthCheck {
     Ok,
     Warning,
@@ -40,6 +50,20 @@ impl From<i8> for HealthCheck {
     }
 }
 
+impl FromStr for HealthCheck {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "ok" => Ok(HealthCheck::Ok),
+            "warning" => Ok(HealthCheck::Warning),
+            "critical" => Ok(HealthCheck::Critical),
+            "unknown" => Ok(HealthCheck::Unknown),
+            _ => Err(sup_error!(Error::BadHealthCheck(value.to_string()))),
+        }
+    }
+}
+
 impl fmt::Display for HealthCheck {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
@@ -52,6 +76,49 @@ impl fmt::Display for HealthCheck {
     }
 }
 
+/// The health status a service gossips to the other members. The first health check is gossiped
+/// right away, after which a changed status is only gossiped once it has held for
+/// `HEALTH_GOSSIP_CHECKS` checks in a row.
+#[derive(Debug, Default)]
+pub struct GossipedHealth {
+    status: Option<HealthCheck>,
+    /// A status differing from the gossiped one and how many checks in a row it has held for
+    pending: Option<(HealthCheck, u32)>,
+}
+
+impl GossipedHealth {
+    pub fn status(&self) -> HealthCheck {
+        self.status.unwrap_or_default()
+    }
+
+    /// Record the result of a health check, returning true if the gossiped status changed.
+    pub fn record(&mut self, check: HealthCheck) -> bool {
+        let current = match self.status {
+            Some(current) => current,
+            None => {
+                self.status = Some(check);
+                return true;
+            }
+        };
+        if check == current {
+            self.pending = None;
+            return false;
+        }
+        let held = match self.pending {
+            Some((pending, held)) if pending == check => held + 1,
+            _ => 1,
+        };
+        if held >= HEALTH_GOSSIP_CHECKS {
+            self.status = Some(check);
+            self.pending = None;
+            true
+        } else {
+            self.pending = Some((check, held));
+            false
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum SmokeCheck {
     Ok,
@@ -75,3 +142,42 @@ impl fmt::Display for SmokeCheck {
         write!(f, "{}", msg)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn gossiped_health_follows_first_check() {
+        let mut health = GossipedHealth::default();
+        assert_eq!(health.status(), HealthCheck::Unknown);
+        assert!(health.record(HealthCheck::Ok));
+        assert_eq!(health.status(), HealthCheck::Ok);
+        assert!(!health.record(HealthCheck::Ok));
+    }
+
+    #[test]
+    fn gossiped_health_changes_once_status_holds() {
+        let mut health = GossipedHealth::default();
+        health.record(HealthCheck::Ok);
+        assert!(!health.record(HealthCheck::Critical));
+        assert!(!health.record(HealthCheck::Critical));
+        assert_eq!(health.status(), HealthCheck::Ok);
+        assert!(health.record(HealthCheck::Critical));
+        assert_eq!(health.status(), HealthCheck::Critical);
+    }
+
+    #[test]
+    fn gossiped_health_ignores_flapping() {
+        let mut health = GossipedHealth::default();
+        health.record(HealthCheck::Ok);
+        for _ in 0..10 {
+            assert!(!health.record(HealthCheck::Critical));
+            assert!(!health.record(HealthCheck::Ok));
+        }
+        assert!(!health.record(HealthCheck::Warning));
+        assert!(!health.record(HealthCheck::Critical));
+        assert!(!health.record(HealthCheck::Critical));
+        assert_eq!(health.status(), HealthCheck::Ok);
+    }
+}
@@ -13,11 +13,16 @@
 // limitations under the License.
 
 use std;
+use std::collections::BTreeMap;
 use std::fmt;
 use std::io::BufReader;
 use std::io::prelude::*;
-use std::fs::File;
+use std::fs::{File, OpenOptions};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
 #[cfg(not(windows))]
 use std::process::{Child, ExitStatus};
 #[cfg(windows)]
@@ -27,16 +32,23 @@ use std::result;
 use ansi_term::Colour;
 use hcore;
 use hcore::crypto;
+use hcore::output::{Level, StructuredOutput};
+use hcore::package::metadata::HookSchedule;
 use hcore::service::ServiceGroup;
+use hcore::util::rotating_log::{RotatingLog, RotationPolicy};
 use serde::{Serialize, Serializer};
 
-use super::{health, Pkg};
+use super::{health, metrics, Pkg};
 use error::{Result, SupError};
 use fs;
+use manager::periodic::Periodic;
 use templating::{RenderContext, TemplateRenderer};
 use util::exec;
 
 pub const HOOK_PERMISSIONS: u32 = 0o755;
+/// Directory of the package's hooks, and of the service's compiled hooks, which holds periodic
+/// hooks.
+pub const PERIODIC_HOOKS_DIR: &'static str = "periodic";
 static LOGKEY: &'static str = "HK";
 
 pub fn stdout_log_path<T>(service_group: &ServiceGroup) -> PathBuf
@@ -53,6 +65,80 @@ where
     fs::svc_logs_path(service_group.service()).join(format!("{}.stderr.log", T::file_name()))
 }
 
+/// Paths to the standard output and standard error logs of the hook with the given file name,
+/// such as `init` or `health_check`, or `None` if there is no such hook. The logs of the `run`
+/// hook hold the output of the service itself. Periodic hooks differ from service to service, so
+/// their logs aren't found here but through the service's `HookTable`.
+pub fn log_paths(service_group: &ServiceGroup, file_name: &str) -> Option<(PathBuf, PathBuf)> {
+    let hooks: [(&str, fn(&ServiceGroup) -> (PathBuf, PathBuf)); 11] =
+        [
+            (FileUpdatedHook::file_name(), log_paths_for::<FileUpdatedHook>),
+            (HealthCheckHook::file_name(), log_paths_for::<HealthCheckHook>),
+            (InitHook::file_name(), log_paths_for::<InitHook>),
+            (RunHook::file_name(), log_paths_for::<RunHook>),
+            (PostRunHook::file_name(), log_paths_for::<PostRunHook>),
+            (PreStopHook::file_name(), log_paths_for::<PreStopHook>),
+            (PostStopHook::file_name(), log_paths_for::<PostStopHook>),
+            (ReloadHook::file_name(), log_paths_for::<ReloadHook>),
+            (ReconfigureHook::file_name(), log_paths_for::<ReconfigureHook>),
+            (SmokeTestHook::file_name(), log_paths_for::<SmokeTestHook>),
+            (SuitabilityHook::file_name(), log_paths_for::<SuitabilityHook>),
+        ];
+    hooks
+        .iter()
+        .find(|&&(name, _)| name == file_name)
+        .map(|&(_, paths)| paths(service_group))
+}
+
+fn log_paths_for<T>(service_group: &ServiceGroup) -> (PathBuf, PathBuf)
+where
+    T: Hook,
+{
+    (
+        stdout_log_path::<T>(service_group),
+        stderr_log_path::<T>(service_group),
+    )
+}
+
+/// Exit code reported by hooks which were killed for running longer than their timeout, the
+/// same code `timeout(1)` exits with.
+pub const TIMED_OUT_EXIT_CODE: i32 = 124;
+
+/// The exit value of a hook which was killed for running longer than its timeout.
+pub trait TimedOut {
+    fn timed_out() -> Self;
+}
+
+impl TimedOut for bool {
+    fn timed_out() -> Self {
+        false
+    }
+}
+
+impl TimedOut for ExitCode {
+    fn timed_out() -> Self {
+        ExitCode(TIMED_OUT_EXIT_CODE)
+    }
+}
+
+impl TimedOut for health::HealthCheck {
+    fn timed_out() -> Self {
+        health::HealthCheck::Unknown
+    }
+}
+
+impl TimedOut for health::SmokeCheck {
+    fn timed_out() -> Self {
+        health::SmokeCheck::Failed(TIMED_OUT_EXIT_CODE)
+    }
+}
+
+impl TimedOut for Option<u64> {
+    fn timed_out() -> Self {
+        None
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct ExitCode(i32);
 
@@ -63,7 +149,7 @@ impl Default for ExitCode {
 }
 
 pub trait Hook: fmt::Debug + Sized {
-    type ExitValue: Default;
+    type ExitValue: Default + TimedOut;
 
     fn file_name() -> &'static str;
 
@@ -98,9 +184,14 @@ pub trait Hook: fmt::Debug + Sized {
 
     fn new(service_group: &ServiceGroup, render_pair: RenderPair) -> Self;
 
+    /// Render a hook without writing it to its destination service directory.
+    fn render(&self, ctx: &RenderContext) -> Result<String> {
+        self.renderer().render(Self::file_name(), ctx)
+    }
+
     /// Compile a hook into its destination service directory.
     fn compile(&self, service_group: &ServiceGroup, ctx: &RenderContext) -> Result<bool> {
-        let content = self.renderer().render(Self::file_name(), ctx)?;
+        let content = self.render(ctx)?;
         if write_hook(&content, self.path())? {
             outputln!(preamble service_group, "{}, compiled to {}", Self::file_name(),
                 self.path().display());
@@ -116,33 +207,54 @@ pub trait Hook: fmt::Debug + Sized {
         }
     }
 
-    /// Run a compiled hook.
+    /// Run a compiled hook, killing it along with any processes it started if it is still
+    /// running once the given timeout has elapsed. A hook which was killed reports its
+    /// `TimedOut` exit value. The logs of earlier runs are rotated according to `logs`.
     fn run<T>(
         &self,
         service_group: &ServiceGroup,
         pkg: &Pkg,
         svc_encrypted_password: Option<T>,
+        timeout: Option<Duration>,
+        logs: &RotationPolicy,
     ) -> Self::ExitValue
     where
         T: ToString,
     {
-        let mut child = match exec::run(self.path(), &pkg, svc_encrypted_password) {
-            Ok(child) => child,
-            Err(err) => {
-                outputln!(preamble service_group,
-                    "Hook failed to run, {}, {}", Self::file_name(), err);
-                return Self::ExitValue::default();
-            }
-        };
-        let mut hook_output = HookOutput::new(self.stdout_log_path(), self.stderr_log_path());
-        hook_output.stream_output::<Self>(service_group, &mut child);
-        match child.wait() {
-            Ok(status) => self.handle_exit(service_group, &hook_output, &status),
-            Err(err) => {
-                outputln!(preamble service_group,
-                    "Hook failed to run, {}, {}", Self::file_name(), err);
-                Self::ExitValue::default()
+        self.try_run(service_group, pkg, svc_encrypted_password, timeout, logs)
+            .unwrap_or_else(Self::ExitValue::timed_out)
+    }
+
+    /// Run a compiled hook like `run`, returning `None` if it was killed for running longer
+    /// than the given timeout.
+    fn try_run<T>(
+        &self,
+        service_group: &ServiceGroup,
+        pkg: &Pkg,
+        svc_encrypted_password: Option<T>,
+        timeout: Option<Duration>,
+        logs: &RotationPolicy,
+    ) -> Option<Self::ExitValue>
+    where
+        T: ToString,
+    {
+        let mut hook_output =
+            HookOutput::new(self.stdout_log_path(), self.stderr_log_path(), *logs);
+        match execute(
+            Self::file_name(),
+            self.path(),
+            &mut hook_output,
+            service_group,
+            pkg,
+            svc_encrypted_password,
+            timeout,
+            None,
+        ) {
+            Execution::Exited(status) => {
+                Some(self.handle_exit(service_group, &hook_output, &status))
             }
+            Execution::TimedOut => None,
+            Execution::Failed => Some(Self::ExitValue::default()),
         }
     }
 
@@ -162,6 +274,76 @@ pub trait Hook: fmt::Debug + Sized {
     fn stderr_log_path(&self) -> &Path;
 }
 
+/// How a run of a hook ended.
+enum Execution {
+    Exited(ExitStatus),
+    TimedOut,
+    Failed,
+}
+
+/// Run the compiled hook at `path`, streaming its output to the Supervisor's output and to the
+/// hook's logs, and recording the run in the service's metrics. A hook still running once the
+/// timeout has elapsed is killed along with any processes it started. The PID of the hook is
+/// kept in `running_pid`, if given, for as long as it runs so it can be killed from elsewhere.
+fn execute<T>(
+    name: &str,
+    path: &Path,
+    hook_output: &mut HookOutput,
+    service_group: &ServiceGroup,
+    pkg: &Pkg,
+    svc_encrypted_password: Option<T>,
+    timeout: Option<Duration>,
+    running_pid: Option<&Mutex<Option<u32>>>,
+) -> Execution
+where
+    T: ToString,
+{
+    let started = Instant::now();
+    let mut child = match exec::run(path, &pkg, svc_encrypted_password) {
+        Ok(child) => child,
+        Err(err) => {
+            outputln!(preamble service_group, "Hook failed to run, {}, {}", name, err);
+            metrics::observe_hook(service_group, name, metrics::HOOK_ERROR, started);
+            return Execution::Failed;
+        }
+    };
+    if let Some(running_pid) = running_pid {
+        *running_pid.lock().expect("Running pid lock poisoned") = Some(child.id());
+    }
+    let watchdog = timeout.map(|timeout| exec::Watchdog::start(child.id(), timeout));
+    hook_output.stream_output(name, service_group, &mut child);
+    let (status, timed_out) = match watchdog {
+        Some(watchdog) => watchdog.wait(|| child.try_wait()),
+        None => (child.wait(), false),
+    };
+    if let Some(running_pid) = running_pid {
+        *running_pid.lock().expect("Running pid lock poisoned") = None;
+    }
+    if let Some(timeout) = timeout {
+        if timed_out {
+            hook_output.timed_out(name, service_group, timeout);
+            metrics::observe_hook(service_group, name, metrics::HOOK_TIMEOUT, started);
+            return Execution::TimedOut;
+        }
+    }
+    match status {
+        Ok(status) => {
+            let result = if status.success() {
+                metrics::HOOK_SUCCESS
+            } else {
+                metrics::HOOK_FAILURE
+            };
+            metrics::observe_hook(service_group, name, result, started);
+            Execution::Exited(status)
+        }
+        Err(err) => {
+            outputln!(preamble service_group, "Hook failed to run, {}, {}", name, err);
+            metrics::observe_hook(service_group, name, metrics::HOOK_ERROR, started);
+            Execution::Failed
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct FileUpdatedHook {
     render_pair: RenderPair,
@@ -355,7 +537,14 @@ impl Hook for RunHook {
         }
     }
 
-    fn run<T>(&self, _: &ServiceGroup, _: &Pkg, _: Option<T>) -> Self::ExitValue
+    fn run<T>(
+        &self,
+        _: &ServiceGroup,
+        _: &Pkg,
+        _: Option<T>,
+        _: Option<Duration>,
+        _: &RotationPolicy,
+    ) -> Self::ExitValue
     where
         T: ToString,
     {
@@ -453,6 +642,116 @@ impl Hook for PostRunHook {
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct PreStopHook {
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+}
+
+impl Hook for PreStopHook {
+    type ExitValue = ExitCode;
+
+    fn file_name() -> &'static str {
+        "pre-stop"
+    }
+
+    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+        PreStopHook {
+            render_pair: pair,
+            stdout_log_path: stdout_log_path::<Self>(service_group),
+            stderr_log_path: stderr_log_path::<Self>(service_group),
+        }
+    }
+
+    fn handle_exit<'a>(
+        &self,
+        service_group: &ServiceGroup,
+        _: &'a HookOutput,
+        status: &ExitStatus,
+    ) -> Self::ExitValue {
+        match status.code() {
+            Some(code) => ExitCode(code),
+            None => {
+                outputln!(preamble service_group,
+                    "{} exited without a status code", Self::file_name());
+                ExitCode::default()
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        &self.render_pair.path
+    }
+
+    fn renderer(&self) -> &TemplateRenderer {
+        &self.render_pair.renderer
+    }
+
+    fn stdout_log_path(&self) -> &Path {
+        &self.stdout_log_path
+    }
+
+    fn stderr_log_path(&self) -> &Path {
+        &self.stderr_log_path
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PostStopHook {
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+}
+
+impl Hook for PostStopHook {
+    type ExitValue = ExitCode;
+
+    fn file_name() -> &'static str {
+        "post-stop"
+    }
+
+    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+        PostStopHook {
+            render_pair: pair,
+            stdout_log_path: stdout_log_path::<Self>(service_group),
+            stderr_log_path: stderr_log_path::<Self>(service_group),
+        }
+    }
+
+    fn handle_exit<'a>(
+        &self,
+        service_group: &ServiceGroup,
+        _: &'a HookOutput,
+        status: &ExitStatus,
+    ) -> Self::ExitValue {
+        match status.code() {
+            Some(code) => ExitCode(code),
+            None => {
+                outputln!(preamble service_group,
+                    "{} exited without a status code", Self::file_name());
+                ExitCode::default()
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        &self.render_pair.path
+    }
+
+    fn renderer(&self) -> &TemplateRenderer {
+        &self.render_pair.renderer
+    }
+
+    fn stdout_log_path(&self) -> &Path {
+        &self.stdout_log_path
+    }
+
+    fn stderr_log_path(&self) -> &Path {
+        &self.stderr_log_path
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct ReloadHook {
     render_pair: RenderPair,
@@ -710,6 +1009,179 @@ impl Hook for SuitabilityHook {
     }
 }
 
+/// A hook from the package's `hooks/periodic` directory, which the Supervisor runs every
+/// `interval` seconds rather than at a point in the service's lifecycle. Periodic hooks run in a
+/// thread of their own, so that long-running tasks such as backups don't hold up the Supervisor.
+#[derive(Debug, Serialize)]
+pub struct PeriodicHook {
+    pub name: String,
+    pub interval: u64,
+    pub leader_only: bool,
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+    #[serde(skip_serializing)]
+    running: Arc<AtomicBool>,
+    #[serde(skip_serializing)]
+    running_pid: Arc<Mutex<Option<u32>>>,
+}
+
+impl PeriodicHook {
+    fn load<C, T>(
+        service_group: &ServiceGroup,
+        schedule: &HookSchedule,
+        concrete_path: C,
+        template_path: T,
+    ) -> Option<Self>
+    where
+        C: AsRef<Path>,
+        T: AsRef<Path>,
+    {
+        let concrete = concrete_path.as_ref().join(PERIODIC_HOOKS_DIR).join(&schedule.name);
+        let template = template_path.as_ref().join(PERIODIC_HOOKS_DIR).join(&schedule.name);
+        if std::fs::metadata(&template).is_err() {
+            outputln!(preamble service_group,
+                "Periodic hook {} not found at {}, not loading",
+                schedule.name, template.display());
+            return None;
+        }
+        let pair = match RenderPair::new(concrete, &template) {
+            Ok(pair) => pair,
+            Err(err) => {
+                outputln!(preamble service_group, "Failed to load hook: {}", err);
+                return None;
+            }
+        };
+        let hook_name = format!("{}-{}", PERIODIC_HOOKS_DIR, schedule.name);
+        let logs = fs::svc_logs_path(service_group.service());
+        Some(PeriodicHook {
+            name: schedule.name.clone(),
+            interval: schedule.interval,
+            leader_only: schedule.leader_only,
+            render_pair: pair,
+            stdout_log_path: logs.join(format!("{}.stdout.log", hook_name)),
+            stderr_log_path: logs.join(format!("{}.stderr.log", hook_name)),
+            running: Arc::new(AtomicBool::new(false)),
+            running_pid: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// The name the hook is known by in the Supervisor's output, its logs and metrics, such as
+    /// `periodic-backup`.
+    pub fn hook_name(&self) -> String {
+        format!("{}-{}", PERIODIC_HOOKS_DIR, self.name)
+    }
+
+    /// Render the hook without writing it to its destination service directory.
+    pub fn render(&self, ctx: &RenderContext) -> Result<String> {
+        self.render_pair.renderer.render(&self.name, ctx)
+    }
+
+    /// Compile the hook into the `periodic` directory of the service's hooks.
+    pub fn compile(&self, service_group: &ServiceGroup, ctx: &RenderContext) -> Result<bool> {
+        let content = self.render(ctx)?;
+        if let Some(dir) = self.render_pair.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        if write_hook(&content, &self.render_pair.path)? {
+            outputln!(preamble service_group, "{}, compiled to {}", self.hook_name(),
+                self.render_pair.path.display());
+            hcore::util::perm::set_permissions(&self.render_pair.path, HOOK_PERMISSIONS)?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Run the compiled hook in the background, killing it if it is still running once the
+    /// given timeout has elapsed. Returns false, without running it, if the previous run has not
+    /// finished yet or its thread couldn't be started.
+    pub fn spawn(
+        &self,
+        service_group: &ServiceGroup,
+        pkg: &Pkg,
+        svc_encrypted_password: Option<String>,
+        timeout: Option<Duration>,
+        logs: RotationPolicy,
+    ) -> bool {
+        if self.running.swap(true, Ordering::SeqCst) {
+            return false;
+        }
+        let name = self.hook_name();
+        let path = self.render_pair.path.clone();
+        let stdout_log_path = self.stdout_log_path.clone();
+        let stderr_log_path = self.stderr_log_path.clone();
+        let service_group = service_group.clone();
+        let pkg = pkg.clone();
+        let running = RunningGuard(self.running.clone());
+        let running_pid = self.running_pid.clone();
+        let result = thread::Builder::new()
+            .name(format!("{}-{}", service_group, name))
+            .spawn(move || {
+                let _running = running;
+                let mut hook_output = HookOutput::new(&stdout_log_path, &stderr_log_path, logs);
+                let execution = execute(
+                    &name,
+                    &path,
+                    &mut hook_output,
+                    &service_group,
+                    &pkg,
+                    svc_encrypted_password,
+                    timeout,
+                    Some(&running_pid),
+                );
+                if let Execution::Exited(status) = execution {
+                    if !status.success() {
+                        outputln!(preamble service_group,
+                            "{} failed with exit code {}",
+                            name,
+                            status.code().map_or("none".to_string(), |c| c.to_string()));
+                    }
+                }
+            });
+        if let Err(err) = result {
+            outputln!(preamble service_group,
+                "Unable to start periodic hook {}, {}", self.hook_name(), err);
+            self.running.store(false, Ordering::SeqCst);
+            return false;
+        }
+        true
+    }
+
+    /// Whether a run of the hook is still going.
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::SeqCst)
+    }
+
+        /// Kill the run in flight, if there is one, along with any processes it started.
+    pub fn kill(&self, service_group: &ServiceGroup) {
+        let running_pid = self.running_pid.lock().expect("Running pid lock poisoned");
+        if let Some(pid) = *running_pid {
+            outputln!(preamble service_group, "Stopping {}, pid {}", self.hook_name(), pid);
+            if let Err(err) = exec::kill_tree(pid) {
+                outputln!(preamble service_group,
+                    "Unable to stop {}, pid {}, {}", self.hook_name(), pid, err);
+            }
+        }
+    }
+}
+
+/// Marks a periodic hook's run as finished when dropped, so a run whose thread panics doesn't
+/// keep the hook from running again.
+struct RunningGuard(Arc<AtomicBool>);
+
+impl Drop for RunningGuard {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
+impl Periodic for PeriodicHook {
+    fn update_period(&self) -> i64 {
+        self.interval as i64 * 1000
+    }
+}
+
 /// Cryptographically hash the contents of the compiled hook
 /// file.
 ///
@@ -751,12 +1223,21 @@ pub struct HookTable {
     pub suitability: Option<SuitabilityHook>,
     pub run: Option<RunHook>,
     pub post_run: Option<PostRunHook>,
+    pub pre_stop: Option<PreStopHook>,
+    pub post_stop: Option<PostStopHook>,
     pub smoke_test: Option<SmokeTestHook>,
+    pub periodic: Vec<PeriodicHook>,
 }
 
 impl HookTable {
-    /// Read all available hook templates from the table's package directory into the table.
-    pub fn load<P, T>(service_group: &ServiceGroup, templates: T, hooks_path: P) -> Self
+    /// Read all available hook templates from the table's package directory into the table,
+    /// including the periodic hooks of the given schedules.
+    pub fn load<P, T>(
+        service_group: &ServiceGroup,
+        templates: T,
+        hooks_path: P,
+        schedules: &[HookSchedule],
+    ) -> Self
     where
         P: AsRef<Path>,
         T: AsRef<Path>,
@@ -772,7 +1253,15 @@ impl HookTable {
                 table.reconfigure = ReconfigureHook::load(service_group, &hooks_path, &templates);
                 table.run = RunHook::load(service_group, &hooks_path, &templates);
                 table.post_run = PostRunHook::load(service_group, &hooks_path, &templates);
+                table.pre_stop = PreStopHook::load(service_group, &hooks_path, &templates);
+                table.post_stop = PostStopHook::load(service_group, &hooks_path, &templates);
                 table.smoke_test = SmokeTestHook::load(service_group, &hooks_path, &templates);
+                table.periodic = schedules
+                    .iter()
+                    .filter_map(|schedule| {
+                        PeriodicHook::load(service_group, schedule, &hooks_path, &templates)
+                    })
+                    .collect();
             }
         }
         debug!(
@@ -815,13 +1304,67 @@ impl HookTable {
         if let Some(ref hook) = self.post_run {
             changed = self.compile_one(hook, service_group, ctx) || changed;
         }
+        if let Some(ref hook) = self.pre_stop {
+            changed = self.compile_one(hook, service_group, ctx) || changed;
+        }
+        if let Some(ref hook) = self.post_stop {
+            changed = self.compile_one(hook, service_group, ctx) || changed;
+        }
         if let Some(ref hook) = self.smoke_test {
             changed = self.compile_one(hook, service_group, ctx) || changed;
         }
+        for hook in self.periodic.iter() {
+            match hook.compile(service_group, ctx) {
+                Ok(status) => changed = status || changed,
+                Err(e) => {
+                    outputln!(preamble service_group,
+                              "Failed to compile {} hook: {}", hook.hook_name(), e);
+                }
+            }
+        }
         outputln!(preamble service_group, "Hooks compiled");
         changed
     }
 
+    /// Render all loaded hooks from the table without writing them to disk, returning the
+    /// content of each hook keyed by its path within the hooks directory.
+    pub fn render(&self, ctx: &RenderContext) -> Result<BTreeMap<String, String>> {
+        let mut rendered = BTreeMap::new();
+        self.render_one(&self.file_updated, ctx, &mut rendered)?;
+        self.render_one(&self.health_check, ctx, &mut rendered)?;
+        self.render_one(&self.init, ctx, &mut rendered)?;
+        self.render_one(&self.reload, ctx, &mut rendered)?;
+        self.render_one(&self.reconfigure, ctx, &mut rendered)?;
+        self.render_one(&self.suitability, ctx, &mut rendered)?;
+        self.render_one(&self.run, ctx, &mut rendered)?;
+        self.render_one(&self.post_run, ctx, &mut rendered)?;
+        self.render_one(&self.pre_stop, ctx, &mut rendered)?;
+        self.render_one(&self.post_stop, ctx, &mut rendered)?;
+        self.render_one(&self.smoke_test, ctx, &mut rendered)?;
+        for hook in self.periodic.iter() {
+            rendered.insert(
+                format!("{}/{}", PERIODIC_HOOKS_DIR, hook.name),
+                hook.render(ctx)?,
+            );
+        }
+        Ok(rendered)
+    }
+
+    fn render_one<H>(
+        &self,
+        hook: &Option<H>,
+        ctx: &RenderContext,
+        rendered: &mut BTreeMap<String, String>,
+    ) -> Result<()>
+    where
+        H: Hook,
+    {
+        if let Some(ref hook) = *hook {
+            rendered.insert(H::file_name().to_string(), hook.render(ctx)?);
+        }
+        Ok(())
+    }
+
     fn compile_one<H>(&self, hook: &H, service_group: &ServiceGroup, ctx: &RenderContext) -> bool
     where
         H: Hook,
@@ -884,13 +1427,15 @@ impl Serialize for RenderPair {
 pub struct HookOutput<'a> {
     stdout_log_file: &'a Path,
     stderr_log_file: &'a Path,
+    logs: RotationPolicy,
 }
 
 impl<'a> HookOutput<'a> {
-    fn new(stdout_log: &'a Path, stderr_log: &'a Path) -> Self {
+    pub fn new(stdout_log: &'a Path, stderr_log: &'a Path, logs: RotationPolicy) -> Self {
         HookOutput {
             stdout_log_file: stdout_log,
             stderr_log_file: stderr_log,
+            logs: logs,
         }
     }
 
@@ -909,18 +1454,19 @@ impl<'a> HookOutput<'a> {
         }
     }
 
-    fn stream_output<H: Hook>(&mut self, service_group: &ServiceGroup, process: &mut Child) {
-        let mut stdout_log =
-            File::create(&self.stdout_log_file).expect("couldn't create log output file");
-        let mut stderr_log =
-            File::create(&self.stderr_log_file).expect("couldn't create log output file");
+    /// Print the hook's output and log it afresh, rotating away the logs of earlier runs. The
+    /// logs are also rotated if this run alone outgrows them.
+    fn stream_output(&mut self, name: &str, service_group: &ServiceGroup, process: &mut Child) {
+        let mut stdout_log = RotatingLog::create(self.stdout_log_file, self.logs)
+            .expect("couldn't create log output file");
+        let mut stderr_log = RotatingLog::create(self.stderr_log_file, self.logs)
+            .expect("couldn't create log output file");
 
-        let preamble_str = self.stream_preamble::<H>(service_group);
         if let Some(ref mut stdout) = process.stdout {
             for line in BufReader::new(stdout).lines() {
                 if let Some(ref l) = line.ok() {
-                    outputln!(preamble preamble_str, l);
-                    stdout_log.write_fmt(format_args!("{}\n", l)).expect(
+                    self.output_line(name, service_group, l, Level::Info);
+                    stdout_log.write_all(format!("{}\n", l).as_bytes()).expect(
                         "couldn't write line",
                     );
                 }
@@ -929,8 +1475,8 @@ impl<'a> HookOutput<'a> {
         if let Some(ref mut stderr) = process.stderr {
             for line in BufReader::new(stderr).lines() {
                 if let Some(ref l) = line.ok() {
-                    outputln!(preamble preamble_str, l);
-                    stderr_log.write_fmt(format_args!("{}\n", l)).expect(
+                    self.output_line(name, service_group, l, Level::Error);
+                    stderr_log.write_all(format!("{}\n", l).as_bytes()).expect(
                         "couldn't write line",
                     );
                 }
@@ -938,8 +1484,66 @@ impl<'a> HookOutput<'a> {
         }
     }
 
-    fn stream_preamble<H: Hook>(&self, service_group: &ServiceGroup) -> String {
-        format!("{} hook[{}]:", service_group, H::file_name())
+    /// Print output which was captured whole, such as a health probe's, and log it afresh just
+    /// like `stream_output` does.
+    pub fn write_output(
+        &mut self,
+        name: &str,
+        service_group: &ServiceGroup,
+        stdout: &str,
+        stderr: &str,
+    ) {
+        let outputs = [
+            (self.stdout_log_file, stdout, Level::Info),
+            (self.stderr_log_file, stderr, Level::Error),
+        ];
+        for &(path, output, level) in outputs.iter() {
+            let mut log = match RotatingLog::create(path, self.logs) {
+                Ok(log) => log,
+                Err(err) => {
+                    warn!("Unable to create log {}, {}", path.display(), err);
+                    continue;
+                }
+            };
+            for line in output.lines() {
+                self.output_line(name, service_group, line, level);
+                if let Err(err) = log.write_all(format!("{}\n", line).as_bytes()) {
+                    warn!("Unable to write log {}, {}", path.display(), err);
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Record that the hook was killed for running longer than its timeout, both in the
+    /// Supervisor's output and at the end of the hook's standard error log.
+    fn timed_out(&self, name: &str, service_group: &ServiceGroup, timeout: Duration) {
+        let msg = format!(
+            "Timed out after {}s, killed with exit code {}",
+            timeout.as_secs(),
+            TIMED_OUT_EXIT_CODE
+        );
+        self.output_line(name, service_group, &msg, Level::Error);
+        match OpenOptions::new().append(true).create(true).open(
+            &self.stderr_log_file,
+        ) {
+            Ok(mut stderr_log) => {
+                if let Err(err) = stderr_log.write_fmt(format_args!("{}\n", msg)) {
+                    warn!("Unable to log hook timeout, {}", err);
+                }
+            }
+            Err(err) => warn!("Unable to log hook timeout, {}", err),
+        }
+    }
+
+    /// Print a line of the hook's output, attributed to the hook within the service group.
+    fn output_line(&self, name: &str, service_group: &ServiceGroup, line: &str, level: Level) {
+        let preamble = service_group.to_string();
+        let source = format!("hook[{}]", name);
+        let mut so = StructuredOutput::new(&preamble, LOGKEY, line!(), file!(), column!(), line);
+        so.source = Some(&source);
+        so.level = level;
+        println!("{}", so);
     }
 }
 
@@ -990,6 +1594,8 @@ mod tests {
                       HealthCheckHook
                       InitHook
                       PostRunHook
+                      PostStopHook
+                      PreStopHook
                       ReconfigureHook
                       ReloadHook
                       RunHook
@@ -1375,7 +1981,20 @@ echo "The message is Hello"
         // END RENDER CONTEXT SETUP
         ////////////////////////////////////////////////////////////////////////
 
-        let hook_table = HookTable::load(&service_group, &template_path, &hooks_path);
+        let schedules = vec![
+            HookSchedule {
+                name: "backup".to_string(),
+                interval: 3600,
+                leader_only: true,
+            },
+            HookSchedule {
+                name: "missing".to_string(),
+                interval: 60,
+                leader_only: false,
+            },
+        ];
+        let hook_table =
+            HookTable::load(&service_group, &template_path, &hooks_path, &schedules);
         assert_eq!(hook_table.compile(&service_group, &ctx), true);
 
         // Verify init hook
@@ -1391,6 +2010,27 @@ echo "The message is Hello"
             "#!/bin/bash\n\necho \"Running a program\"\n"
         );
 
+        // Verify pre-stop hook
+        let pre_stop_hook_content =
+            file_content(&hook_table.pre_stop.as_ref().expect("no pre-stop hook??"));
+        assert_eq!(
+            pre_stop_hook_content,
+            "#!/bin/bash\n\necho \"Draining before stopping, Hello\"\n"
+        );
+        assert!(hook_table.post_stop.is_none());
+
+        // Verify periodic hook, skipping the one without a template
+        assert_eq!(hook_table.periodic.len(), 1);
+        let periodic = &hook_table.periodic[0];
+        assert_eq!(periodic.hook_name(), "periodic-backup");
+        assert_eq!(periodic.update_period(), 3_600_000);
+        assert!(periodic.leader_only);
+        assert_eq!(
+            file_content(&hooks_path.join("periodic").join("backup")),
+            "#!/bin/bash\n\necho \"Backing up Hello\"\n"
+        );
+        assert!(hook_table.render(&ctx).unwrap().contains_key("periodic/backup"));
+
         // Recompiling again results in no changes
         assert_eq!(hook_table.compile(&service_group, &ctx), false);
 
@@ -1430,12 +2070,12 @@ echo "The message is Hello"
             "{}.stderr.log",
             InitHook::file_name()
         ));
-        let mut hook_output = HookOutput::new(&stdout_log, &stderr_log);
+        let mut hook_output = HookOutput::new(&stdout_log, &stderr_log, RotationPolicy::default());
         let service_group = ServiceGroup::new(None, "dummy", "service", None).expect(
             "couldn't create ServiceGroup",
         );
 
-        hook_output.stream_output::<InitHook>(&service_group, &mut child);
+        hook_output.stream_output(InitHook::file_name(), &service_group, &mut child);
 
         let mut stdout = String::new();
         hook_output
@@ -0,0 +1,286 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prometheus metrics of the Supervisor's services, labeled by service group and gathered by
+//! the HTTP gateway's `/metrics` route along with everything else in the default registry.
+
+use std::time::{Duration, Instant};
+
+use hcore::service::ServiceGroup;
+use prometheus::{CounterVec, GaugeVec, HistogramVec};
+
+use census::ElectionStatus;
+use super::health::HealthCheck;
+use super::supervisor::ProcessState;
+
+/// Hooks whose executions are measured, by file name.
+const HOOKS: &'static [&'static str] = &[
+    "file_updated",
+    "health_check",
+    "init",
+    "run",
+    "post-run",
+    "pre-stop",
+    "post-stop",
+    "reload",
+    "reconfigure",
+    "smoke_test",
+    "suitability",
+];
+
+// Results of a hook execution: the hook exited zero, exited non-zero, was killed for running
+// past its timeout, or could not be run at all.
+pub const HOOK_SUCCESS: &'static str = "success";
+pub const HOOK_FAILURE: &'static str = "failure";
+pub const HOOK_TIMEOUT: &'static str = "timeout";
+pub const HOOK_ERROR: &'static str = "error";
+const HOOK_RESULTS: &'static [&'static str] =
+    &[HOOK_SUCCESS, HOOK_FAILURE, HOOK_TIMEOUT, HOOK_ERROR];
+
+// Outcomes of a package update: the new package was put in place, it couldn't be loaded, it
+// passed its verification window, or it failed verification and was rolled back.
+pub const UPDATE_APPLIED: &'static str = "applied";
+pub const UPDATE_FAILED: &'static str = "failed";
+pub const UPDATE_VERIFIED: &'static str = "verified";
+pub const UPDATE_ROLLED_BACK: &'static str = "rolled_back";
+const UPDATE_OUTCOMES: &'static [&'static str] =
+    &[UPDATE_APPLIED, UPDATE_FAILED, UPDATE_VERIFIED, UPDATE_ROLLED_BACK];
+
+const PROCESS_STATES: &'static [ProcessState] = &[
+    ProcessState::Down,
+    ProcessState::Up,
+    ProcessState::Failed,
+    ProcessState::WaitingForBinds,
+];
+
+const ELECTION_STATUSES: &'static [ElectionStatus] = &[
+    ElectionStatus::None,
+    ElectionStatus::ElectionInProgress,
+    ElectionStatus::ElectionNoQuorum,
+    ElectionStatus::ElectionFinished,
+];
+
+lazy_static! {
+    static ref PROCESS_STATE: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_sup_service_process_state",
+            "Whether the service's process is in the given state (1) or not (0)."),
+        &["service_group", "state"]).unwrap();
+
+    static ref RESTARTS: CounterVec = register_counter_vec!(
+        opts!(
+            "hab_sup_service_restarts_total",
+            "Total number of restarts scheduled for the service's process."),
+        &["service_group"]).unwrap();
+
+    static ref HEALTH_STATUS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_sup_service_health_status",
+            "Current health of the service: 0 ok, 1 warning, 2 critical, 3 unknown."),
+        &["service_group"]).unwrap();
+
+    static ref HOOK_EXECUTIONS: CounterVec = register_counter_vec!(
+        opts!(
+            "hab_sup_hook_executions_total",
+            "Total number of hook executions by hook and result."),
+        &["service_group", "hook", "result"]).unwrap();
+
+    static ref HOOK_DURATION: HistogramVec = register_histogram_vec!(
+        histogram_opts!(
+            "hab_sup_hook_duration_seconds",
+            "Hook execution times in seconds."),
+        &["service_group", "hook"]).unwrap();
+
+    static ref UPDATE_ATTEMPTS: CounterVec = register_counter_vec!(
+        opts!(
+            "hab_sup_service_update_attempts_total",
+            "Total number of attempts to update the service to a new package."),
+        &["service_group"]).unwrap();
+
+    static ref UPDATE_OUTCOMES: CounterVec = register_counter_vec!(
+        opts!(
+            "hab_sup_service_update_outcomes_total",
+            "Total number of package updates by outcome."),
+        &["service_group", "outcome"]).unwrap();
+
+    static ref ELECTION_STATUS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_sup_service_election_status",
+            "Whether the service group's election is in the given status (1) or not (0)."),
+        &["service_group", "status"]).unwrap();
+
+    static ref LEADER: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_sup_service_leader",
+            "Whether this member is the leader of its service group (1) or not (0)."),
+        &["service_group"]).unwrap();
+
+    static ref CONFIG_AGE: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_sup_service_config_age_seconds",
+            "Seconds since the service's rendered configuration last changed."),
+        &["service_group"]).unwrap();
+}
+
+pub fn set_process_state(service_group: &ServiceGroup, state: &ProcessState) {
+    let group = service_group.to_string();
+    for s in PROCESS_STATES {
+        let value = if s == state { 1.0 } else { 0.0 };
+        PROCESS_STATE
+            .with_label_values(&[&group, &s.to_string()])
+            .set(value);
+    }
+}
+
+pub fn inc_restarts(service_group: &ServiceGroup) {
+    RESTARTS.with_label_values(&[&service_group.to_string()]).inc();
+}
+
+pub fn set_health_status(service_group: &ServiceGroup, health: HealthCheck) {
+    let value = match health {
+        HealthCheck::Ok => 0.0,
+        HealthCheck::Warning => 1.0,
+        HealthCheck::Critical => 2.0,
+        HealthCheck::Unknown => 3.0,
+    };
+    HEALTH_STATUS
+        .with_label_values(&[&service_group.to_string()])
+        .set(value);
+}
+
+/// Record one execution of the given hook which started at `started`.
+pub fn observe_hook(service_group: &ServiceGroup, hook: &str, result: &str, started: Instant) {
+    let group = service_group.to_string();
+    HOOK_EXECUTIONS
+        .with_label_values(&[&group, hook, result])
+        .inc();
+    HOOK_DURATION.with_label_values(&[&group, hook]).observe(
+        seconds(started.elapsed()),
+    );
+}
+
+pub fn inc_update_attempts(service_group: &ServiceGroup) {
+    UPDATE_ATTEMPTS
+        .with_label_values(&[&service_group.to_string()])
+        .inc();
+}
+
+pub fn inc_update_outcome(service_group: &ServiceGroup, outcome: &str) {
+    UPDATE_OUTCOMES
+        .with_label_values(&[&service_group.to_string(), outcome])
+        .inc();
+}
+
+pub fn set_election(service_group: &ServiceGroup, status: ElectionStatus, leader: bool) {
+    let group = service_group.to_string();
+    for s in ELECTION_STATUSES {
+        let value = if *s == status { 1.0 } else { 0.0 };
+        ELECTION_STATUS
+            .with_label_values(&[&group, &s.to_string()])
+            .set(value);
+    }
+    LEADER.with_label_values(&[&group]).set(
+        if leader { 1.0 } else { 0.0 },
+    );
+}
+
+pub fn set_config_changed(service_group: &ServiceGroup, changed: Instant) {
+    CONFIG_AGE
+        .with_label_values(&[&service_group.to_string()])
+        .set(seconds(changed.elapsed()));
+}
+
+/// Drop every metric of the given service group, including those of the named hooks beyond the
+/// lifecycle hooks, so an unloaded service stops being reported.
+pub fn remove(service_group: &ServiceGroup, hooks: &[String]) {
+    let group = service_group.to_string();
+    // Label values which were never recorded can't be removed, which is fine.
+    for s in PROCESS_STATES {
+        let _ = PROCESS_STATE.remove_label_values(&[&group, &s.to_string()]);
+    }
+    for s in ELECTION_STATUSES {
+        let _ = ELECTION_STATUS.remove_label_values(&[&group, &s.to_string()]);
+    }
+    for hook in HOOKS.iter().map(|h| h.to_string()).chain(hooks.iter().cloned()) {
+        for result in HOOK_RESULTS {
+            let _ = HOOK_EXECUTIONS.remove_label_values(&[&group, &hook, result]);
+        }
+        let _ = HOOK_DURATION.remove_label_values(&[&group, &hook]);
+    }
+    for outcome in UPDATE_OUTCOMES {
+        let _ = UPDATE_OUTCOMES.remove_label_values(&[&group, outcome]);
+    }
+    let _ = RESTARTS.remove_label_values(&[&group]);
+    let _ = HEALTH_STATUS.remove_label_values(&[&group]);
+    let _ = UPDATE_ATTEMPTS.remove_label_values(&[&group]);
+    let _ = LEADER.remove_label_values(&[&group]);
+    let _ = CONFIG_AGE.remove_label_values(&[&group]);
+}
+
+fn seconds(duration: Duration) -> f64 {
+    duration.as_secs() as f64 + duration.subsec_nanos() as f64 / 1_000_000_000.0
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+    use std::time::Instant;
+
+    use prometheus::{self, Encoder, TextEncoder};
+
+    use super::*;
+
+    fn gathered() -> String {
+        let mut buffer = vec![];
+        TextEncoder::new()
+            .encode(&prometheus::gather(), &mut buffer)
+            .unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn service_metrics_are_labeled_by_service_group() {
+        let sg = ServiceGroup::from_str("metrics-labeled.default").unwrap();
+        set_process_state(&sg, &ProcessState::Up);
+        set_health_status(&sg, HealthCheck::Critical);
+        observe_hook(&sg, "init", HOOK_SUCCESS, Instant::now());
+        let output = gathered();
+        assert!(output.contains(
+            "hab_sup_service_process_state{service_group=\"metrics-labeled.default\",\
+             state=\"up\"} 1",
+        ));
+        assert!(output.contains(
+            "hab_sup_service_process_state{service_group=\"metrics-labeled.default\",\
+             state=\"down\"} 0",
+        ));
+        assert!(output.contains(
+            "hab_sup_service_health_status{service_group=\"metrics-labeled.default\"} 2",
+        ));
+        assert!(output.contains(
+            "hab_sup_hook_executions_total{hook=\"init\",result=\"success\",\
+             service_group=\"metrics-labeled.default\"} 1",
+        ));
+    }
+
+    #[test]
+    fn remove_drops_service_metrics() {
+        let sg = ServiceGroup::from_str("metrics-removed.default").unwrap();
+        set_process_state(&sg, &ProcessState::Down);
+        inc_restarts(&sg);
+        inc_update_outcome(&sg, UPDATE_ROLLED_BACK);
+        assert!(gathered().contains("metrics-removed.default"));
+        remove(&sg, &[]);
+        assert!(!gathered().contains("metrics-removed.default"));
+    }
+}
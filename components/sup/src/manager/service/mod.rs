@@ -16,11 +16,16 @@ pub mod hooks;
 mod composite_spec;
 mod config;
 mod health;
+mod metrics;
 mod package;
+mod probe;
+mod rollback;
 mod spec;
 mod supervisor;
+mod update_window;
 
 use std;
+use std::collections::BTreeMap;
 use std::fmt;
 use std::fs::File;
 use std::io::BufWriter;
@@ -33,20 +38,27 @@ use std::time::{Duration, Instant};
 
 use ansi_term::Colour::{Yellow, Red, Green};
 use butterfly::rumor::service::Service as ServiceRumor;
+use hcore;
 use hcore::crypto::hash;
 use hcore::fs::FS_ROOT_PATH;
-use hcore::package::{PackageIdent, PackageInstall};
+use hcore::package::{PackageIdent, PackageInstall, VersionReq};
 use hcore::service::ServiceGroup;
-use hcore::util::deserialize_using_from_str;
+use hcore::util::{deserialize_using_from_str, serialize_using_to_string};
 use hcore::util::perm::{set_owner, set_permissions};
-use launcher_client::LauncherCli;
+use hcore::util::rotating_log::RotationPolicy;
+use launcher_client::{LauncherCli, ResourceLimits};
 use serde;
-use time::Timespec;
+use time::{self, SteadyTime, Timespec};
 
 use super::Sys;
-use self::config::CfgRenderer;
-use self::hooks::{HOOK_PERMISSIONS, Hook, HookTable};
-use self::supervisor::Supervisor;
+use super::periodic::Periodic;
+use self::health::GossipedHealth;
+use self::hooks::{HOOK_PERMISSIONS, FileUpdatedHook, Hook, HookTable, InitHook, PeriodicHook,
+                  PostRunHook, PostStopHook, PreStopHook, ReconfigureHook, ReloadHook,
+                  SuitabilityHook};
+use self::rollback::{BlockedReleases, UpdateVerification, VERIFY_CRITICAL_CHECKS,
+                     VERIFY_MAX_RESTARTS};
+use self::supervisor::{restart_delay, RestartHistory, Supervisor};
 use error::{Error, Result, SupError};
 use fs;
 use manager;
@@ -54,39 +66,70 @@ use census::{ServiceFile, CensusRing, ElectionStatus};
 use templating::RenderContext;
 use util;
 
-pub use self::config::Cfg;
+pub use self::config::{Cfg, CfgRenderer, RenderCfg};
 pub use self::health::{HealthCheck, SmokeCheck};
 pub use self::package::Pkg;
+pub use self::probe::HealthProbe;
+pub use self::rollback::Rollback;
 pub use self::composite_spec::CompositeSpec;
-pub use self::spec::{DesiredState, ServiceBind, ServiceSpec, StartStyle};
-pub use self::supervisor::ProcessState;
+pub use self::spec::{CanarySize, DesiredState, RestartPolicy, RunMode, ServiceBind, ServiceSpec,
+                     StartStyle};
+pub use self::supervisor::{ProcessState, RunResult};
+pub use self::update_window::UpdateWindow;
 
 static LOGKEY: &'static str = "SR";
 
-lazy_static! {
-    static ref HEALTH_CHECK_INTERVAL: Duration = {
-        Duration::from_millis(30_000)
-    };
-}
-
 #[derive(Debug, Serialize)]
 pub struct Service {
     pub service_group: ServiceGroup,
     pub bldr_url: String,
     pub channel: String,
+    pub version_req: Option<VersionReq>,
     pub spec_file: PathBuf,
     pub spec_ident: PackageIdent,
     pub start_style: StartStyle,
     pub topology: Topology,
     pub update_strategy: UpdateStrategy,
+    #[serde(serialize_with = "serialize_using_to_string")]
+    pub canary_size: CanarySize,
+    pub canary_soak: u64,
+    pub update_verify_window: Option<u64>,
+    pub update_window: Option<UpdateWindow>,
+    pub health_check_interval: u64,
+    pub health_check_timeout: Option<u64>,
+    pub health_check_timeout_status: HealthCheck,
+    #[serde(serialize_with = "serialize_using_to_string")]
+    pub run_mode: RunMode,
+    pub restart_policy: RestartPolicy,
+    pub max_restarts: Option<u32>,
+    pub restart_window: u64,
+    pub restart_backoff: u64,
+    pub restart_backoff_max: u64,
+    pub memory_limit: Option<u64>,
+    pub cpu_limit: Option<u32>,
+    pub pids_limit: Option<u64>,
+    pub log_max_size: u64,
+    pub log_max_age: Option<u64>,
+    pub log_keep: u32,
+    pub wait_for_binds: bool,
+    pub bind_wait_timeout: Option<u64>,
+    pub hook_timeouts: BTreeMap<String, u64>,
+    pub health_probe: Option<HealthProbe>,
     pub cfg: Cfg,
     pub pkg: Pkg,
     pub sys: Arc<Sys>,
     pub initialized: bool,
+    pub blocked_releases: BlockedReleases,
+    pub last_rollback: Option<Rollback>,
+    pub last_run: Option<RunResult>,
+    #[serde(serialize_with = "serialize_pending_update")]
+    pending_update: Option<PackageInstall>,
 
     #[serde(skip_serializing)]
     config_renderer: CfgRenderer,
     health_check: HealthCheck,
+    #[serde(skip_serializing)]
+    gossiped_health: GossipedHealth,
     last_election_status: ElectionStatus,
     needs_reload: bool,
     needs_reconfiguration: bool,
@@ -95,7 +138,19 @@ pub struct Service {
     hooks: HookTable,
     config_from: Option<PathBuf>,
     #[serde(skip_serializing)]
-    last_health_check: Instant,
+    last_health_check: Option<Instant>,
+    #[serde(skip_serializing)]
+    restarts: RestartHistory,
+    #[serde(skip_serializing)]
+    restart_at: Option<Instant>,
+    #[serde(skip_serializing)]
+    bind_wait_started: Option<Instant>,
+    #[serde(skip_serializing)]
+    verification: Option<UpdateVerification>,
+    #[serde(skip_serializing)]
+    last_config_change: Option<Instant>,
+    #[serde(skip_serializing)]
+    periodic_due: BTreeMap<String, SteadyTime>,
     manager_fs_cfg: Arc<manager::FsCfg>,
     #[serde(rename = "process")]
     supervisor: Supervisor,
@@ -122,17 +177,22 @@ impl Service {
         )?;
         let config_root = Self::config_root(&pkg, spec.config_from.as_ref());
         let hooks_root = Self::hooks_root(&pkg, spec.config_from.as_ref());
+        let blocked_releases =
+            BlockedReleases::load(manager_fs_cfg.blocked_releases(&service_group));
         Ok(Service {
             sys: sys,
             cfg: Cfg::new(&pkg, spec.config_from.as_ref())?,
             config_renderer: CfgRenderer::new(&config_root)?,
             bldr_url: spec.bldr_url,
             channel: spec.channel,
+            version_req: spec.version_req,
             health_check: HealthCheck::default(),
+            gossiped_health: GossipedHealth::default(),
             hooks: HookTable::load(
                 &service_group,
                 &hooks_root,
                 fs::svc_hooks_path(&service_group.service()),
+                &pkg.periodic_hooks,
             ),
             initialized: false,
             last_election_status: ElectionStatus::None,
@@ -149,8 +209,41 @@ impl Service {
             start_style: spec.start_style,
             topology: spec.topology,
             update_strategy: spec.update_strategy,
+            canary_size: spec.canary_size,
+            canary_soak: spec.canary_soak,
+            update_verify_window: spec.update_verify_window,
+            update_window: spec.update_window,
+            pending_update: None,
+            blocked_releases: blocked_releases,
+            last_rollback: None,
+            last_run: None,
+            verification: None,
+            last_config_change: None,
+            periodic_due: BTreeMap::new(),
             config_from: spec.config_from,
-            last_health_check: Instant::now() - *HEALTH_CHECK_INTERVAL,
+            last_health_check: None,
+            health_check_interval: spec.health_check_interval,
+            health_check_timeout: spec.health_check_timeout,
+            health_check_timeout_status: spec.health_check_timeout_status,
+            run_mode: spec.run_mode,
+            restart_policy: spec.restart_policy,
+            max_restarts: spec.max_restarts,
+            restart_window: spec.restart_window,
+            restart_backoff: spec.restart_backoff,
+            restart_backoff_max: spec.restart_backoff_max,
+            memory_limit: spec.memory_limit,
+            cpu_limit: spec.cpu_limit,
+            pids_limit: spec.pids_limit,
+            log_max_size: spec.log_max_size,
+            log_max_age: spec.log_max_age,
+            log_keep: spec.log_keep,
+            wait_for_binds: spec.wait_for_binds,
+            bind_wait_timeout: spec.bind_wait_timeout,
+            bind_wait_started: None,
+            restarts: RestartHistory::default(),
+            restart_at: None,
+            hook_timeouts: spec.hook_timeouts,
+            health_probe: spec.health_probe,
             svc_encrypted_password: spec.svc_encrypted_password,
             composite: spec.composite,
         })
@@ -178,9 +271,23 @@ impl Service {
         manager_fs_cfg: Arc<manager::FsCfg>,
         organization: Option<&str>,
     ) -> Result<Service> {
-        // The package for a spec should already be installed.
+        // The package for a spec should already be installed, in a release which satisfies the
+        // spec's version requirement if it has one.
         let fs_root_path = Path::new(&*FS_ROOT_PATH);
-        let package = PackageInstall::load(&spec.ident, Some(fs_root_path))?;
+        let package = match spec.version_req {
+            Some(ref version_req) => {
+                match PackageInstall::load_matching(&spec.ident, version_req, Some(fs_root_path)) {
+                    Ok(package) => package,
+                    Err(hcore::Error::PackageNotFound(_)) => {
+                        return Err(sup_error!(
+                            Error::NoInstalledRelease(spec.ident.clone(), version_req.clone())
+                        ))
+                    }
+                    Err(err) => return Err(err.into()),
+                }
+            }
+            None => PackageInstall::load(&spec.ident, Some(fs_root_path))?,
+        };
         Ok(Self::new(sys, package, spec, manager_fs_cfg, organization)?)
     }
 
@@ -242,10 +349,13 @@ impl Service {
                 &self.service_group,
                 launcher,
                 self.svc_encrypted_password.as_ref(),
+                &self.log_rotation(),
+                &self.resource_limits(),
             )
             .err()
         {
             outputln!(preamble self.service_group, "Service start failed: {}", err);
+            self.schedule_restart(None);
         } else {
             self.needs_reload = false;
             self.needs_reconfiguration = false;
@@ -253,31 +363,48 @@ impl Service {
     }
 
     pub fn stop(&mut self, launcher: &LauncherCli) {
-        if let Err(err) = self.supervisor.stop(launcher) {
+        if let Err(err) = self.stop_process(launcher) {
             outputln!(preamble self.service_group, "Service stop failed: {}", err);
         }
     }
 
+    /// Stop the service's process, running the pre-stop hook before the process is signaled and
+    /// the post-stop hook once it has exited. The hooks only run for a process which is up.
+    /// Periodic hooks still running are killed, as they only run alongside the process.
+    fn stop_process(&mut self, launcher: &LauncherCli) -> Result<()> {
+        for hook in self.hooks.periodic.iter() {
+            hook.kill(&self.service_group);
+        }
+        let up = self.supervisor.state == ProcessState::Up;
+        if up {
+            self.pre_stop();
+        }
+        self.supervisor.stop(launcher)?;
+        if up {
+            self.post_stop();
+        }
+        Ok(())
+    }
+
+    /// Run the reload hook, or restart the process if there is none. A restart goes through
+    /// `stop_process` so the stop hooks see the old process off.
     fn reload(&mut self, launcher: &LauncherCli) {
         self.needs_reload = false;
         if self.process_down() || self.hooks.reload.is_none() {
-            if let Some(err) = self.supervisor
-                .restart(
-                    &self.pkg,
-                    &self.service_group,
-                    launcher,
-                    self.svc_encrypted_password.as_ref(),
-                )
-                .err()
-            {
+            if let Err(err) = self.stop_process(launcher) {
                 outputln!(preamble self.service_group, "Service restart failed: {}", err);
+                self.schedule_restart(None);
+                return;
             }
+            self.start(launcher);
         } else {
             let hook = self.hooks.reload.as_ref().unwrap();
             hook.run(
                 &self.service_group,
                 &self.pkg,
                 self.svc_encrypted_password.as_ref(),
+                self.hook_timeout::<ReloadHook>(),
+                &self.log_rotation(),
             );
         }
     }
@@ -286,14 +413,55 @@ impl Service {
         self.supervisor.state_entered
     }
 
+    pub fn process_state(&self) -> &ProcessState {
+        &self.supervisor.state
+    }
+
+    pub fn health_check(&self) -> HealthCheck {
+        self.health_check
+    }
+
+    /// Whether the given release failed verification after an earlier update to it.
+    pub fn is_blocked(&self, ident: &PackageIdent) -> bool {
+        self.blocked_releases.contains(ident)
+    }
+
+    /// Publish the service's current process state, election status and configuration age to
+    /// its Prometheus metrics.
+    pub fn update_metrics(&self, census_ring: &CensusRing) {
+        metrics::set_process_state(&self.service_group, &self.supervisor.state);
+        if let Some(census_group) = census_ring.census_group_for(&self.service_group) {
+            let leader = census_group.me().map_or(false, |me| me.leader);
+            metrics::set_election(&self.service_group, census_group.election_status, leader);
+        }
+        if let Some(changed) = self.last_config_change {
+            metrics::set_config_changed(&self.service_group, changed);
+        }
+    }
+
+    /// Stop reporting metrics for the service, once it is unloaded.
+    pub fn remove_metrics(&self) {
+        let periodic: Vec<String> = self.hooks.periodic.iter().map(|h| h.hook_name()).collect();
+        metrics::remove(&self.service_group, &periodic);
+    }
+
     pub fn tick(&mut self, census_ring: &CensusRing, launcher: &LauncherCli) -> bool {
-        if !self.initialized {
+        if !self.initialized || (self.wait_for_binds && self.restart_at.is_some()) {
+            if self.supervisor.state == ProcessState::Failed {
+                return false;
+            }
             if !self.all_binds_satisfied(census_ring) {
                 outputln!(preamble self.service_group, "Waiting for service binds...");
+                if self.wait_for_binds {
+                    self.hold_for_binds();
+                }
                 return false;
             }
+            self.bind_wait_started = None;
         }
 
+        let gossiped_health = self.gossiped_health.status();
+        let ident = self.pkg.ident.clone();
         let svc_updated = self.update_templates(census_ring);
         if self.update_service_files(census_ring) {
             self.file_updated();
@@ -348,7 +516,11 @@ impl Service {
                 }
             }
         }
-        svc_updated
+        self.run_periodic_hooks(census_ring);
+        // Members gossip their health and package, so a change in either after a health check or
+        // a rollback is published like any other update. Health is debounced by
+        // `GossipedHealth` so that a flapping service doesn't publish every check.
+        svc_updated || self.gossiped_health.status() != gossiped_health || self.pkg.ident != ident
     }
 
     pub fn to_spec(&self) -> ServiceSpec {
@@ -362,17 +534,68 @@ impl Service {
         }
         spec.bldr_url = self.bldr_url.clone();
         spec.channel = self.channel.clone();
+        spec.version_req = self.version_req.clone();
         spec.topology = self.topology;
         spec.update_strategy = self.update_strategy;
+        spec.canary_size = self.canary_size;
+        spec.canary_soak = self.canary_soak;
+        spec.update_verify_window = self.update_verify_window;
+        spec.update_window = self.update_window.clone();
         spec.binds = self.binds.clone();
         spec.start_style = self.start_style;
         spec.config_from = self.config_from.clone();
+        spec.health_check_interval = self.health_check_interval;
+        spec.health_check_timeout = self.health_check_timeout;
+        spec.health_check_timeout_status = self.health_check_timeout_status;
+        spec.run_mode = self.run_mode;
+        spec.restart_policy = self.restart_policy;
+        spec.max_restarts = self.max_restarts;
+        spec.restart_window = self.restart_window;
+        spec.restart_backoff = self.restart_backoff;
+        spec.restart_backoff_max = self.restart_backoff_max;
+        spec.memory_limit = self.memory_limit;
+        spec.cpu_limit = self.cpu_limit;
+        spec.pids_limit = self.pids_limit;
+        spec.log_max_size = self.log_max_size;
+        spec.log_max_age = self.log_max_age;
+        spec.log_keep = self.log_keep;
+        spec.wait_for_binds = self.wait_for_binds;
+        spec.bind_wait_timeout = self.bind_wait_timeout;
+        spec.hook_timeouts = self.hook_timeouts.clone();
+        spec.health_probe = self.health_probe.clone();
         if let Some(ref password) = self.svc_encrypted_password {
             spec.svc_encrypted_password = Some(password.clone())
         }
         spec
     }
 
+    fn resource_limits(&self) -> ResourceLimits {
+        ResourceLimits {
+            memory: self.memory_limit,
+            cpu: self.cpu_limit,
+            pids: self.pids_limit,
+        }
+    }
+
+    fn log_rotation(&self) -> RotationPolicy {
+        RotationPolicy {
+            max_size: self.log_max_size,
+            max_age: self.log_max_age,
+            keep: self.log_keep,
+        }
+    }
+
+    /// The time the given hook may run for before it is killed, if the spec sets one.
+    fn hook_timeout<H: Hook>(&self) -> Option<Duration> {
+        self.hook_timeouts.get(H::file_name()).map(|secs| Duration::from_secs(*secs))
+    }
+
+    /// The time a run of the given periodic hook may take before it is killed, if the spec sets
+    /// one under the hook's name, such as `periodic-backup`.
+    fn periodic_hook_timeout(&self, hook: &PeriodicHook) -> Option<Duration> {
+        self.hook_timeouts.get(&hook.hook_name()).map(|secs| Duration::from_secs(*secs))
+    }
+
     fn all_binds_satisfied(&self, census_ring: &CensusRing) -> bool {
         let mut ret = true;
         for ref bind in self.binds.iter() {
@@ -398,9 +621,110 @@ impl Service {
         ret
     }
 
-    /// Updates the process state of the service's supervisor
-    fn check_process(&mut self) -> bool {
-        self.supervisor.check_process()
+    /// Keep a service which waits for its binds from starting, marking it as failed once it has
+    /// waited for longer than the bind wait timeout.
+    fn hold_for_binds(&mut self) {
+        let started = match self.bind_wait_started {
+            Some(started) => started,
+            None => {
+                let now = Instant::now();
+                self.bind_wait_started = Some(now);
+                now
+            }
+        };
+        let timeout = self.bind_wait_timeout.map(Duration::from_secs);
+        if self.supervisor.hold_for_binds(started.elapsed(), timeout) {
+            outputln!(preamble self.service_group, "{}",
+                      Red.bold().paint(format!("Binds were not available within {}s, \
+                                                marking service as failed",
+                                               self.bind_wait_timeout.unwrap_or(0))));
+            self.restart_at = None;
+        }
+    }
+
+    /// Updates the process state of the service's supervisor, running the post-stop hook and
+    /// scheduling a restart if the process exited since the last check. The outcome of a
+    /// one-shot process's run is recorded.
+    fn check_process(&mut self, launcher: &LauncherCli) -> bool {
+        let was_up = self.supervisor.state == ProcessState::Up;
+        let alive = self.supervisor.check_process(launcher);
+        if was_up && !alive {
+            self.post_stop();
+            let exit_code = self.supervisor.last_exit_code;
+            if self.run_mode == RunMode::OneShot {
+                let result = RunResult::new(self.pkg.ident.clone(), exit_code);
+                outputln!(preamble self.service_group, "Run of {} {}", result.ident, result);
+                self.last_run = Some(result);
+            }
+            self.schedule_restart(exit_code);
+        }
+        alive
+    }
+
+    /// Decide whether and when a process which is down should be started again, given how it
+    /// exited and how often it was restarted recently. A one-shot process which ran to
+    /// completion stays down. A process which the restart policy says not to restart stays down
+    /// too, or is marked as failed if it exited with an error, as is one which was restarted too
+    /// many times within the restart window.
+    fn schedule_restart(&mut self, exit_code: Option<i32>) {
+        let failed = exit_code != Some(0);
+        if self.run_mode == RunMode::OneShot && !failed {
+            return;
+        }
+        if !self.restart_policy.restarts(exit_code) {
+            outputln!(preamble self.service_group,
+                      "Not restarting process with restart policy '{}'", self.restart_policy);
+            if failed {
+                self.supervisor.fail();
+            }
+            return;
+        }
+        let now = Instant::now();
+        let attempt = self.restarts.within(now, Duration::from_secs(self.restart_window));
+        if let Some(max) = self.max_restarts {
+            if attempt >= max as usize {
+                outputln!(preamble self.service_group, "{}",
+                          Red.bold().paint(format!("Process was restarted {} times within {}s, \
+                                                    marking service as failed",
+                                                   attempt, self.restart_window)));
+                self.supervisor.fail();
+                return;
+            }
+        }
+        let delay = restart_delay(attempt, self.restart_backoff, self.restart_backoff_max);
+        if delay.as_secs() > 0 {
+            outputln!(preamble self.service_group,
+                      "Restarting process in {}s", delay.as_secs());
+        }
+        self.restarts.record(now);
+        self.restart_at = Some(now + delay);
+        metrics::inc_restarts(&self.service_group);
+    }
+
+    /// Returns true once a scheduled restart is due, clearing it.
+    fn restart_due(&mut self) -> bool {
+        match self.restart_at {
+            Some(at) if Instant::now() >= at => {
+                self.restart_at = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether a one-shot process which ran to completion should run again, because the
+    /// service's configuration or hooks changed since.
+    fn rerun_due(&self) -> bool {
+        self.run_mode == RunMode::OneShot && self.restart_at.is_none() &&
+            (self.needs_reload || self.needs_reconfiguration)
+    }
+
+    /// Whether the service is one-shot and the last run of its current package succeeded.
+    fn ran_to_completion(&self) -> bool {
+        self.run_mode == RunMode::OneShot &&
+            self.last_run.as_ref().map_or(false, |run| {
+                run.ident == self.pkg.ident && run.succeeded()
+            })
     }
 
     fn process_down(&self) -> bool {
@@ -423,14 +747,75 @@ impl Service {
                 let reconfigure = self.compile_configuration(&ctx);
                 (reload, reconfigure)
             };
-            self.needs_reload = reload;
-            self.needs_reconfiguration = reconfigure;
+            // A change which hasn't been acted on yet, such as one held for a one-shot process
+            // which is still running, stays pending until it is.
+            self.needs_reload = self.needs_reload || reload;
+            self.needs_reconfiguration = self.needs_reconfiguration || reconfigure;
+            if reconfigure {
+                self.last_config_change = Some(Instant::now());
+            }
         }
         cfg_updated
     }
 
+    /// Update to the given package if the service's update window is open, otherwise hold it as
+    /// a pending update until the window opens. Returns true if the service was updated.
+    pub fn offer_update(&mut self, package: PackageInstall, launcher: &LauncherCli) -> bool {
+        if self.update_window_open() {
+            self.pending_update = None;
+            self.update_package(package, launcher);
+            return true;
+        }
+        if self.pending_update.as_ref().map(|pending| pending.ident()) != Some(package.ident()) {
+            outputln!(preamble self.service_group,
+                      "Update to {} is pending until the update window opens, {}",
+                      package.ident(),
+                      self.update_window.as_ref().unwrap());
+        }
+        self.pending_update = Some(package);
+        false
+    }
+
+    /// Apply an update which was held outside of the update window, once the window is open.
+    /// Returns true if the service was updated.
+    pub fn apply_pending_update(&mut self, launcher: &LauncherCli) -> bool {
+        if !self.update_window_open() {
+            return false;
+        }
+        match self.pending_update.take() {
+            Some(package) => {
+                self.update_package(package, launcher);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn has_pending_update(&self) -> bool {
+        self.pending_update.is_some()
+    }
+
+    fn update_window_open(&self) -> bool {
+        self.update_window.as_ref().map_or(
+            true,
+            |window| window.is_open(),
+        )
+    }
+
     /// Replace the package of the running service and restart it's system process.
     pub fn update_package(&mut self, package: PackageInstall, launcher: &LauncherCli) {
+        metrics::inc_update_attempts(&self.service_group);
+        let outcome = if self.replace_package(package, launcher) {
+            metrics::UPDATE_APPLIED
+        } else {
+            metrics::UPDATE_FAILED
+        };
+        metrics::inc_update_outcome(&self.service_group, outcome);
+    }
+
+    /// Load the given package in place of the current one and stop the process, which is started
+    /// again from the new package. Returns false if the package could not be loaded.
+    fn replace_package(&mut self, package: PackageInstall, launcher: &LauncherCli) -> bool {
         match Pkg::from_install(package) {
             Ok(pkg) => {
                 outputln!(preamble self.service_group,
@@ -440,27 +825,43 @@ impl Service {
                     Err(e) => {
                         outputln!(preamble self.service_group,
                                   "Failed to load config templates after updating package, {}", e);
-                        return;
+                        return false;
                     }
                 }
+                // The process is stopped before the hooks are replaced, so that the stop hooks
+                // of the package it was started from see it off.
+                if let Err(err) = self.stop_process(launcher) {
+                    outputln!(preamble self.service_group,
+                              "Error stopping process while updating package: {}", err);
+                }
                 self.hooks = HookTable::load(
                     &self.service_group,
                     &Self::hooks_root(&pkg, self.config_from.as_ref()),
                     fs::svc_hooks_path(self.service_group.service()),
+                    &pkg.periodic_hooks,
                 );
+                self.periodic_due.clear();
+                if self.update_verify_window.is_some() {
+                    self.verification = Some(UpdateVerification::new(self.pkg.ident.clone()));
+                }
                 self.pkg = pkg;
             }
             Err(err) => {
                 outputln!(preamble self.service_group,
                           "Unexpected error while updating package, {}", err);
-                return;
+                return false;
             }
         }
-        if let Err(err) = self.supervisor.stop(launcher) {
-            outputln!(preamble self.service_group,
-                      "Error stopping process while updating package: {}", err);
-        }
+        // A new package gets a fresh restart budget, even if the old one had failed.
+        self.restarts.clear();
+        self.restart_at = None;
+        self.bind_wait_started = None;
+        // The old package's health says nothing about the new one, which is checked right away.
+        self.health_check = HealthCheck::default();
+        self.gossiped_health = GossipedHealth::default();
+        self.last_health_check = None;
         self.initialized = false;
+        true
     }
 
     pub fn to_rumor(&self, incarnation: u64) -> ServiceRumor {
@@ -481,6 +882,7 @@ impl Service {
             exported.as_ref(),
         );
         rumor.set_incarnation(incarnation);
+        rumor.set_health(self.gossiped_health.status().to_string());
         rumor
     }
 
@@ -496,6 +898,8 @@ impl Service {
                 &self.service_group,
                 &self.pkg,
                 self.svc_encrypted_password.as_ref(),
+                self.hook_timeout::<InitHook>(),
+                &self.log_rotation(),
             )
         }
     }
@@ -509,6 +913,8 @@ impl Service {
                 &self.service_group,
                 &self.pkg,
                 self.svc_encrypted_password.as_ref(),
+                self.hook_timeout::<ReconfigureHook>(),
+                &self.log_rotation(),
             );
         }
     }
@@ -519,6 +925,79 @@ impl Service {
                 &self.service_group,
                 &self.pkg,
                 self.svc_encrypted_password.as_ref(),
+                self.hook_timeout::<PostRunHook>(),
+                &self.log_rotation(),
+            );
+        }
+    }
+
+    /// Start the periodic hooks which are due, each one interval after it last started or after
+    /// the service was loaded. Periodic hooks only run while the process is up, and those meant
+    /// for the leader only run on the leader of a service group in a leader topology.
+    fn run_periodic_hooks(&mut self, census_ring: &CensusRing) {
+        if !self.initialized || self.supervisor.state != ProcessState::Up {
+            return;
+        }
+        let leader = census_ring
+            .census_group_for(&self.service_group)
+            .and_then(|census_group| census_group.me())
+            .map_or(false, |me| me.leader);
+        let logs = self.log_rotation();
+        let now = SteadyTime::now();
+        for hook in self.hooks.periodic.iter() {
+            {
+                let due = self.periodic_due.entry(hook.name.clone()).or_insert_with(|| {
+                    hook.next_period_start()
+                });
+                if now < *due {
+                    continue;
+                }
+            }
+            if hook.leader_only && self.topology == Topology::Leader && !leader {
+                self.periodic_due.insert(hook.name.clone(), hook.next_period_start());
+                continue;
+            }
+            if hook.is_running() {
+                outputln!(preamble self.service_group,
+                          "Skipping {}, its previous run is still going", hook.hook_name());
+                self.periodic_due.insert(hook.name.clone(), hook.next_period_start());
+                continue;
+            }
+            // A run which couldn't be started is tried again on the next tick rather than
+            // counting for this period.
+            if hook.spawn(
+                &self.service_group,
+                &self.pkg,
+                self.svc_encrypted_password.clone(),
+                self.periodic_hook_timeout(hook),
+                logs,
+            )
+            {
+                self.periodic_due.insert(hook.name.clone(), hook.next_period_start());
+            }
+        }
+    }
+
+    fn pre_stop(&self) {
+        if let Some(ref hook) = self.hooks.pre_stop {
+            hook.run(
+                &self.service_group,
+                &self.pkg,
+                self.svc_encrypted_password.as_ref(),
+                self.hook_timeout::<PreStopHook>(),
+                &self.log_rotation(),
+            );
+        }
+    }
+
+    fn post_stop(&self) {
+        if let Some(ref hook) = self.hooks.post_stop {
+            hook.run(
+                &self.service_group,
+                &self.pkg,
+                self.svc_encrypted_password.as_ref(),
+                self.hook_timeout::<PostStopHook>(),
+                &self.log_rotation(),
             );
         }
     }
@@ -532,6 +1011,8 @@ impl Service {
                 &self.service_group,
                 &self.pkg,
                 self.svc_encrypted_password.as_ref(),
+                self.hook_timeout::<SuitabilityHook>(),
+                &self.log_rotation(),
             )
         })
     }
@@ -640,7 +1121,7 @@ impl Service {
 
     fn execute_hooks(&mut self, launcher: &LauncherCli) {
         if !self.initialized {
-            if self.check_process() {
+            if self.check_process(launcher) {
                 outputln!("Reattached to {}", self.service_group);
                 self.initialized = true;
                 return;
@@ -651,10 +1132,25 @@ impl Service {
                 self.post_run();
             }
         } else {
-            self.check_process();
-            if Instant::now().duration_since(self.last_health_check) >= *HEALTH_CHECK_INTERVAL {
+            self.check_process(launcher);
+            let interval = Duration::from_secs(self.health_check_interval);
+            if self.last_health_check.map_or(true, |last| last.elapsed() >= interval) {
+                self.supervisor.update_resource_usage(launcher);
                 self.run_health_check_hook();
             }
+            if self.verify_update(launcher) {
+                return;
+            }
+
+            // A process which is down is only started again once its restart is due, or once
+            // the configuration or hooks of a one-shot process which ran to completion change.
+            match self.supervisor.state {
+                ProcessState::Failed => return,
+                ProcessState::Down if !self.restart_due() && !self.rerun_due() => return,
+                // A one-shot process is left to finish before it runs again with the changes.
+                ProcessState::Up if self.run_mode == RunMode::OneShot => return,
+                _ => (),
+            }
 
             // NOTE: if you need reconfiguration and you DON'T have a
             // reload script, you're going to restart anyway.
@@ -667,6 +1163,80 @@ impl Service {
         }
     }
 
+    /// Roll back to the previous package if the package being verified after an update has stayed
+    /// critical or is crash-looping, or finish verifying it once its window has passed. Returns
+    /// true if the service was rolled back.
+    fn verify_update(&mut self, launcher: &LauncherCli) -> bool {
+        let window = match (self.update_verify_window, self.verification.as_ref()) {
+            (Some(window), Some(_)) => Duration::from_secs(window),
+            _ => return false,
+        };
+        let restarts = self.restarts.within(
+            Instant::now(),
+            Duration::from_secs(self.restart_window),
+        );
+        let reason = if self.verification.as_ref().map_or(
+            false,
+            UpdateVerification::is_critical,
+        )
+        {
+            format!("health check was critical {} times in a row", VERIFY_CRITICAL_CHECKS)
+        } else if self.supervisor.state == ProcessState::Failed {
+            "process has failed".to_string()
+        } else if restarts >= VERIFY_MAX_RESTARTS {
+            format!("process was restarted {} times", restarts)
+        } else {
+            if self.verification.as_ref().map_or(
+                false,
+                |verification| verification.is_complete(window),
+            )
+            {
+                outputln!(preamble self.service_group, "Update to {} verified", self.pkg.ident);
+                self.verification = None;
+                metrics::inc_update_outcome(&self.service_group, metrics::UPDATE_VERIFIED);
+            }
+            return false;
+        };
+        let previous = self.verification.take().unwrap().previous;
+        self.rollback(previous, reason, launcher);
+        true
+    }
+
+    /// Revert to the given, previously running package, blocking the current one.
+    fn rollback(&mut self, previous: PackageIdent, reason: String, launcher: &LauncherCli) {
+        let failed = self.pkg.ident.clone();
+        outputln!(preamble self.service_group, "{}",
+                  Red.bold().paint(format!("Update to {} failed verification, {}; rolling \
+                                            back to {}", failed, reason, previous)));
+        if let Err(err) = self.blocked_releases.block(failed.clone()) {
+            outputln!(preamble self.service_group,
+                      "Unable to persist blocked release {}, {}", failed, err);
+        }
+        match util::pkg::installed(&previous) {
+            // Not through `update_package`, a rollback is counted as such below rather than as
+            // another update attempt.
+            Some(package) => {
+                self.replace_package(package, launcher);
+            }
+            None => {
+                outputln!(preamble self.service_group,
+                          "Unable to roll back, {} is no longer installed", previous);
+                return;
+            }
+        }
+        self.verification = None;
+        if self.pkg.ident != previous {
+            return;
+        }
+        metrics::inc_update_outcome(&self.service_group, metrics::UPDATE_ROLLED_BACK);
+        self.last_rollback = Some(Rollback {
+            from: failed,
+            to: previous,
+            reason: reason,
+            timestamp: time::get_time().sec,
+        });
+    }
+
     /// Run file_updated hook if present
     fn file_updated(&self) -> bool {
         if self.initialized {
@@ -675,6 +1245,8 @@ impl Service {
                     &self.service_group,
                     &self.pkg,
                     self.svc_encrypted_password.as_ref(),
+                    self.hook_timeout::<FileUpdatedHook>(),
+                    &self.log_rotation(),
                 );
             }
         }
@@ -728,19 +1300,39 @@ impl Service {
     }
 
     fn run_health_check_hook(&mut self) {
-        let check_result = if let Some(ref hook) = self.hooks.health_check {
-            hook.run(
+        let check_result = if let Some(ref probe) = self.health_probe {
+            probe
+                .check(
+                    &self.service_group,
+                    &self.pkg,
+                    self.svc_encrypted_password.as_ref(),
+                    self.health_check_timeout.map(Duration::from_secs),
+                    &self.log_rotation(),
+                )
+                .unwrap_or(self.health_check_timeout_status)
+        } else if let Some(ref hook) = self.hooks.health_check {
+            hook.try_run(
                 &self.service_group,
                 &self.pkg,
                 self.svc_encrypted_password.as_ref(),
-            )
+                self.health_check_timeout.map(Duration::from_secs),
+                &self.log_rotation(),
+            ).unwrap_or(self.health_check_timeout_status)
         } else {
             match self.supervisor.status() {
                 (true, _) => HealthCheck::Ok,
+                // A one-shot process which ran to completion is down because it's done.
+                (false, _) if self.ran_to_completion() => HealthCheck::Ok,
                 (false, _) => HealthCheck::Critical,
             }
         };
-        self.last_health_check = Instant::now();
+        self.last_health_check = Some(Instant::now());
+        self.health_check = check_result;
+        self.gossiped_health.record(check_result);
+        if let Some(ref mut verification) = self.verification {
+            verification.record(check_result);
+        }
+        metrics::set_health_status(&self.service_group, check_result);
         self.cache_health_check(check_result);
     }
 
@@ -810,6 +1402,19 @@ impl fmt::Display for Service {
     }
 }
 
+fn serialize_pending_update<S>(
+    value: &Option<PackageInstall>,
+    s: S,
+) -> result::Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match *value {
+        Some(ref package) => s.serialize_some(package.ident()),
+        None => s.serialize_none(),
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Topology {
     Standalone,
@@ -872,6 +1477,7 @@ pub enum UpdateStrategy {
     None,
     AtOnce,
     Rolling,
+    Canary,
 }
 
 impl UpdateStrategy {
@@ -880,6 +1486,7 @@ impl UpdateStrategy {
             UpdateStrategy::None => "none",
             UpdateStrategy::AtOnce => "at-once",
             UpdateStrategy::Rolling => "rolling",
+            UpdateStrategy::Canary => "canary",
         }
     }
 }
@@ -892,6 +1499,7 @@ impl FromStr for UpdateStrategy {
             "none" => Ok(UpdateStrategy::None),
             "at-once" => Ok(UpdateStrategy::AtOnce),
             "rolling" => Ok(UpdateStrategy::Rolling),
+            "canary" => Ok(UpdateStrategy::Canary),
             _ => Err(sup_error!(
                 Error::InvalidUpdateStrategy(String::from(strategy))
             )),
@@ -1017,6 +1625,14 @@ mod test {
         assert_eq!(strategy, UpdateStrategy::AtOnce);
     }
 
+    #[test]
+    fn update_strategy_from_str_canary() {
+        let strategy = UpdateStrategy::from_str("canary").unwrap();
+
+        assert_eq!(strategy, UpdateStrategy::Canary);
+        assert_eq!(strategy.to_string(), "canary");
+    }
+
     #[test]
     fn update_strategy_from_str_invalid() {
         let strategy_str = "dope";
@@ -19,6 +19,7 @@ use std::path::{Path, PathBuf};
 
 use hcore::fs::FS_ROOT_PATH;
 use hcore::package::{PackageIdent, PackageInstall};
+use hcore::package::metadata::HookSchedule;
 use hcore::util::{deserialize_using_from_str, serialize_using_to_string};
 
 use error::{Error, Result};
@@ -101,6 +102,8 @@ pub struct Pkg {
     pub svc_run: PathBuf,
     pub svc_user: String,
     pub svc_group: String,
+    #[serde(skip)]
+    pub periodic_hooks: Vec<HookSchedule>,
 }
 
 impl Pkg {
@@ -127,6 +130,9 @@ impl Pkg {
             exports: package.exports().map_err(|e| {
                 sup_error!(Error::BadPackage(package.clone(), e))
             })?,
+            periodic_hooks: package.periodic_hooks().map_err(|e| {
+                sup_error!(Error::BadPackage(package.clone(), e))
+            })?,
             path: package.installed_path,
             ident: package.ident.clone(),
             origin: package.ident.origin.clone(),
@@ -0,0 +1,448 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Health checks run natively by the Supervisor, declared in a service's spec instead of shipping
+//! a `health_check` hook in the package.
+//!
+//! A probe's result is recorded exactly like the result of a `health_check` hook and its output
+//! is printed and logged like the hook's, to the same rotated log files, so it is served by the
+//! HTTP gateway's `/health` route.
+
+use std::io::Read;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use hcore::service::ServiceGroup;
+use hcore::util::rotating_log::RotationPolicy;
+use hyper::Client;
+use hyper::net::HttpsConnector;
+use hyper_openssl::OpensslClient;
+
+use super::{HealthCheck, Pkg};
+use super::hooks::{self, HealthCheckHook, Hook, HookOutput};
+use error::{Error, Result, SupError};
+use util::exec;
+
+static LOGKEY: &'static str = "HP";
+/// Seconds a probe may run for when the spec sets no health check timeout. Unlike a hook, a
+/// probe runs on the Supervisor's main loop, so it is never left to run without limit.
+const DEFAULT_PROBE_TIMEOUT_SECS: u64 = 30;
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum HealthProbe {
+    /// Healthy when an HTTP GET of `url` answers with a status between `min_status` and
+    /// `max_status`, inclusive.
+    #[serde(rename = "http")]
+    Http {
+        url: String,
+        #[serde(default = "default_min_status")]
+        min_status: u16,
+        #[serde(default = "default_max_status")]
+        max_status: u16,
+    },
+    /// Healthy when a TCP connection to `address`, such as `127.0.0.1:6379`, can be opened.
+    #[serde(rename = "tcp")]
+    Tcp { address: String },
+    /// Runs `command` through the shell as the service's user. Without thresholds its exit code
+    /// is read like that of a `health_check` hook. With thresholds the command must print a
+    /// number, which is a warning or critical once it reaches the matching threshold.
+    #[serde(rename = "exec")]
+    Exec {
+        command: String,
+        warning: Option<i64>,
+        critical: Option<i64>,
+    },
+}
+
+fn default_min_status() -> u16 {
+    200
+}
+
+fn default_max_status() -> u16 {
+    399
+}
+
+/// The result of running a probe along with the output to log for it.
+struct Outcome {
+    health: HealthCheck,
+    stdout: String,
+    stderr: String,
+}
+
+impl Outcome {
+    fn new(health: HealthCheck, stdout: String) -> Self {
+        Outcome {
+            health: health,
+            stdout: stdout,
+            stderr: String::new(),
+        }
+    }
+
+    fn failed(health: HealthCheck, stderr: String) -> Self {
+        Outcome {
+            health: health,
+            stdout: String::new(),
+            stderr: stderr,
+        }
+    }
+}
+
+impl HealthProbe {
+    pub fn validate(&self) -> Result<()> {
+        let err = match *self {
+            HealthProbe::Http { min_status, max_status, .. } if min_status > max_status => {
+                format!("min_status {} is above max_status {}", min_status, max_status)
+            }
+            HealthProbe::Tcp { ref address } if address.is_empty() => {
+                "a tcp probe requires an address".to_string()
+            }
+            HealthProbe::Exec { ref command, .. } if command.is_empty() => {
+                "an exec probe requires a command".to_string()
+            }
+            HealthProbe::Exec {
+                warning: Some(warning),
+                critical: Some(critical),
+                ..
+            } if warning > critical => {
+                format!("warning {} is above critical {}", warning, critical)
+            }
+            _ => return Ok(()),
+        };
+        Err(sup_error!(Error::InvalidHealthProbe(err)))
+    }
+
+    /// Run the probe, returning `None` if it did not finish within the given timeout, or within
+    /// `DEFAULT_PROBE_TIMEOUT_SECS` if none is given. Its output is logged like a `health_check`
+    /// hook's, rotating the logs of earlier runs according to `logs`.
+    pub fn check<T>(
+        &self,
+        service_group: &ServiceGroup,
+        pkg: &Pkg,
+        svc_encrypted_password: Option<T>,
+        timeout: Option<Duration>,
+        logs: &RotationPolicy,
+    ) -> Option<HealthCheck>
+    where
+        T: ToString,
+    {
+        let timeout = timeout.unwrap_or(Duration::from_secs(DEFAULT_PROBE_TIMEOUT_SECS));
+        let outcome = match *self {
+            HealthProbe::Http {
+                ref url,
+                min_status,
+                max_status,
+            } => {
+                let url = url.clone();
+                in_background(timeout, move || {
+                    http_probe(&url, min_status, max_status, timeout)
+                })
+            }
+            HealthProbe::Tcp { ref address } => {
+                let address = address.clone();
+                in_background(timeout, move || tcp_probe(&address, timeout))
+            }
+            HealthProbe::Exec {
+                ref command,
+                warning,
+                critical,
+            } => exec_probe(command, warning, critical, pkg, svc_encrypted_password, timeout),
+        };
+        match outcome {
+            Some(outcome) => {
+                let stdout_log = hooks::stdout_log_path::<HealthCheckHook>(service_group);
+                let stderr_log = hooks::stderr_log_path::<HealthCheckHook>(service_group);
+                HookOutput::new(&stdout_log, &stderr_log, *logs).write_output(
+                    HealthCheckHook::file_name(),
+                    service_group,
+                    &outcome.stdout,
+                    &outcome.stderr,
+                );
+                Some(outcome.health)
+            }
+            None => {
+                outputln!(preamble service_group, "Health probe timed out after {}s",
+                    timeout.as_secs());
+                None
+            }
+        }
+    }
+}
+
+/// Run a network probe on its own thread so that a connection which hangs can't hold up the
+/// service past its timeout.
+fn in_background<F>(timeout: Duration, probe: F) -> Option<Outcome>
+where
+    F: FnOnce() -> Outcome + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = tx.send(probe());
+    });
+    match rx.recv_timeout(timeout) {
+        Ok(outcome) => Some(outcome),
+        Err(RecvTimeoutError::Timeout) => None,
+        Err(RecvTimeoutError::Disconnected) => {
+            Some(Outcome::failed(
+                HealthCheck::Unknown,
+                "Health probe failed unexpectedly".to_string(),
+            ))
+        }
+    }
+}
+
+fn http_probe(url: &str, min_status: u16, max_status: u16, timeout: Duration) -> Outcome {
+    let mut client = match OpensslClient::new() {
+        Ok(ssl) => Client::with_connector(HttpsConnector::new(ssl)),
+        Err(err) => return Outcome::failed(HealthCheck::Unknown, err.to_string()),
+    };
+    client.set_read_timeout(Some(timeout));
+    client.set_write_timeout(Some(timeout));
+    match client.get(url).send() {
+        Ok(res) => {
+            let status = res.status.to_u16();
+            Outcome::new(
+                http_health(status, min_status, max_status),
+                format!("GET {} returned {}", url, res.status),
+            )
+        }
+        Err(err) => Outcome::failed(HealthCheck::Critical, format!("GET {} failed, {}", url, err)),
+    }
+}
+
+fn http_health(status: u16, min_status: u16, max_status: u16) -> HealthCheck {
+    if status >= min_status && status <= max_status {
+        HealthCheck::Ok
+    } else {
+        HealthCheck::Critical
+    }
+}
+
+fn tcp_probe(address: &str, timeout: Duration) -> Outcome {
+    let addrs = match address.to_socket_addrs() {
+        Ok(addrs) => addrs,
+        Err(err) => {
+            return Outcome::failed(
+                HealthCheck::Unknown,
+                format!("Unable to resolve {}, {}", address, err),
+            )
+        }
+    };
+    let mut last_err = None;
+    for addr in addrs {
+        match TcpStream::connect_timeout(&addr, timeout) {
+            Ok(_) => {
+                return Outcome::new(HealthCheck::Ok, format!("Connected to {}", addr));
+            }
+            Err(err) => last_err = Some(err),
+        }
+    }
+    Outcome::failed(
+        HealthCheck::Critical,
+        match last_err {
+            Some(err) => format!("Unable to connect to {}, {}", address, err),
+            None => format!("{} did not resolve to any address", address),
+        },
+    )
+}
+
+fn exec_probe<T>(
+    command: &str,
+    warning: Option<i64>,
+    critical: Option<i64>,
+    pkg: &Pkg,
+    svc_encrypted_password: Option<T>,
+    timeout: Duration,
+) -> Option<Outcome>
+where
+    T: ToString,
+{
+    let mut child = match exec::run_command(command, pkg, svc_encrypted_password) {
+        Ok(child) => child,
+        Err(err) => {
+            return Some(Outcome::failed(
+                HealthCheck::Unknown,
+                format!("Unable to run '{}', {}", command, err),
+            ))
+        }
+    };
+    let watchdog = exec::Watchdog::start(child.id(), timeout);
+    // Both pipes are drained at once, as a command which fills one while the other is being read
+    // to its end would block forever.
+    let stderr_reader = child.stderr.take().map(|mut err| {
+        thread::spawn(move || {
+            let mut stderr = String::new();
+            let _ = err.read_to_string(&mut stderr);
+            stderr
+        })
+    });
+    let mut stdout = String::new();
+    if let Some(ref mut out) = child.stdout {
+        let _ = out.read_to_string(&mut stdout);
+    }
+    let mut stderr = stderr_reader
+        .and_then(|reader| reader.join().ok())
+        .unwrap_or_default();
+    let (status, timed_out) = watchdog.wait(|| child.try_wait());
+    if timed_out {
+        return None;
+    }
+    let health = match status {
+        Ok(status) => exec_health(status.code(), &stdout, warning, critical),
+        Err(err) => {
+            stderr.push_str(&format!("Unable to wait for '{}', {}\n", command, err));
+            HealthCheck::Unknown
+        }
+    };
+    Some(Outcome {
+        health: health,
+        stdout: stdout,
+        stderr: stderr,
+    })
+}
+
+fn exec_health(
+    code: Option<i32>,
+    stdout: &str,
+    warning: Option<i64>,
+    critical: Option<i64>,
+) -> HealthCheck {
+    if warning.is_none() && critical.is_none() {
+        return match code {
+            Some(code) if code >= 0 && code <= 3 => HealthCheck::from(code as i8),
+            _ => HealthCheck::Unknown,
+        };
+    }
+    if code != Some(0) {
+        return HealthCheck::Critical;
+    }
+    let value = match stdout.trim().parse::<f64>() {
+        Ok(value) => value,
+        Err(_) => return HealthCheck::Unknown,
+    };
+    if critical.map_or(false, |c| value >= c as f64) {
+        HealthCheck::Critical
+    } else if warning.map_or(false, |w| value >= w as f64) {
+        HealthCheck::Warning
+    } else {
+        HealthCheck::Ok
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::TcpListener;
+
+    use toml;
+
+    use super::*;
+
+    #[test]
+    fn health_probe_from_toml() {
+        let probe: HealthProbe = toml::from_str(
+            r#"
+            type = "http"
+            url = "http://localhost:8080/status"
+            "#,
+        ).unwrap();
+        assert_eq!(
+            probe,
+            HealthProbe::Http {
+                url: "http://localhost:8080/status".to_string(),
+                min_status: 200,
+                max_status: 399,
+            }
+        );
+
+        let probe: HealthProbe = toml::from_str(
+            r#"
+            type = "exec"
+            command = "redis-cli dbsize"
+            critical = 1000
+            "#,
+        ).unwrap();
+        assert_eq!(
+            probe,
+            HealthProbe::Exec {
+                command: "redis-cli dbsize".to_string(),
+                warning: None,
+                critical: Some(1000),
+            }
+        );
+    }
+
+    #[test]
+    fn health_probe_validate() {
+        let probe = HealthProbe::Http {
+            url: "http://localhost".to_string(),
+            min_status: 400,
+            max_status: 200,
+        };
+        assert!(probe.validate().is_err());
+        let probe = HealthProbe::Tcp { address: "127.0.0.1:6379".to_string() };
+        assert!(probe.validate().is_ok());
+    }
+
+    #[test]
+    fn http_health_status_range() {
+        assert_eq!(http_health(200, 200, 399), HealthCheck::Ok);
+        assert_eq!(http_health(302, 200, 399), HealthCheck::Ok);
+        assert_eq!(http_health(404, 200, 399), HealthCheck::Critical);
+        assert_eq!(http_health(503, 200, 599), HealthCheck::Ok);
+    }
+
+    #[test]
+    fn exec_health_exit_codes() {
+        assert_eq!(exec_health(Some(0), "", None, None), HealthCheck::Ok);
+        assert_eq!(exec_health(Some(1), "", None, None), HealthCheck::Warning);
+        assert_eq!(exec_health(Some(2), "", None, None), HealthCheck::Critical);
+        assert_eq!(exec_health(Some(42), "", None, None), HealthCheck::Unknown);
+        assert_eq!(exec_health(None, "", None, None), HealthCheck::Unknown);
+    }
+
+    #[test]
+    fn exec_health_thresholds() {
+        let (w, c) = (Some(10), Some(20));
+        assert_eq!(exec_health(Some(0), "5\n", w, c), HealthCheck::Ok);
+        assert_eq!(exec_health(Some(0), "10\n", w, c), HealthCheck::Warning);
+        assert_eq!(exec_health(Some(0), "25.5", w, c), HealthCheck::Critical);
+        assert_eq!(exec_health(Some(0), "lots", w, c), HealthCheck::Unknown);
+        assert_eq!(exec_health(Some(1), "5", w, c), HealthCheck::Critical);
+    }
+
+    #[test]
+    fn tcp_probe_connects() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let timeout = Duration::from_secs(5);
+        assert_eq!(tcp_probe(&address, timeout).health, HealthCheck::Ok);
+        drop(listener);
+        assert_eq!(tcp_probe(&address, timeout).health, HealthCheck::Critical);
+    }
+
+    #[test]
+    fn in_background_gives_up_at_the_timeout() {
+        let outcome = in_background(Duration::from_millis(50), || {
+            thread::sleep(Duration::from_secs(5));
+            Outcome::new(HealthCheck::Ok, String::new())
+        });
+        assert!(outcome.is_none());
+        let outcome = in_background(Duration::from_secs(5), || {
+            Outcome::new(HealthCheck::Warning, String::new())
+        });
+        assert_eq!(outcome.unwrap().health, HealthCheck::Warning);
+    }
+}
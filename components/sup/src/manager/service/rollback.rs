@@ -0,0 +1,208 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verification of a service's package after an automatic update.
+//!
+//! For the verification window following an update, a service which stays `Critical` or keeps
+//! crashing is rolled back to the package it ran before. The release it was updated to is then
+//! blocked, so the updater never installs it for that service again.
+
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::result;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use hcore::package::PackageIdent;
+use hcore::util::serialize_using_to_string;
+use serde;
+
+use super::HealthCheck;
+
+/// Restarts of an updated process within its verification window which count as a crash loop.
+pub const VERIFY_MAX_RESTARTS: usize = 3;
+/// Consecutive critical health checks which fail an update. A single one doesn't, as the first
+/// check runs right after the new process starts, often before it is ready to answer.
+pub const VERIFY_CRITICAL_CHECKS: u32 = 3;
+
+/// An update which is being verified.
+#[derive(Debug)]
+pub struct UpdateVerification {
+    /// The package which ran before the update, and which the service is rolled back to
+    pub previous: PackageIdent,
+    started: Instant,
+    /// How many health checks in a row have been critical since the update
+    critical_checks: u32,
+}
+
+impl UpdateVerification {
+    pub fn new(previous: PackageIdent) -> Self {
+        UpdateVerification {
+            previous: previous,
+            started: Instant::now(),
+            critical_checks: 0,
+        }
+    }
+
+    /// Record the result of a health check of the updated package.
+    pub fn record(&mut self, check: HealthCheck) {
+        if check == HealthCheck::Critical {
+            self.critical_checks += 1;
+        } else {
+            self.critical_checks = 0;
+        }
+    }
+
+    /// Whether the health check has been critical for `VERIFY_CRITICAL_CHECKS` checks in a row.
+    pub fn is_critical(&self) -> bool {
+        self.critical_checks >= VERIFY_CRITICAL_CHECKS
+    }
+
+    /// Whether the given verification window has passed since the update.
+    pub fn is_complete(&self, window: Duration) -> bool {
+        self.started.elapsed() >= window
+    }
+}
+
+/// An update which failed verification and was rolled back.
+#[derive(Clone, Debug, Serialize)]
+pub struct Rollback {
+    #[serde(serialize_with = "serialize_using_to_string")]
+    pub from: PackageIdent,
+    #[serde(serialize_with = "serialize_using_to_string")]
+    pub to: PackageIdent,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
+/// Releases a service will not be updated to, persisted to a file with one package identifier
+/// per line.
+#[derive(Debug, Default)]
+pub struct BlockedReleases {
+    path: PathBuf,
+    idents: Vec<PackageIdent>,
+}
+
+impl BlockedReleases {
+    /// Load the blocked releases from the given file. A missing file blocks nothing.
+    pub fn load<T>(path: T) -> Self
+    where
+        T: Into<PathBuf>,
+    {
+        let path = path.into();
+        let mut idents = Vec::new();
+        if let Ok(file) = File::open(&path) {
+            for line in BufReader::new(file).lines() {
+                let line = match line {
+                    Ok(line) => line,
+                    Err(err) => {
+                        warn!("Couldn't read blocked releases, {}, {}", path.display(), err);
+                        break;
+                    }
+                };
+                match PackageIdent::from_str(line.trim()) {
+                    Ok(ident) => idents.push(ident),
+                    Err(err) => warn!("Ignoring blocked release '{}', {}", line, err),
+                }
+            }
+        }
+        BlockedReleases {
+            path: path,
+            idents: idents,
+        }
+    }
+
+    pub fn contains(&self, ident: &PackageIdent) -> bool {
+        self.idents.contains(ident)
+    }
+
+    /// Block the given release and persist the list.
+    pub fn block(&mut self, ident: PackageIdent) -> io::Result<()> {
+        if !self.contains(&ident) {
+            self.idents.push(ident);
+        }
+        let tmp_file = self.path.with_extension("tmp");
+        {
+            let mut file = File::create(&tmp_file)?;
+            for ident in self.idents.iter() {
+                writeln!(file, "{}", ident)?;
+            }
+        }
+        fs::rename(&tmp_file, &self.path)
+    }
+
+    pub fn idents(&self) -> Vec<PackageIdent> {
+        self.idents.clone()
+    }
+}
+
+impl serde::Serialize for BlockedReleases {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.idents.iter().map(|ident| ident.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tempdir::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn blocked_releases_persist() {
+        let tmpdir = TempDir::new("blockedreleases").unwrap();
+        let path = tmpdir.path().join("redis.blocked");
+        let bad = PackageIdent::from_str("core/redis/3.2.5/20170601000000").unwrap();
+        let good = PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap();
+
+        let mut blocked = BlockedReleases::load(&path);
+        assert!(!blocked.contains(&bad));
+        blocked.block(bad.clone()).unwrap();
+        blocked.block(bad.clone()).unwrap();
+
+        let blocked = BlockedReleases::load(&path);
+        assert!(blocked.contains(&bad));
+        assert!(!blocked.contains(&good));
+        assert_eq!(blocked.idents(), vec![bad]);
+    }
+
+    #[test]
+    fn update_verification_window() {
+        let verification = UpdateVerification::new(
+            PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap(),
+        );
+        assert!(verification.is_complete(Duration::from_secs(0)));
+        assert!(!verification.is_complete(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn update_verification_fails_once_critical_holds() {
+        let mut verification = UpdateVerification::new(
+            PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap(),
+        );
+        verification.record(HealthCheck::Critical);
+        assert!(!verification.is_critical());
+        verification.record(HealthCheck::Ok);
+        for _ in 1..VERIFY_CRITICAL_CHECKS {
+            verification.record(HealthCheck::Critical);
+        }
+        assert!(!verification.is_critical());
+        verification.record(HealthCheck::Critical);
+        assert!(verification.is_critical());
+    }
+}
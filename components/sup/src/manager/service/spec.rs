@@ -12,29 +12,38 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashSet;
+use std::cmp;
+use std::collections::{BTreeMap, HashSet};
 use std::fmt;
 use std::fs::{self, File};
-use std::io::{BufReader, Read, Write};
+use std::io::{self, BufReader, Read, Write};
 use std::iter::FromIterator;
 use std::path::{Path, PathBuf};
 use std::result;
 use std::str::FromStr;
 
 use hcore::channel::STABLE_CHANNEL;
-use hcore::package::{PackageIdent, PackageInstall};
+use hcore::package::{PackageIdent, PackageInstall, VersionReq};
 use hcore::service::{ApplicationEnvironment, ServiceGroup};
 use hcore::url::DEFAULT_BLDR_URL;
 use hcore::util::{deserialize_using_from_str, serialize_using_to_string};
+use hcore::util::rotating_log;
 use rand::{Rng, thread_rng};
 use serde::{self, Deserialize};
 use toml;
 
-use super::{Topology, UpdateStrategy};
+use super::{HealthCheck, HealthProbe, Topology, UpdateStrategy, UpdateWindow};
+use super::hooks::{PERIODIC_HOOKS_DIR, FileUpdatedHook, Hook, InitHook, PostRunHook, PostStopHook,
+                   PreStopHook, ReconfigureHook, ReloadHook, SmokeTestHook, SuitabilityHook};
 use error::{Error, Result, SupError};
 
 static LOGKEY: &'static str = "SS";
 static DEFAULT_GROUP: &'static str = "default";
+const DEFAULT_HEALTH_CHECK_INTERVAL: u64 = 30;
+const DEFAULT_RESTART_WINDOW: u64 = 300;
+const DEFAULT_RESTART_BACKOFF: u64 = 1;
+const DEFAULT_RESTART_BACKOFF_MAX: u64 = 60;
+const DEFAULT_CANARY_SOAK: u64 = 300;
 const SPEC_FILE_EXT: &'static str = "spec";
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -99,8 +108,22 @@ pub struct ServiceSpec {
     pub application_environment: Option<ApplicationEnvironment>,
     pub bldr_url: String,
     pub channel: String,
+    // Versions of the package the service may run, any version in the channel if not set
+    pub version_req: Option<VersionReq>,
     pub topology: Topology,
     pub update_strategy: UpdateStrategy,
+    // Members updated first by the canary update strategy, either a count or a percentage of the
+    // service group
+    #[serde(deserialize_with = "deserialize_using_from_str",
+            serialize_with = "serialize_using_to_string")]
+    pub canary_size: CanarySize,
+    // Seconds the canaries must stay healthy before the rest of the service group is updated
+    pub canary_soak: u64,
+    // Seconds after an automatic update during which the service is rolled back to its previous
+    // package if it stays critical or crash-loops, without verification if not set
+    pub update_verify_window: Option<u64>,
+    // When the updater may apply updates, at any time if not set
+    pub update_window: Option<UpdateWindow>,
     pub binds: Vec<ServiceBind>,
     pub config_from: Option<PathBuf>,
     #[serde(deserialize_with = "deserialize_using_from_str",
@@ -112,6 +135,55 @@ pub struct ServiceSpec {
     pub svc_encrypted_password: Option<String>,
     // The name of the composite this service is a part of
     pub composite: Option<String>,
+    // Seconds to wait between runs of the health check
+    pub health_check_interval: u64,
+    // Seconds a health check may run for before it is killed, without limit if not set except
+    // for a health probe, which is given 30 seconds
+    pub health_check_timeout: Option<u64>,
+    // The health recorded for a health check which was killed for running too long
+    #[serde(deserialize_with = "deserialize_using_from_str",
+            serialize_with = "serialize_health_check")]
+    pub health_check_timeout_status: HealthCheck,
+    // Whether the service's process runs until it is stopped or runs to completion
+    #[serde(deserialize_with = "deserialize_using_from_str",
+            serialize_with = "serialize_using_to_string")]
+    pub run_mode: RunMode,
+    // When the service's process is restarted after it exits
+    #[serde(deserialize_with = "deserialize_using_from_str",
+            serialize_with = "serialize_using_to_string")]
+    pub restart_policy: RestartPolicy,
+    // Restarts allowed within the restart window before the service is marked as failed, without
+    // limit if not set
+    pub max_restarts: Option<u32>,
+    // Seconds over which restarts are counted
+    pub restart_window: u64,
+    // Seconds to wait before the first restart, doubled for each further restart in the window
+    pub restart_backoff: u64,
+    // Upper bound in seconds on the wait before a restart
+    pub restart_backoff_max: u64,
+    // Bytes of memory the service may use, without limit if not set
+    pub memory_limit: Option<u64>,
+    // Thousandths of a CPU the service may use, without limit if not set
+    pub cpu_limit: Option<u32>,
+    // Number of processes and threads the service may run, without limit if not set
+    pub pids_limit: Option<u64>,
+    // Bytes each log of the service and its hooks may grow to before it is rotated
+    pub log_max_size: u64,
+    // Seconds the service's logs are written to before they are rotated, by size only if not set
+    pub log_max_age: Option<u64>,
+    // Rotated files kept for each log of the service and its hooks
+    pub log_keep: u32,
+    // Hold the service, before it is first started and before every restart, until every group
+    // it binds to has an alive member
+    pub wait_for_binds: bool,
+    // Seconds to wait for binds before the service is marked as failed, without limit if not set
+    pub bind_wait_timeout: Option<u64>,
+    // TOML tables must follow every plain value, so the fields below must stay last.
+    //
+    // Seconds each hook, keyed by its file name, may run for before it is killed
+    pub hook_timeouts: BTreeMap<String, u64>,
+    // Checked by the Supervisor itself in place of the package's health check hook
+    pub health_probe: Option<HealthProbe>,
 }
 
 impl ServiceSpec {
@@ -141,22 +213,46 @@ impl ServiceSpec {
     }
 
     pub fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-        debug!(
-            "Writing service spec to '{}': {:?}",
-            path.as_ref().display(),
-            &self
-        );
-        let dst_path = path.as_ref().parent().expect(
+        let tmpfile = self.to_tmpfile(path.as_ref())?;
+        fs::rename(&tmpfile, path.as_ref()).map_err(|err| {
+            sup_error!(Error::ServiceSpecFileIO(path.as_ref().to_path_buf(), err))
+        })?;
+
+        Ok(())
+    }
+
+    /// Write the spec to the given path like `to_file`, unless a spec file is already there.
+    /// The file is linked into place in one step, so of two specs created at the same path at
+    /// once only one is written. Returns false, writing nothing, if the path was taken.
+    pub fn create_file<P: AsRef<Path>>(&self, path: P) -> Result<bool> {
+        let tmpfile = self.to_tmpfile(path.as_ref())?;
+        let created = match fs::hard_link(&tmpfile, path.as_ref()) {
+            Ok(()) => Ok(true),
+            Err(ref err) if err.kind() == io::ErrorKind::AlreadyExists => Ok(false),
+            Err(err) => Err(sup_error!(
+                Error::ServiceSpecFileIO(path.as_ref().to_path_buf(), err)
+            )),
+        };
+        if let Err(err) = fs::remove_file(&tmpfile) {
+            debug!("Error removing '{}': {}", tmpfile.display(), err);
+        }
+        created
+    }
+
+    /// Write the spec to a temporary file next to the given path, returning the temporary file.
+    fn to_tmpfile(&self, path: &Path) -> Result<PathBuf> {
+        debug!("Writing service spec to '{}': {:?}", path.display(), &self);
+        let dst_path = path.parent().expect(
             "Cannot determine parent directory for service spec",
         );
-        let tmpfile = path.as_ref().with_extension(
+        let tmpfile = path.with_extension(
             thread_rng()
                 .gen_ascii_chars()
                 .take(8)
                 .collect::<String>(),
         );
         fs::create_dir_all(dst_path).map_err(|err| {
-            sup_error!(Error::ServiceSpecFileIO(path.as_ref().to_path_buf(), err))
+            sup_error!(Error::ServiceSpecFileIO(path.to_path_buf(), err))
         })?;
         // Release the write file handle before the end of the function since we're done
         {
@@ -168,11 +264,7 @@ impl ServiceSpec {
                 sup_error!(Error::ServiceSpecFileIO(tmpfile.to_path_buf(), err))
             })?;
         }
-        fs::rename(&tmpfile, path.as_ref()).map_err(|err| {
-            sup_error!(Error::ServiceSpecFileIO(path.as_ref().to_path_buf(), err))
-        })?;
-
-        Ok(())
+        Ok(tmpfile)
     }
 
     pub fn file_name(&self) -> String {
@@ -181,9 +273,71 @@ impl ServiceSpec {
 
     pub fn validate(&self, package: &PackageInstall) -> Result<()> {
         self.validate_binds(package)?;
+        self.validate_health_check_timeout_status()?;
+        self.validate_hook_timeouts()?;
+        self.validate_resource_limits()?;
+        if let Some(ref probe) = self.health_probe {
+            probe.validate()?;
+        }
+        Ok(())
+    }
+
+    /// Validates that timeouts are only set for hooks which are expected to run to completion.
+    /// The `run` hook is the service itself and the health check has its own timeout. Periodic
+    /// hooks are named like `periodic-backup`.
+    fn validate_hook_timeouts(&self) -> Result<()> {
+        let hooks = [
+            FileUpdatedHook::file_name(),
+            InitHook::file_name(),
+            PostRunHook::file_name(),
+            PostStopHook::file_name(),
+            PreStopHook::file_name(),
+            ReconfigureHook::file_name(),
+            ReloadHook::file_name(),
+            SmokeTestHook::file_name(),
+            SuitabilityHook::file_name(),
+        ];
+        let periodic = format!("{}-", PERIODIC_HOOKS_DIR);
+        match self.hook_timeouts.keys().find(|hook| {
+            !hooks.contains(&hook.as_str()) &&
+                !(hook.starts_with(&periodic) && hook.len() > periodic.len())
+        }) {
+            Some(hook) => Err(sup_error!(Error::InvalidHookTimeout(hook.clone()))),
+            None => Ok(()),
+        }
+    }
+
+    /// Validates that no resource limit is zero, which would keep the service from running at
+    /// all, and that limits are only set where the Launcher is able to enforce them.
+    fn validate_resource_limits(&self) -> Result<()> {
+        if self.memory_limit == Some(0) {
+            return Err(sup_error!(Error::InvalidResourceLimit("memory_limit".to_string())));
+        }
+        if self.cpu_limit == Some(0) {
+            return Err(sup_error!(Error::InvalidResourceLimit("cpu_limit".to_string())));
+        }
+        if self.pids_limit == Some(0) {
+            return Err(sup_error!(Error::InvalidResourceLimit("pids_limit".to_string())));
+        }
+        let limited = self.memory_limit.is_some() || self.cpu_limit.is_some() ||
+            self.pids_limit.is_some();
+        if limited && !cfg!(target_os = "linux") {
+            return Err(sup_error!(Error::ResourceLimitsUnsupported));
+        }
         Ok(())
     }
 
+    /// Validates that a timed out health check is recorded as either unknown or critical, as
+    /// it can't be known to be healthy.
+    fn validate_health_check_timeout_status(&self) -> Result<()> {
+        match self.health_check_timeout_status {
+            HealthCheck::Unknown | HealthCheck::Critical => Ok(()),
+            status => Err(sup_error!(Error::BadHealthCheckTimeoutStatus(
+                status.to_string().to_lowercase(),
+            ))),
+        }
+    }
+
     /// Validates that all required package binds are present in service binds and all remaining
     /// service binds are optional package binds.
     ///
@@ -236,18 +390,49 @@ impl Default for ServiceSpec {
             application_environment: None,
             bldr_url: DEFAULT_BLDR_URL.to_string(),
             channel: STABLE_CHANNEL.to_string(),
+            version_req: None,
             topology: Topology::default(),
             update_strategy: UpdateStrategy::default(),
+            canary_size: CanarySize::default(),
+            canary_soak: DEFAULT_CANARY_SOAK,
+            update_verify_window: None,
+            update_window: None,
             binds: Vec::default(),
             config_from: None,
             desired_state: DesiredState::default(),
             start_style: StartStyle::default(),
             svc_encrypted_password: None,
             composite: None,
+            health_check_interval: DEFAULT_HEALTH_CHECK_INTERVAL,
+            health_check_timeout: None,
+            health_check_timeout_status: HealthCheck::Unknown,
+            run_mode: RunMode::default(),
+            restart_policy: RestartPolicy::default(),
+            max_restarts: None,
+            restart_window: DEFAULT_RESTART_WINDOW,
+            restart_backoff: DEFAULT_RESTART_BACKOFF,
+            restart_backoff_max: DEFAULT_RESTART_BACKOFF_MAX,
+            memory_limit: None,
+            cpu_limit: None,
+            pids_limit: None,
+            log_max_size: rotating_log::DEFAULT_MAX_SIZE,
+            log_max_age: None,
+            log_keep: rotating_log::DEFAULT_KEEP,
+            wait_for_binds: false,
+            bind_wait_timeout: None,
+            hook_timeouts: BTreeMap::new(),
+            health_probe: None,
         }
     }
 }
 
+fn serialize_health_check<S>(value: &HealthCheck, s: S) -> result::Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    s.serialize_str(&value.to_string().to_lowercase())
+}
+
 impl FromStr for ServiceSpec {
     type Err = SupError;
 
@@ -342,6 +527,154 @@ impl FromStr for StartStyle {
     }
 }
 
+/// How long the service's process is expected to run for. A long-running process is kept up
+/// until the service is stopped, while a one-shot process runs to completion and is only started
+/// again when the service's package, configuration or hooks change.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RunMode {
+    LongRunning,
+    OneShot,
+}
+
+impl Default for RunMode {
+    fn default() -> RunMode {
+        RunMode::LongRunning
+    }
+}
+
+impl fmt::Display for RunMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            RunMode::LongRunning => "long-running",
+            RunMode::OneShot => "one-shot",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl FromStr for RunMode {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "long-running" => Ok(RunMode::LongRunning),
+            "one-shot" => Ok(RunMode::OneShot),
+            _ => Err(sup_error!(Error::BadRunMode(value.to_string()))),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RestartPolicy {
+    Always,
+    OnFailure,
+    Never,
+}
+
+impl RestartPolicy {
+    /// Whether a process which exited with the given code should be restarted. A process killed
+    /// by a signal has no exit code and is considered to have failed.
+    pub fn restarts(&self, exit_code: Option<i32>) -> bool {
+        match *self {
+            RestartPolicy::Always => true,
+            RestartPolicy::OnFailure => exit_code != Some(0),
+            RestartPolicy::Never => false,
+        }
+    }
+}
+
+impl Default for RestartPolicy {
+    fn default() -> RestartPolicy {
+        RestartPolicy::Always
+    }
+}
+
+impl fmt::Display for RestartPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            RestartPolicy::Always => "always",
+            RestartPolicy::OnFailure => "on-failure",
+            RestartPolicy::Never => "never",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl serde::Serialize for RestartPolicy {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl FromStr for RestartPolicy {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "always" => Ok(RestartPolicy::Always),
+            "on-failure" => Ok(RestartPolicy::OnFailure),
+            "never" => Ok(RestartPolicy::Never),
+            _ => Err(sup_error!(Error::BadRestartPolicy(value.to_string()))),
+        }
+    }
+}
+
+/// How many members of a service group are updated first by the canary update strategy.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CanarySize {
+    Count(u32),
+    Percent(u32),
+}
+
+impl CanarySize {
+    /// The number of canaries in a service group with the given number of alive members. A
+    /// percentage is rounded up, and there is always at least one canary.
+    pub fn of(&self, population: usize) -> usize {
+        let size = match *self {
+            CanarySize::Count(count) => count as usize,
+            CanarySize::Percent(percent) => (population * percent as usize + 99) / 100,
+        };
+        cmp::max(1, cmp::min(size, population))
+    }
+}
+
+impl Default for CanarySize {
+    fn default() -> CanarySize {
+        CanarySize::Count(1)
+    }
+}
+
+impl fmt::Display for CanarySize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CanarySize::Count(count) => write!(f, "{}", count),
+            CanarySize::Percent(percent) => write!(f, "{}%", percent),
+        }
+    }
+}
+
+impl FromStr for CanarySize {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        let size = if value.ends_with('%') {
+            match value[..value.len() - 1].parse() {
+                Ok(percent) if percent > 0 && percent <= 100 => Some(CanarySize::Percent(percent)),
+                _ => None,
+            }
+        } else {
+            match value.parse() {
+                Ok(count) if count > 0 => Some(CanarySize::Count(count)),
+                _ => None,
+            }
+        };
+        size.ok_or(sup_error!(Error::BadCanarySize(value.to_string())))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::fs::{self, File};
@@ -385,13 +718,44 @@ mod test {
             group = "jobs"
             application_environment = "theinternet.preprod"
             bldr_url = "http://example.com/depot"
+            version_req = ">=1.2, <2.0"
             topology = "leader"
             update_strategy = "rolling"
+            canary_size = "25%"
+            canary_soak = 600
+            update_verify_window = 900
+            update_window = "mon-fri 02:00-04:00 UTC"
             binds = ["cache:redis.cache@acmecorp", "db:postgres.app@acmecorp"]
             start_style = "persistent"
             config_from = "/only/for/development"
+            health_check_interval = 10
+            health_check_timeout = 5
+            health_check_timeout_status = "critical"
+            run_mode = "one-shot"
+            restart_policy = "never"
+            max_restarts = 3
+            restart_window = 60
+            restart_backoff = 5
+            restart_backoff_max = 120
+            memory_limit = 1073741824
+            cpu_limit = 1500
+            pids_limit = 100
+            log_max_size = 5242880
+            log_max_age = 3600
+            log_keep = 10
+            wait_for_binds = true
+            bind_wait_timeout = 120
 
             extra_stuff = "should be ignored"
+
+            [hook_timeouts]
+            init = 60
+            reconfigure = 30
+
+            [health_probe]
+            type = "http"
+            url = "http://localhost:8080/status"
+            max_status = 299
             "#;
         let spec = ServiceSpec::from_str(toml).unwrap();
 
@@ -407,8 +771,19 @@ mod test {
             )
         );
         assert_eq!(spec.bldr_url, String::from("http://example.com/depot"));
+        assert_eq!(
+            spec.version_req,
+            Some(VersionReq::from_str(">=1.2, <2.0").unwrap())
+        );
         assert_eq!(spec.topology, Topology::Leader);
         assert_eq!(spec.update_strategy, UpdateStrategy::Rolling);
+        assert_eq!(spec.canary_size, CanarySize::Percent(25));
+        assert_eq!(spec.canary_soak, 600);
+        assert_eq!(spec.update_verify_window, Some(900));
+        assert_eq!(
+            spec.update_window,
+            Some(UpdateWindow::from_str("mon-fri 02:00-04:00 UTC").unwrap())
+        );
         assert_eq!(
             spec.binds,
             vec![
@@ -421,6 +796,238 @@ mod test {
             Some(PathBuf::from("/only/for/development"))
         );
         assert_eq!(spec.start_style, StartStyle::Persistent);
+        assert_eq!(spec.health_check_interval, 10);
+        assert_eq!(spec.health_check_timeout, Some(5));
+        assert_eq!(spec.health_check_timeout_status, HealthCheck::Critical);
+        assert_eq!(spec.run_mode, RunMode::OneShot);
+        assert_eq!(spec.restart_policy, RestartPolicy::Never);
+        assert_eq!(spec.max_restarts, Some(3));
+        assert_eq!(spec.restart_window, 60);
+        assert_eq!(spec.restart_backoff, 5);
+        assert_eq!(spec.restart_backoff_max, 120);
+        assert_eq!(spec.memory_limit, Some(1073741824));
+        assert_eq!(spec.cpu_limit, Some(1500));
+        assert_eq!(spec.pids_limit, Some(100));
+        assert_eq!(spec.log_max_size, 5242880);
+        assert_eq!(spec.log_max_age, Some(3600));
+        assert_eq!(spec.log_keep, 10);
+        assert!(spec.wait_for_binds);
+        assert_eq!(spec.bind_wait_timeout, Some(120));
+        assert_eq!(spec.hook_timeouts.get("init"), Some(&60));
+        assert_eq!(spec.hook_timeouts.get("reconfigure"), Some(&30));
+        assert_eq!(
+            spec.health_probe,
+            Some(HealthProbe::Http {
+                url: "http://localhost:8080/status".to_string(),
+                min_status: 200,
+                max_status: 299,
+            })
+        );
+    }
+
+    #[test]
+    fn service_spec_from_str_health_check_defaults() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert_eq!(spec.health_check_interval, 30);
+        assert_eq!(spec.health_check_timeout, None);
+        assert_eq!(spec.health_check_timeout_status, HealthCheck::Unknown);
+    }
+
+    #[test]
+    fn service_spec_from_str_restart_defaults() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert_eq!(spec.run_mode, RunMode::LongRunning);
+        assert_eq!(spec.restart_policy, RestartPolicy::Always);
+        assert_eq!(spec.max_restarts, None);
+        assert_eq!(spec.restart_window, 300);
+        assert_eq!(spec.restart_backoff, 1);
+        assert_eq!(spec.restart_backoff_max, 60);
+    }
+
+    #[test]
+    fn service_spec_from_str_log_rotation_defaults() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert_eq!(spec.log_max_size, 10485760);
+        assert_eq!(spec.log_max_age, None);
+        assert_eq!(spec.log_keep, 5);
+    }
+
+    #[test]
+    fn service_spec_from_str_invalid_restart_policy() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            restart_policy = "sometimes"
+            "#;
+
+        match ServiceSpec::from_str(toml) {
+            Err(e) => {
+                match e.err {
+                    ServiceSpecParse(_) => assert!(true),
+                    e => panic!("Unexpected error returned: {:?}", e),
+                }
+            }
+            Ok(_) => panic!("Spec TOML should fail to parse"),
+        }
+    }
+
+    #[test]
+    fn service_spec_from_str_invalid_run_mode() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            run_mode = "forever"
+            "#;
+
+        match ServiceSpec::from_str(toml) {
+            Err(e) => {
+                match e.err {
+                    ServiceSpecParse(_) => assert!(true),
+                    e => panic!("Unexpected error returned: {:?}", e),
+                }
+            }
+            Ok(_) => panic!("Spec TOML should fail to parse"),
+        }
+    }
+
+    #[test]
+    fn restart_policy_restarts() {
+        assert!(RestartPolicy::Always.restarts(Some(0)));
+        assert!(RestartPolicy::Always.restarts(Some(1)));
+        assert!(!RestartPolicy::OnFailure.restarts(Some(0)));
+        assert!(RestartPolicy::OnFailure.restarts(Some(1)));
+        assert!(RestartPolicy::OnFailure.restarts(None));
+        assert!(!RestartPolicy::Never.restarts(Some(1)));
+        assert!(!RestartPolicy::Never.restarts(None));
+    }
+
+    #[test]
+    fn canary_size_from_str() {
+        assert_eq!(CanarySize::from_str("3").unwrap(), CanarySize::Count(3));
+        assert_eq!(CanarySize::from_str("10%").unwrap(), CanarySize::Percent(10));
+        assert!(CanarySize::from_str("0").is_err());
+        assert!(CanarySize::from_str("0%").is_err());
+        assert!(CanarySize::from_str("101%").is_err());
+        assert!(CanarySize::from_str("some").is_err());
+    }
+
+    #[test]
+    fn canary_size_to_string() {
+        assert_eq!(CanarySize::Count(3).to_string(), "3");
+        assert_eq!(CanarySize::Percent(10).to_string(), "10%");
+    }
+
+    #[test]
+    fn canary_size_of_population() {
+        assert_eq!(CanarySize::Count(2).of(5), 2);
+        assert_eq!(CanarySize::Count(8).of(5), 5);
+        assert_eq!(CanarySize::Percent(10).of(5), 1);
+        assert_eq!(CanarySize::Percent(50).of(5), 3);
+        assert_eq!(CanarySize::Percent(100).of(5), 5);
+    }
+
+    #[test]
+    fn service_spec_from_str_invalid_health_check_timeout_status() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            health_check_timeout_status = "sideways"
+            "#;
+
+        match ServiceSpec::from_str(toml) {
+            Err(e) => {
+                match e.err {
+                    ServiceSpecParse(_) => assert!(true),
+                    e => panic!("Unexpected error returned: {:?}", e),
+                }
+            }
+            Ok(_) => panic!("Spec TOML should fail to parse"),
+        }
+    }
+
+    #[test]
+    fn service_spec_health_check_timeout_status_must_not_be_healthy() {
+        let mut spec = ServiceSpec::default();
+        assert!(spec.validate_health_check_timeout_status().is_ok());
+        spec.health_check_timeout_status = HealthCheck::Critical;
+        assert!(spec.validate_health_check_timeout_status().is_ok());
+        spec.health_check_timeout_status = HealthCheck::Ok;
+        match spec.validate_health_check_timeout_status() {
+            Err(e) => {
+                match e.err {
+                    BadHealthCheckTimeoutStatus(ref s) => assert_eq!(s, "ok"),
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("A healthy timeout status should not validate"),
+        }
+    }
+
+    #[test]
+    fn service_spec_resource_limits_must_not_be_zero() {
+        let mut spec = ServiceSpec::default();
+        assert!(spec.validate_resource_limits().is_ok());
+        spec.memory_limit = Some(1024);
+        spec.pids_limit = Some(0);
+        match spec.validate_resource_limits() {
+            Err(e) => {
+                match e.err {
+                    InvalidResourceLimit(ref limit) => assert_eq!(limit, "pids_limit"),
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("A zero resource limit should not validate"),
+        }
+    }
+
+    #[test]
+    fn service_spec_resource_limits_only_on_linux() {
+        let mut spec = ServiceSpec::default();
+        spec.cpu_limit = Some(500);
+        if cfg!(target_os = "linux") {
+            assert!(spec.validate_resource_limits().is_ok());
+        } else {
+            match spec.validate_resource_limits() {
+                Err(e) => {
+                    match e.err {
+                        ResourceLimitsUnsupported => (),
+                        wrong => panic!("Unexpected error returned: {:?}", wrong),
+                    }
+                }
+                Ok(_) => panic!("Resource limits should not validate off Linux"),
+            }
+        }
+    }
+
+    #[test]
+    fn service_spec_hook_timeouts_only_for_hooks_which_finish() {
+        let mut spec = ServiceSpec::default();
+        spec.hook_timeouts.insert("init".to_string(), 60);
+        spec.hook_timeouts.insert("smoke_test".to_string(), 60);
+        spec.hook_timeouts.insert("periodic-backup".to_string(), 600);
+        assert!(spec.validate_hook_timeouts().is_ok());
+        spec.hook_timeouts.insert("periodic-".to_string(), 60);
+        assert!(spec.validate_hook_timeouts().is_err());
+        spec.hook_timeouts.remove("periodic-");
+        spec.hook_timeouts.insert("run".to_string(), 60);
+        match spec.validate_hook_timeouts() {
+            Err(e) => {
+                match e.err {
+                    InvalidHookTimeout(ref hook) => assert_eq!(hook, "run"),
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("A timeout for the run hook should not validate"),
+        }
     }
 
     #[test]
@@ -486,8 +1093,13 @@ mod test {
             ),
             bldr_url: String::from("http://example.com/depot"),
             channel: String::from("unstable"),
+            version_req: Some(VersionReq::from_str("~1.2").unwrap()),
             topology: Topology::Leader,
             update_strategy: UpdateStrategy::AtOnce,
+            canary_size: CanarySize::Count(2),
+            canary_soak: 120,
+            update_verify_window: Some(600),
+            update_window: Some(UpdateWindow::from_str("sat,sun 22:00-06:00").unwrap()),
             binds: vec![
                 ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                 ServiceBind::from_str("db:postgres.app@acmecorp").unwrap(),
@@ -497,6 +1109,25 @@ mod test {
             start_style: StartStyle::Persistent,
             svc_encrypted_password: None,
             composite: None,
+            health_check_interval: 10,
+            health_check_timeout: Some(5),
+            health_check_timeout_status: HealthCheck::Critical,
+            run_mode: RunMode::OneShot,
+            restart_policy: RestartPolicy::OnFailure,
+            max_restarts: Some(5),
+            restart_window: 120,
+            restart_backoff: 2,
+            restart_backoff_max: 30,
+            memory_limit: Some(536870912),
+            cpu_limit: Some(500),
+            pids_limit: Some(64),
+            log_max_size: 1048576,
+            log_max_age: Some(86400),
+            log_keep: 3,
+            wait_for_binds: true,
+            bind_wait_timeout: Some(600),
+            hook_timeouts: vec![("init".to_string(), 60)].into_iter().collect(),
+            health_probe: Some(HealthProbe::Tcp { address: "127.0.0.1:6379".to_string() }),
         };
         let toml = spec.to_toml_string().unwrap();
 
@@ -509,13 +1140,39 @@ mod test {
         ));
         assert!(toml.contains(r#"bldr_url = "http://example.com/depot""#));
         assert!(toml.contains(r#"channel = "unstable""#));
+        assert!(toml.contains(r#"version_req = "~1.2""#));
         assert!(toml.contains(r#"topology = "leader""#));
         assert!(toml.contains(r#"update_strategy = "at-once""#));
+        assert!(toml.contains(r#"canary_size = "2""#));
+        assert!(toml.contains(r#"canary_soak = 120"#));
+        assert!(toml.contains(r#"update_verify_window = 600"#));
+        assert!(toml.contains(r#"update_window = "sat,sun 22:00-06:00""#));
         assert!(toml.contains(r#""cache:redis.cache@acmecorp""#));
         assert!(toml.contains(r#""db:postgres.app@acmecorp""#));
         assert!(toml.contains(r#"desired_state = "down""#));
         assert!(toml.contains(r#"start_style = "persistent""#));
         assert!(toml.contains(r#"config_from = "/only/for/development""#));
+        assert!(toml.contains(r#"health_check_interval = 10"#));
+        assert!(toml.contains(r#"health_check_timeout = 5"#));
+        assert!(toml.contains(r#"health_check_timeout_status = "critical""#));
+        assert!(toml.contains(r#"run_mode = "one-shot""#));
+        assert!(toml.contains(r#"restart_policy = "on-failure""#));
+        assert!(toml.contains(r#"max_restarts = 5"#));
+        assert!(toml.contains(r#"restart_window = 120"#));
+        assert!(toml.contains(r#"restart_backoff = 2"#));
+        assert!(toml.contains(r#"restart_backoff_max = 30"#));
+        assert!(toml.contains(r#"memory_limit = 536870912"#));
+        assert!(toml.contains(r#"cpu_limit = 500"#));
+        assert!(toml.contains(r#"pids_limit = 64"#));
+        assert!(toml.contains(r#"log_max_size = 1048576"#));
+        assert!(toml.contains(r#"log_max_age = 86400"#));
+        assert!(toml.contains(r#"log_keep = 3"#));
+        assert!(toml.contains(r#"wait_for_binds = true"#));
+        assert!(toml.contains(r#"bind_wait_timeout = 600"#));
+        assert!(toml.contains("[hook_timeouts]\ninit = 60"));
+        assert!(toml.contains(
+            "[health_probe]\ntype = \"tcp\"\naddress = \"127.0.0.1:6379\"",
+        ));
     }
 
     #[test]
@@ -645,8 +1302,13 @@ mod test {
             ),
             bldr_url: String::from("http://example.com/depot"),
             channel: String::from("unstable"),
+            version_req: Some(VersionReq::from_str("~1.2").unwrap()),
             topology: Topology::Leader,
             update_strategy: UpdateStrategy::AtOnce,
+            canary_size: CanarySize::Count(2),
+            canary_soak: 120,
+            update_verify_window: Some(600),
+            update_window: Some(UpdateWindow::from_str("sat,sun 22:00-06:00").unwrap()),
             binds: vec![
                 ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                 ServiceBind::from_str("db:postgres.app@acmecorp").unwrap(),
@@ -656,6 +1318,25 @@ mod test {
             start_style: StartStyle::Persistent,
             svc_encrypted_password: None,
             composite: None,
+            health_check_interval: 10,
+            health_check_timeout: Some(5),
+            health_check_timeout_status: HealthCheck::Critical,
+            run_mode: RunMode::OneShot,
+            restart_policy: RestartPolicy::OnFailure,
+            max_restarts: Some(5),
+            restart_window: 120,
+            restart_backoff: 2,
+            restart_backoff_max: 30,
+            memory_limit: Some(536870912),
+            cpu_limit: Some(500),
+            pids_limit: Some(64),
+            log_max_size: 1048576,
+            log_max_age: Some(86400),
+            log_keep: 3,
+            wait_for_binds: true,
+            bind_wait_timeout: Some(600),
+            hook_timeouts: vec![("init".to_string(), 60)].into_iter().collect(),
+            health_probe: Some(HealthProbe::Tcp { address: "127.0.0.1:6379".to_string() }),
         };
         spec.to_file(&path).unwrap();
         let toml = string_from_file(path);
@@ -669,13 +1350,39 @@ mod test {
         ));
         assert!(toml.contains(r#"bldr_url = "http://example.com/depot""#));
         assert!(toml.contains(r#"channel = "unstable""#));
+        assert!(toml.contains(r#"version_req = "~1.2""#));
         assert!(toml.contains(r#"topology = "leader""#));
         assert!(toml.contains(r#"update_strategy = "at-once""#));
+        assert!(toml.contains(r#"canary_size = "2""#));
+        assert!(toml.contains(r#"canary_soak = 120"#));
+        assert!(toml.contains(r#"update_verify_window = 600"#));
+        assert!(toml.contains(r#"update_window = "sat,sun 22:00-06:00""#));
         assert!(toml.contains(r#""cache:redis.cache@acmecorp""#));
         assert!(toml.contains(r#""db:postgres.app@acmecorp""#));
         assert!(toml.contains(r#"desired_state = "down""#));
         assert!(toml.contains(r#"start_style = "persistent""#));
         assert!(toml.contains(r#"config_from = "/only/for/development""#));
+        assert!(toml.contains(r#"health_check_interval = 10"#));
+        assert!(toml.contains(r#"health_check_timeout = 5"#));
+        assert!(toml.contains(r#"health_check_timeout_status = "critical""#));
+        assert!(toml.contains(r#"run_mode = "one-shot""#));
+        assert!(toml.contains(r#"restart_policy = "on-failure""#));
+        assert!(toml.contains(r#"max_restarts = 5"#));
+        assert!(toml.contains(r#"restart_window = 120"#));
+        assert!(toml.contains(r#"restart_backoff = 2"#));
+        assert!(toml.contains(r#"restart_backoff_max = 30"#));
+        assert!(toml.contains(r#"memory_limit = 536870912"#));
+        assert!(toml.contains(r#"cpu_limit = 500"#));
+        assert!(toml.contains(r#"pids_limit = 64"#));
+        assert!(toml.contains(r#"log_max_size = 1048576"#));
+        assert!(toml.contains(r#"log_max_age = 86400"#));
+        assert!(toml.contains(r#"log_keep = 3"#));
+        assert!(toml.contains(r#"wait_for_binds = true"#));
+        assert!(toml.contains(r#"bind_wait_timeout = 600"#));
+        assert!(toml.contains("[hook_timeouts]\ninit = 60"));
+        assert!(toml.contains(
+            "[health_probe]\ntype = \"tcp\"\naddress = \"127.0.0.1:6379\"",
+        ));
     }
 
     #[test]
@@ -697,6 +1404,19 @@ mod test {
         }
     }
 
+    #[test]
+    fn service_spec_create_file_only_when_absent() {
+        let tmpdir = TempDir::new("specs").unwrap();
+        let path = tmpdir.path().join("name.spec");
+        let mut spec = ServiceSpec::default_for(PackageIdent::from_str("origin/name").unwrap());
+        assert!(spec.create_file(&path).unwrap());
+
+        spec.group = String::from("jobs");
+        assert!(!spec.create_file(&path).unwrap());
+        assert_eq!(ServiceSpec::from_file(&path).unwrap().group, "default");
+        assert_eq!(fs::read_dir(tmpdir.path()).unwrap().count(), 1);
+    }
+
     #[test]
     fn service_spec_file_name() {
         let spec = ServiceSpec::default_for(PackageIdent::from_str("origin/hoopa/1.2.3").unwrap());
@@ -16,9 +16,11 @@
 ///
 /// The Supervisor is responsible for running any services we are asked to start. It handles
 /// spawning the new process, watching for failure, and ensuring the service is either up or down.
-/// If the process dies, the Supervisor will restart it.
+/// If the process dies, the Supervisor records how it exited so the service's restart policy can
+/// decide whether to restart it.
 
 use std;
+use std::collections::VecDeque;
 use std::fmt;
 use std::fs::File;
 use std::io::BufReader;
@@ -26,9 +28,12 @@ use std::io::prelude::*;
 use std::path::{Path, PathBuf};
 use hcore::os::process::{self, Pid};
 use std::result;
+use std::time::{Duration, Instant};
 
+use hcore::package::PackageIdent;
 use hcore::service::ServiceGroup;
-use launcher_client::LauncherCli;
+use hcore::util::rotating_log::RotationPolicy;
+use launcher_client::{LauncherCli, ResourceLimits, ResourceUsage};
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
 use time::{self, Timespec};
@@ -36,6 +41,7 @@ use time::{self, Timespec};
 use error::{Result, Error};
 use fs;
 use manager::service::Pkg;
+use manager::service::hooks::{self, RunHook};
 
 static LOGKEY: &'static str = "SV";
 
@@ -43,6 +49,8 @@ static LOGKEY: &'static str = "SV";
 pub enum ProcessState {
     Down,
     Up,
+    Failed,
+    WaitingForBinds,
 }
 
 impl fmt::Display for ProcessState {
@@ -50,6 +58,8 @@ impl fmt::Display for ProcessState {
         let state = match *self {
             ProcessState::Down => "down",
             ProcessState::Up => "up",
+            ProcessState::Failed => "failed",
+            ProcessState::WaitingForBinds => "waiting-for-binds",
         };
         write!(f, "{}", state)
     }
@@ -60,6 +70,11 @@ pub struct Supervisor {
     pub preamble: String,
     pub state: ProcessState,
     pub state_entered: Timespec,
+    /// Exit code of the last process which exited on its own, `None` if it was killed by a
+    /// signal or its exit code could not be retrieved from the Launcher.
+    pub last_exit_code: Option<i32>,
+    /// Resources used by the running process, as last reported by the Launcher.
+    pub resource_usage: Option<ResourceUsage>,
     pid: Option<Pid>,
     pid_file: PathBuf,
 }
@@ -70,13 +85,41 @@ impl Supervisor {
             preamble: service_group.to_string(),
             state: ProcessState::Down,
             state_entered: time::get_time(),
+            last_exit_code: None,
+            resource_usage: None,
             pid: None,
             pid_file: fs::svc_pid_file(service_group.service()),
         }
     }
 
-    /// Check if the child process is running
-    pub fn check_process(&mut self) -> bool {
+    /// Check if the child process is running, recording its exit code if it has exited since
+    /// the last check.
+    pub fn check_process(&mut self, launcher: &LauncherCli) -> bool {
+        if self.find_process() {
+            return true;
+        }
+        debug!("Could not find a live process with pid {:?}", self.pid);
+        if let Some(pid) = self.pid {
+            self.last_exit_code = match launcher.exit_status(pid) {
+                Ok(code) => code,
+                Err(err) => {
+                    debug!("Unable to retrieve exit status of pid {}, {}", pid, err);
+                    None
+                }
+            };
+        }
+        if self.state != ProcessState::Failed {
+            self.change_state(ProcessState::Down);
+        }
+        self.cleanup_pidfile();
+        self.pid = None;
+        self.resource_usage = None;
+        false
+    }
+
+    /// Look for a live process by the PID started last or recorded in the PID file, marking the
+    /// service as up if there is one.
+    fn find_process(&mut self) -> bool {
         let pid = match self.pid {
             Some(pid) => Some(pid),
             None => {
@@ -94,19 +137,33 @@ impl Supervisor {
                 return true;
             }
         }
-        debug!("Could not find a live process with pid {:?}", self.pid);
-        self.change_state(ProcessState::Down);
-        self.cleanup_pidfile();
-        self.pid = None;
         false
     }
 
+    /// Ask the Launcher for the resource usage of the running process.
+    pub fn update_resource_usage(&mut self, launcher: &LauncherCli) {
+        self.resource_usage = match self.pid {
+            Some(pid) => {
+                match launcher.resource_usage(pid) {
+                    Ok(usage) => Some(usage),
+                    Err(err) => {
+                        debug!("Unable to retrieve resource usage of pid {}, {}", pid, err);
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+    }
+
     pub fn start<T>(
         &mut self,
         pkg: &Pkg,
         group: &ServiceGroup,
         launcher: &LauncherCli,
         svc_password: Option<T>,
+        logs: &RotationPolicy,
+        limits: &ResourceLimits,
     ) -> Result<()>
     where
         T: ToString,
@@ -120,6 +177,10 @@ impl Supervisor {
             &pkg.svc_group,
             svc_password,
             (*pkg.env).clone(),
+            hooks::stdout_log_path::<RunHook>(group),
+            hooks::stderr_log_path::<RunHook>(group),
+            logs,
+            limits,
         )?;
         self.pid = Some(pid);
         self.create_pidfile()?;
@@ -136,51 +197,45 @@ impl Supervisor {
         );
         let healthy = match self.state {
             ProcessState::Up => true,
-            ProcessState::Down => false,
+            ProcessState::Down |
+            ProcessState::Failed |
+            ProcessState::WaitingForBinds => false,
         };
         (healthy, status)
     }
 
     pub fn stop(&mut self, launcher: &LauncherCli) -> Result<()> {
-        if self.pid.is_none() {
-            return Ok(());
+        if let Some(pid) = self.pid {
+            launcher.terminate(pid)?;
+            self.cleanup_pidfile();
+            self.pid = None;
         }
-        launcher.terminate(self.pid.unwrap())?;
-        self.cleanup_pidfile();
         self.change_state(ProcessState::Down);
         Ok(())
     }
 
-    pub fn restart<T>(
-        &mut self,
-        pkg: &Pkg,
-        group: &ServiceGroup,
-        launcher: &LauncherCli,
-        svc_password: Option<T>,
-    ) -> Result<()>
-    where
-        T: ToString,
-    {
-        match self.pid {
-            Some(pid) => {
-                match launcher.restart(pid) {
-                    Ok(pid) => {
-                        self.pid = Some(pid);
-                        self.create_pidfile()?;
-                        self.change_state(ProcessState::Up);
-                        Ok(())
-                    }
-                    Err(err) => {
-                        self.cleanup_pidfile();
-                        self.change_state(ProcessState::Down);
-                        Err(sup_error!(Error::Launcher(err)))
-                    }
-                }
+    /// Mark a service whose process is down as held back until its binds are available, or as
+    /// failed once it has waited for them for longer than the timeout. Returns true if it was
+    /// marked as failed.
+    pub fn hold_for_binds(&mut self, waited: Duration, timeout: Option<Duration>) -> bool {
+        match timeout {
+            Some(timeout) if waited >= timeout => {
+                self.fail();
+                true
+            }
+            _ => {
+                self.change_state(ProcessState::WaitingForBinds);
+                false
             }
-            None => self.start(pkg, group, launcher, svc_password),
         }
     }
 
+    /// Mark a service whose process is down as failed. It won't be started again until the
+    /// Supervisor is told to stop or update it.
+    pub fn fail(&mut self) {
+        self.change_state(ProcessState::Failed);
+    }
+
     /// Create a PID file for a running service
     fn create_pidfile(&mut self) -> Result<()> {
         match self.pid {
@@ -228,6 +283,17 @@ impl Serialize for Supervisor {
         let mut strukt = serializer.serialize_struct("supervisor", 5)?;
         strukt.serialize_field("pid", &self.pid)?;
         strukt.serialize_field("state", &self.state)?;
+        strukt.serialize_field("last_exit_code", &self.last_exit_code)?;
+        strukt.serialize_field(
+            "resource_usage",
+            &self.resource_usage.as_ref().map(|usage| {
+                json!({
+                    "memory": usage.memory,
+                    "cpu": usage.cpu,
+                    "pids": usage.pids,
+                })
+            }),
+        )?;
         strukt.serialize_field(
             "state_entered",
             &self.state_entered.sec,
@@ -236,6 +302,75 @@ impl Serialize for Supervisor {
     }
 }
 
+/// Restarts of a service's process which happened within the restart window.
+#[derive(Debug, Default)]
+pub struct RestartHistory(VecDeque<Instant>);
+
+impl RestartHistory {
+    /// Forget restarts which happened more than `window` before `now`, returning the number of
+    /// restarts which remain.
+    pub fn within(&mut self, now: Instant, window: Duration) -> usize {
+        while self.0.front().map_or(
+            false,
+            |at| now > *at && now.duration_since(*at) > window,
+        )
+        {
+            self.0.pop_front();
+        }
+        self.0.len()
+    }
+
+    pub fn record(&mut self, at: Instant) {
+        self.0.push_back(at);
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// How the last run of a one-shot service's process ended.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RunResult {
+    /// The package the process was started from
+    pub ident: PackageIdent,
+    /// Exit code of the process, `None` if it was killed by a signal or its exit code could not
+    /// be retrieved from the Launcher
+    pub exit_code: Option<i32>,
+    pub timestamp: i64,
+}
+
+impl RunResult {
+    pub fn new(ident: PackageIdent, exit_code: Option<i32>) -> Self {
+        RunResult {
+            ident: ident,
+            exit_code: exit_code,
+            timestamp: time::get_time().sec,
+        }
+    }
+
+    pub fn succeeded(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+}
+
+impl fmt::Display for RunResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.exit_code {
+            Some(code) => write!(f, "exited {}", code),
+            None => write!(f, "killed"),
+        }
+    }
+}
+
+/// The time to wait before restarting a process which was already restarted `attempt` times
+/// within the restart window. The wait starts at `backoff` seconds and doubles with every
+/// attempt, up to `backoff_max` seconds.
+pub fn restart_delay(attempt: usize, backoff: u64, backoff_max: u64) -> Duration {
+    let factor = 1u64.checked_shl(attempt as u32).unwrap_or(u64::max_value());
+    Duration::from_secs(backoff.saturating_mul(factor).min(backoff_max))
+}
+
 fn read_pid<T>(pid_file: T) -> Result<Pid>
 where
     T: AsRef<Path>,
@@ -262,3 +397,78 @@ where
         )),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+    use std::time::{Duration, Instant};
+
+    use super::*;
+
+    #[test]
+    fn restart_delay_doubles_up_to_max() {
+        assert_eq!(restart_delay(0, 1, 60), Duration::from_secs(1));
+        assert_eq!(restart_delay(1, 1, 60), Duration::from_secs(2));
+        assert_eq!(restart_delay(3, 1, 60), Duration::from_secs(8));
+        assert_eq!(restart_delay(6, 1, 60), Duration::from_secs(60));
+        assert_eq!(restart_delay(200, 1, 60), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn restart_delay_without_backoff() {
+        assert_eq!(restart_delay(0, 0, 60), Duration::from_secs(0));
+        assert_eq!(restart_delay(10, 0, 60), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn restart_history_forgets_restarts_outside_window() {
+        let start = Instant::now();
+        let mut history = RestartHistory::default();
+        history.record(start);
+        history.record(start + Duration::from_secs(30));
+        history.record(start + Duration::from_secs(90));
+        let window = Duration::from_secs(60);
+        assert_eq!(history.within(start + Duration::from_secs(60), window), 3);
+        assert_eq!(history.within(start + Duration::from_secs(100), window), 2);
+        assert_eq!(history.within(start + Duration::from_secs(200), window), 0);
+    }
+
+    #[test]
+    fn held_for_binds_until_up_or_timed_out() {
+        let group = ServiceGroup::new(None, "redis", "default", None).unwrap();
+        let timeout = Some(Duration::from_secs(60));
+
+        let mut supervisor = Supervisor::new(&group);
+        assert!(!supervisor.hold_for_binds(Duration::from_secs(0), timeout));
+        assert_eq!(supervisor.state, ProcessState::WaitingForBinds);
+        assert!(!supervisor.hold_for_binds(Duration::from_secs(59), timeout));
+        assert_eq!(supervisor.state, ProcessState::WaitingForBinds);
+        // Once the binds are there the process is started, which is what marks it as up.
+        supervisor.pid = Some(process::current_pid());
+        assert!(supervisor.find_process());
+        assert_eq!(supervisor.state, ProcessState::Up);
+
+        let mut supervisor = Supervisor::new(&group);
+        assert!(!supervisor.hold_for_binds(Duration::from_secs(30), timeout));
+        assert!(supervisor.hold_for_binds(Duration::from_secs(60), timeout));
+        assert_eq!(supervisor.state, ProcessState::Failed);
+
+        let mut supervisor = Supervisor::new(&group);
+        assert!(!supervisor.hold_for_binds(Duration::from_secs(86_400), None));
+        assert_eq!(supervisor.state, ProcessState::WaitingForBinds);
+    }
+
+    #[test]
+    fn run_result_succeeds_only_on_zero_exit_code() {
+        let ident = PackageIdent::from_str("core/redis").unwrap();
+        let result = RunResult::new(ident.clone(), Some(0));
+        assert!(result.succeeded());
+        assert_eq!(result.to_string(), "exited 0");
+        let result = RunResult::new(ident.clone(), Some(3));
+        assert!(!result.succeeded());
+        assert_eq!(result.to_string(), "exited 3");
+        let result = RunResult::new(ident, None);
+        assert!(!result.succeeded());
+        assert_eq!(result.to_string(), "killed");
+    }
+}
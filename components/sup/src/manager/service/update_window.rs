@@ -0,0 +1,303 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Maintenance windows during which the updater may apply updates to a service.
+//!
+//! A window is one or more ranges separated by `;`. Each range is an optional list of days, a
+//! time range and an optional timezone, for example `mon-fri 02:00-04:00 UTC` or
+//! `sat,sun 22:00-06:00 +01:00; wed 12:00-13:00 local`. A range without days applies to every
+//! day, a range without a timezone is in UTC, and a range which ends before it starts runs past
+//! midnight into the next day.
+
+use std::fmt;
+use std::result;
+use std::str::FromStr;
+
+use hcore::util::deserialize_using_from_str;
+use serde;
+use time::{self, Duration, Timespec, Tm};
+
+use error::{Error, SupError};
+
+static LOGKEY: &'static str = "UW";
+static DAYS: [&'static str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpdateWindow {
+    source: String,
+    ranges: Vec<TimeRange>,
+}
+
+impl UpdateWindow {
+    /// Whether updates may be applied right now.
+    pub fn is_open(&self) -> bool {
+        self.is_open_at(time::get_time())
+    }
+
+    /// Whether updates may be applied at the given time.
+    pub fn is_open_at(&self, at: Timespec) -> bool {
+        self.ranges.iter().any(|range| range.contains(&range.tz.tm_at(at)))
+    }
+}
+
+impl fmt::Display for UpdateWindow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+impl FromStr for UpdateWindow {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        let ranges = value
+            .split(';')
+            .map(|range| TimeRange::parse(range.trim()))
+            .collect::<result::Result<Vec<TimeRange>, ()>>();
+        match ranges {
+            Ok(ranges) => {
+                Ok(UpdateWindow {
+                    source: value.trim().to_string(),
+                    ranges: ranges,
+                })
+            }
+            Err(()) => Err(sup_error!(Error::BadUpdateWindow(value.to_string()))),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for UpdateWindow {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for UpdateWindow {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Timezone {
+    Local,
+    /// Seconds east of UTC
+    Offset(i64),
+}
+
+impl Timezone {
+    fn tm_at(&self, at: Timespec) -> Tm {
+        match *self {
+            Timezone::Local => time::at(at),
+            Timezone::Offset(offset) => time::at_utc(at + Duration::seconds(offset)),
+        }
+    }
+
+    fn parse(value: &str) -> result::Result<Self, ()> {
+        match value.to_lowercase().as_ref() {
+            "utc" => return Ok(Timezone::Offset(0)),
+            "local" => return Ok(Timezone::Local),
+            _ => (),
+        }
+        let sign = match value.chars().next() {
+            Some('+') => 1,
+            Some('-') => -1,
+            _ => return Err(()),
+        };
+        let minutes = parse_time(&value[1..])?;
+        if minutes >= MINUTES_PER_DAY {
+            return Err(());
+        }
+        Ok(Timezone::Offset(sign * minutes as i64 * 60))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct TimeRange {
+    /// Days the range starts on, indexed from Sunday
+    days: [bool; 7],
+    /// Minutes after midnight
+    start: u32,
+    end: u32,
+    tz: Timezone,
+}
+
+impl TimeRange {
+    fn contains(&self, tm: &Tm) -> bool {
+        let day = tm.tm_wday as usize;
+        let minute = (tm.tm_hour * 60 + tm.tm_min) as u32;
+        if self.start < self.end {
+            self.days[day] && self.start <= minute && minute < self.end
+        } else {
+            (self.days[day] && minute >= self.start) ||
+                (self.days[(day + 6) % 7] && minute < self.end)
+        }
+    }
+
+    fn parse(value: &str) -> result::Result<Self, ()> {
+        let parts: Vec<&str> = value.split_whitespace().collect();
+        let (days, times, tz) = match parts.len() {
+            1 => (None, parts[0], None),
+            2 if parts[0].contains(':') => (None, parts[0], Some(parts[1])),
+            2 => (Some(parts[0]), parts[1], None),
+            3 => (Some(parts[0]), parts[1], Some(parts[2])),
+            _ => return Err(()),
+        };
+        let days = match days {
+            Some(days) => parse_days(days)?,
+            None => [true; 7],
+        };
+        let mut times = times.splitn(2, '-');
+        let start = parse_time(times.next().ok_or(())?)?;
+        let end = parse_time(times.next().ok_or(())?)?;
+        if start >= MINUTES_PER_DAY || end > MINUTES_PER_DAY || start == end {
+            return Err(());
+        }
+        let tz = match tz {
+            Some(tz) => Timezone::parse(tz)?,
+            None => Timezone::Offset(0),
+        };
+        Ok(TimeRange {
+            days: days,
+            start: start,
+            end: end % MINUTES_PER_DAY,
+            tz: tz,
+        })
+    }
+}
+
+/// Parse a list of days and day ranges such as `mon,wed-fri`.
+fn parse_days(value: &str) -> result::Result<[bool; 7], ()> {
+    let mut days = [false; 7];
+    for item in value.split(',') {
+        let mut bounds = item.splitn(2, '-');
+        let first = parse_day(bounds.next().ok_or(())?)?;
+        let last = match bounds.next() {
+            Some(last) => parse_day(last)?,
+            None => first,
+        };
+        let mut day = first;
+        loop {
+            days[day] = true;
+            if day == last {
+                break;
+            }
+            day = (day + 1) % 7;
+        }
+    }
+    Ok(days)
+}
+
+fn parse_day(value: &str) -> result::Result<usize, ()> {
+    let value = value.to_lowercase();
+    DAYS.iter().position(|day| value.starts_with(day)).ok_or(())
+}
+
+/// Parse `HH:MM` into minutes after midnight.
+fn parse_time(value: &str) -> result::Result<u32, ()> {
+    let mut parts = value.splitn(2, ':');
+    let hours: u32 = parts.next().ok_or(())?.parse().map_err(|_| ())?;
+    let minutes: u32 = parts.next().ok_or(())?.parse().map_err(|_| ())?;
+    if hours > 24 || minutes >= 60 {
+        return Err(());
+    }
+    Ok(hours * 60 + minutes)
+}
+
+#[cfg(test)]
+mod test {
+    use time::{self, Timespec};
+
+    use super::*;
+
+    // 2017-06-05 was a Monday
+    fn at(day: i32, hour: i32, min: i32) -> Timespec {
+        let mut tm = time::empty_tm();
+        tm.tm_year = 117;
+        tm.tm_mon = 5;
+        tm.tm_mday = 5 + day;
+        tm.tm_hour = hour;
+        tm.tm_min = min;
+        tm.to_timespec()
+    }
+
+    #[test]
+    fn update_window_weekdays() {
+        let window = UpdateWindow::from_str("mon-fri 02:00-04:00 UTC").unwrap();
+        assert!(window.is_open_at(at(0, 2, 0)));
+        assert!(window.is_open_at(at(4, 3, 59)));
+        assert!(!window.is_open_at(at(0, 4, 0)));
+        assert!(!window.is_open_at(at(5, 3, 0)));
+    }
+
+    #[test]
+    fn update_window_past_midnight() {
+        let window = UpdateWindow::from_str("sat,sun 22:00-06:00").unwrap();
+        assert!(window.is_open_at(at(5, 23, 0)));
+        assert!(window.is_open_at(at(6, 5, 0)));
+        assert!(window.is_open_at(at(7, 5, 0)));
+        assert!(!window.is_open_at(at(7, 6, 0)));
+        assert!(!window.is_open_at(at(5, 5, 0)));
+    }
+
+    #[test]
+    fn update_window_offset() {
+        let window = UpdateWindow::from_str("mon 02:00-04:00 +02:00").unwrap();
+        assert!(window.is_open_at(at(0, 0, 30)));
+        assert!(!window.is_open_at(at(0, 2, 30)));
+
+        let window = UpdateWindow::from_str("mon 22:00-24:00 -05:00").unwrap();
+        assert!(window.is_open_at(at(1, 3, 0)));
+    }
+
+    #[test]
+    fn update_window_multiple_ranges() {
+        let window = UpdateWindow::from_str("wed 12:00-13:00; 02:00-03:00").unwrap();
+        assert!(window.is_open_at(at(2, 12, 30)));
+        assert!(window.is_open_at(at(4, 2, 30)));
+        assert!(!window.is_open_at(at(4, 12, 30)));
+        assert_eq!(window.to_string(), "wed 12:00-13:00; 02:00-03:00");
+    }
+
+    #[test]
+    fn update_window_invalid() {
+        for value in &[
+            "",
+            "02:00",
+            "02:00-02:00",
+            "25:00-26:00",
+            "someday 02:00-04:00",
+            "mon 02:00-04:00 mars",
+            "mon 02:00-04:00 UTC extra",
+        ]
+        {
+            match UpdateWindow::from_str(value) {
+                Err(e) => {
+                    match e.err {
+                        Error::BadUpdateWindow(_) => (),
+                        wrong => panic!("Unexpected error returned: {:?}", wrong),
+                    }
+                }
+                Ok(_) => panic!("'{}' should fail to parse", value),
+            }
+        }
+    }
+}
@@ -15,17 +15,18 @@
 use std::collections::HashMap;
 use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TryRecvError};
 use std::thread;
+use std::time::{Duration, Instant};
 
 use butterfly;
 use common::ui::UI;
 use env;
-use hcore::package::{PackageIdent, PackageInstall};
+use hcore::package::{PackageIdent, PackageInstall, VersionReq};
 use hcore::service::ServiceGroup;
 use launcher_client::LauncherCli;
 
-use census::CensusRing;
+use census::{CensusMember, CensusRing};
 use manager::periodic::Periodic;
-use manager::service::{Service, Topology, UpdateStrategy};
+use manager::service::{HealthCheck, Service, Topology, UpdateStrategy};
 use util;
 
 static LOGKEY: &'static str = "SU";
@@ -56,6 +57,9 @@ enum LeaderState {
 enum FollowerState {
     /// Waiting to be told to update
     Waiting,
+    /// Waiting for the canaries of a canary update to stay healthy for the soak period, which
+    /// started at the given instant
+    Soaking(Instant),
     /// Currently updating
     Updating(Receiver<PackageInstall>),
 }
@@ -88,7 +92,8 @@ impl ServiceUpdater {
                     });
                 true
             }
-            UpdateStrategy::Rolling => {
+            UpdateStrategy::Rolling |
+            UpdateStrategy::Canary => {
                 self.states.entry(service.service_group.clone()).or_insert(
                     UpdaterState::Rolling(RollingState::AwaitingElection),
                 );
@@ -103,20 +108,24 @@ impl ServiceUpdater {
     /// See if the given service has an update. Returns `true` if a
     /// new version was installed, thus signalling that the service
     /// should be restarted
+    ///
+    /// An update found outside of the service's update window is held
+    /// by the service, and nothing else is checked until it is applied.
     pub fn check_for_updated_package(
         &mut self,
         service: &mut Service,
         census_ring: &CensusRing,
         launcher: &LauncherCli,
     ) -> bool {
+        if service.has_pending_update() {
+            return service.apply_pending_update(launcher);
+        }
         let mut updated = false;
+        let mut received = false;
         match self.states.get_mut(&service.service_group) {
             Some(&mut UpdaterState::AtOnce(ref mut rx)) => {
                 match rx.try_recv() {
-                    Ok(package) => {
-                        service.update_package(package, launcher);
-                        return true;
-                    }
+                    Ok(package) => return service.offer_update(package, launcher),
                     Err(TryRecvError::Empty) => return false,
                     Err(TryRecvError::Disconnected) => {
                         debug!("Service Updater worker has died; restarting...");
@@ -184,8 +193,8 @@ impl ServiceUpdater {
                         match rx.try_recv() {
                             Ok(package) => {
                                 debug!("Rolling Update, polling found a new package");
-                                service.update_package(package, launcher);
-                                updated = true;
+                                updated = service.offer_update(package, launcher);
+                                received = true;
                             }
                             Err(TryRecvError::Empty) => return false,
                             Err(TryRecvError::Disconnected) => {
@@ -217,7 +226,7 @@ impl ServiceUpdater {
                         }
                     }
                 }
-                if updated {
+                if received {
                     *state = LeaderState::Waiting;
                 }
             }
@@ -236,7 +245,37 @@ impl ServiceUpdater {
                                             debug!("We're not in an update");
                                             return false;
                                         }
-                                        if leader.pkg != peer.pkg {
+                                        if leader.pkg.as_ref().map_or(
+                                            false,
+                                            |pkg| service.is_blocked(pkg),
+                                        )
+                                        {
+                                            debug!("We're in an update to a blocked release");
+                                            return false;
+                                        }
+                                        if service.update_strategy == UpdateStrategy::Canary {
+                                            // Canaries update straight after the leader, everyone
+                                            // else once the canaries have soaked.
+                                            let canaries =
+                                                census_group.update_canaries(service.canary_size);
+                                            if !canaries.iter().any(
+                                                |cm| cm.member_id == me.member_id,
+                                            )
+                                            {
+                                                if canaries_healthy(&canaries, &leader.pkg) {
+                                                    outputln!(
+                                                        "Canaries of {} are healthy, soaking for \
+                                                         {} seconds",
+                                                        service.service_group,
+                                                        service.canary_soak
+                                                    );
+                                                    *state = FollowerState::Soaking(Instant::now());
+                                                } else {
+                                                    debug!("We're waiting for canaries to update");
+                                                }
+                                                return false;
+                                            }
+                                        } else if leader.pkg != peer.pkg {
                                             debug!("We're in an update but it's not our turn");
                                             return false;
                                         }
@@ -258,13 +297,55 @@ impl ServiceUpdater {
                             }
                         }
                     }
+                    FollowerState::Soaking(since) => {
+                        match census_ring.census_group_for(&service.service_group) {
+                            Some(census_group) => {
+                                match (census_group.update_leader(), census_group.me()) {
+                                    (Some(leader), Some(me)) => {
+                                        if leader.pkg == me.pkg {
+                                            *state = FollowerState::Waiting;
+                                            return false;
+                                        }
+                                        let canaries =
+                                            census_group.update_canaries(service.canary_size);
+                                        if !canaries_healthy(&canaries, &leader.pkg) {
+                                            outputln!(
+                                                "Canaries of {} are no longer healthy, holding \
+                                                 the update",
+                                                service.service_group
+                                            );
+                                            *state = FollowerState::Waiting;
+                                            return false;
+                                        }
+                                        let soak = Duration::from_secs(service.canary_soak);
+                                        if since.elapsed() < soak {
+                                            return false;
+                                        }
+                                        debug!("Canaries have soaked, it's our turn");
+                                        let rx = Worker::new(service).start(
+                                            &service.service_group,
+                                            leader.pkg.clone(),
+                                        );
+                                        *state = FollowerState::Updating(rx);
+                                    }
+                                    _ => return false,
+                                }
+                            }
+                            None => {
+                                panic!(
+                                    "Expected census list to have service group '{}'!",
+                                    &*service.service_group
+                                )
+                            }
+                        }
+                    }
                     FollowerState::Updating(ref mut rx) => {
                         match census_ring.census_group_for(&service.service_group) {
                             Some(census_group) => {
                                 match rx.try_recv() {
                                     Ok(package) => {
-                                        service.update_package(package, launcher);
-                                        updated = true
+                                        updated = service.offer_update(package, launcher);
+                                        received = true;
                                     }
                                     Err(TryRecvError::Empty) => return false,
                                     Err(TryRecvError::Disconnected) => {
@@ -287,7 +368,7 @@ impl ServiceUpdater {
                         }
                     }
                 }
-                if updated {
+                if received {
                     *state = FollowerState::Waiting;
                 }
             }
@@ -297,9 +378,20 @@ impl ServiceUpdater {
     }
 }
 
+/// Whether there are canaries and every one of them runs the given package and passed its last
+/// health check.
+fn canaries_healthy(canaries: &[&CensusMember], pkg: &Option<PackageIdent>) -> bool {
+    !canaries.is_empty() &&
+        canaries.iter().all(|cm| {
+            &cm.pkg == pkg && cm.health == Some(HealthCheck::Ok)
+        })
+}
+
 struct Worker {
     current: PackageIdent,
+    blocked: Vec<PackageIdent>,
     spec_ident: PackageIdent,
+    version_req: Option<VersionReq>,
     builder_url: String,
     channel: String,
 }
@@ -333,7 +425,9 @@ impl Worker {
     fn new(service: &Service) -> Self {
         Worker {
             current: service.pkg.ident.clone(),
+            blocked: service.blocked_releases.idents(),
             spec_ident: service.spec_ident.clone(),
+            version_req: service.version_req.clone(),
             builder_url: service.bldr_url.clone(),
             channel: service.channel.clone(),
         }
@@ -407,21 +501,25 @@ impl Worker {
     }
 
     /// Continually poll for a new version of a package, installing it
-    /// when found.
+    /// when found. Only versions satisfying the service's version
+    /// requirement are considered.
     fn run_poll(&mut self, sender: SyncSender<PackageInstall>) {
         let install_source = self.spec_ident.clone().into(); // UGH clone
         loop {
             let next_time = self.next_period_start();
 
-            match util::pkg::install(
+            match util::pkg::install_matching(
                 // We don't want anything in here to print
                 &mut UI::with_sinks(),
                 &self.builder_url,
                 &install_source,
+                self.version_req.as_ref(),
                 &self.channel,
             ) {
                 Ok(maybe_newer_package) => {
-                    if self.current < *maybe_newer_package.ident() {
+                    if self.blocked.contains(maybe_newer_package.ident()) {
+                        debug!("Package found is blocked for this service");
+                    } else if self.current < *maybe_newer_package.ident() {
                         outputln!(
                             "Updating from {} to {}",
                             self.current,
@@ -17,6 +17,8 @@ use std::os::unix::process::CommandExt;
 use std::process::{Child, Command, Stdio};
 
 use hcore::os;
+use hcore::os::process::{self, Pid, Signal};
+use libc;
 
 use error::{Error, Result};
 use manager::service::Pkg;
@@ -28,7 +30,27 @@ where
     T: ToString,
     S: AsRef<OsStr>,
 {
-    let mut cmd = Command::new(path);
+    spawn(Command::new(path), pkg)
+}
+
+/// Run a command line through the shell as the service's user.
+pub fn run_command<T>(command: &str, pkg: &Pkg, _: Option<T>) -> Result<Child>
+where
+    T: ToString,
+{
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(command);
+    spawn(cmd, pkg)
+}
+
+/// Forcefully kill a process started by `run` along with any processes it started in turn.
+pub fn kill_tree(pid: u32) -> Result<()> {
+    // Signalling the negated pid reaches every process in the group `spawn` placed the child in.
+    process::signal(-(pid as Pid), Signal::KILL)?;
+    Ok(())
+}
+
+fn spawn(mut cmd: Command, pkg: &Pkg) -> Result<Child> {
     let uid = os::users::get_uid_by_name(&pkg.svc_user).ok_or(sup_error!(
         Error::Permissions(format!(
             "No uid for user '{}' could be found",
@@ -51,5 +73,13 @@ where
     for (key, val) in pkg.env.iter() {
         cmd.env(key, val);
     }
+    // Start the child in a new process group so that it can be killed along with everything
+    // it has started by `kill_tree`.
+    unsafe {
+        cmd.before_exec(|| {
+            libc::setpgid(0, 0);
+            Ok(())
+        });
+    }
     Ok(cmd.spawn()?)
 }
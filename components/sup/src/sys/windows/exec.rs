@@ -13,19 +13,30 @@
 // limitations under the License.
 
 use std::ffi::OsStr;
+use std::process::Command;
 
 use hcore::os::process::windows_child::Child;
 
-use error::Result;
+use error::{Error, Result};
 use manager::service::Pkg;
 
+static LOGKEY: &'static str = "EX";
+
 pub fn run<T, S>(path: S, pkg: &Pkg, svc_encrypted_password: Option<T>) -> Result<Child>
 where
     T: ToString,
     S: AsRef<OsStr>,
 {
     let ps_cmd = format!("iex $(gc {} | out-string)", path.as_ref().to_string_lossy());
-    let args = vec!["-NonInteractive", "-command", ps_cmd.as_str()];
+    run_command(&ps_cmd, pkg, svc_encrypted_password)
+}
+
+/// Run a command line through PowerShell as the service's user.
+pub fn run_command<T>(command: &str, pkg: &Pkg, svc_encrypted_password: Option<T>) -> Result<Child>
+where
+    T: ToString,
+{
+    let args = vec!["-NonInteractive", "-command", command];
     Ok(Child::spawn(
         "powershell.exe",
         args,
@@ -34,3 +45,18 @@ where
         svc_encrypted_password,
     )?)
 }
+
+/// Forcefully kill a process started by `run` along with any processes it started in turn.
+pub fn kill_tree(pid: u32) -> Result<()> {
+    let output = Command::new("taskkill")
+        .args(&["/F", "/T", "/PID", &pid.to_string()])
+        .output()?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(sup_error!(Error::KillTreeFailed(
+            pid,
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+        )))
+    }
+}
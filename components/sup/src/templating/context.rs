@@ -18,7 +18,7 @@ use hcore::service::ServiceGroup;
 
 use census::{CensusGroup, CensusMember, CensusRing, ElectionStatus};
 use manager::Sys;
-use manager::service::{Cfg, Pkg, ServiceBind};
+use manager::service::{Cfg, Pkg, RenderCfg, ServiceBind};
 
 #[derive(Clone, Debug, Serialize)]
 pub struct Binds<'a>(HashMap<String, BindGroup<'a>>);
@@ -57,7 +57,7 @@ impl<'a> BindGroup<'a> {
 pub struct RenderContext<'a> {
     pub sys: &'a Sys,
     pub pkg: &'a Pkg,
-    pub cfg: &'a Cfg,
+    pub cfg: RenderCfg<'a>,
     pub svc: Svc<'a>,
     pub bind: Binds<'a>,
 }
@@ -80,7 +80,7 @@ impl<'a> RenderContext<'a> {
         RenderContext {
             sys: sys,
             pkg: pkg,
-            cfg: cfg,
+            cfg: RenderCfg::new(cfg),
             svc: Svc::new(census_group),
             bind: Binds::new(bindings, census),
         }
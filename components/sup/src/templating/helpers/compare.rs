@@ -0,0 +1,145 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use handlebars::{Handlebars, Helper, HelperDef, Renderable, RenderContext, RenderError};
+
+use super::super::RenderResult;
+use super::compare_json;
+
+#[derive(Clone, Copy)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// Compares two values. Used as a block, like `if`, it renders its template when the comparison
+/// holds and its `else` template otherwise. Used inline, it writes `true` or `false`.
+#[derive(Clone, Copy)]
+pub struct CompareHelper(CompareOp);
+
+impl CompareHelper {
+    fn name(&self) -> &'static str {
+        match self.0 {
+            CompareOp::Eq => "eq",
+            CompareOp::Ne => "ne",
+            CompareOp::Lt => "lt",
+            CompareOp::Le => "le",
+            CompareOp::Gt => "gt",
+            CompareOp::Ge => "ge",
+        }
+    }
+
+    fn holds(&self, ordering: Option<Ordering>) -> bool {
+        match (self.0, ordering) {
+            (CompareOp::Eq, Some(o)) => o == Ordering::Equal,
+            (CompareOp::Ne, Some(o)) => o != Ordering::Equal,
+            (CompareOp::Ne, None) => true,
+            (CompareOp::Lt, Some(o)) => o == Ordering::Less,
+            (CompareOp::Le, Some(o)) => o != Ordering::Greater,
+            (CompareOp::Gt, Some(o)) => o == Ordering::Greater,
+            (CompareOp::Ge, Some(o)) => o != Ordering::Less,
+            (_, None) => false,
+        }
+    }
+}
+
+impl HelperDef for CompareHelper {
+    fn call(&self, h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult<()> {
+        let (lhs, rhs) = match (h.param(0), h.param(1)) {
+            (Some(lhs), Some(rhs)) => (lhs.value(), rhs.value()),
+            _ => {
+                return Err(RenderError::new(
+                    format!("Expected 2 parameters for \"{}\"", self.name()),
+                ))
+            }
+        };
+        let holds = self.holds(compare_json(lhs, rhs));
+        match h.template() {
+            Some(template) => {
+                if holds {
+                    template.render(r, rc)?;
+                } else if let Some(else_template) = h.inverse() {
+                    else_template.render(r, rc)?;
+                }
+            }
+            None => rc.writer.write(holds.to_string().into_bytes().as_ref())?,
+        }
+        Ok(())
+    }
+}
+
+pub static EQ: CompareHelper = CompareHelper(CompareOp::Eq);
+pub static NE: CompareHelper = CompareHelper(CompareOp::Ne);
+pub static LT: CompareHelper = CompareHelper(CompareOp::Lt);
+pub static LE: CompareHelper = CompareHelper(CompareOp::Le);
+pub static GT: CompareHelper = CompareHelper(CompareOp::Gt);
+pub static GE: CompareHelper = CompareHelper(CompareOp::Ge);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn render(template: &str) -> String {
+        let json = json!({
+            "cfg": {
+                "workers": 4,
+                "mode": "cluster",
+                "port": "8080"
+            }
+        });
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("eq", Box::new(EQ));
+        handlebars.register_helper("ne", Box::new(NE));
+        handlebars.register_helper("lt", Box::new(LT));
+        handlebars.register_helper("le", Box::new(LE));
+        handlebars.register_helper("gt", Box::new(GT));
+        handlebars.register_helper("ge", Box::new(GE));
+        handlebars.template_render(template, &json).unwrap()
+    }
+
+    #[test]
+    fn test_compare_helpers_inline() {
+        assert_eq!("true", render("{{eq cfg.mode \"cluster\"}}"));
+        assert_eq!("false", render("{{ne cfg.mode \"cluster\"}}"));
+        assert_eq!("true", render("{{lt cfg.workers 10}}"));
+        assert_eq!("true", render("{{le cfg.workers 4}}"));
+        assert_eq!("false", render("{{gt cfg.workers 4}}"));
+        assert_eq!("true", render("{{ge cfg.port 1024}}"));
+    }
+
+    #[test]
+    fn test_compare_helpers_block() {
+        assert_eq!(
+            "many",
+            render("{{#gt cfg.workers 1}}many{{else}}one{{/gt}}")
+        );
+        assert_eq!(
+            "standalone",
+            render("{{#eq cfg.mode \"standalone\"}}cluster{{else}}standalone{{/eq}}")
+        );
+    }
+
+    #[test]
+    fn test_compare_helpers_with_unordered_values() {
+        assert_eq!("false", render("{{lt cfg.mode 10}}"));
+        assert_eq!("true", render("{{ne cfg.mode 10}}"));
+        assert_eq!("false", render("{{eq cfg.missing 10}}"));
+    }
+}
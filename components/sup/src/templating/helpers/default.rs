@@ -0,0 +1,67 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use handlebars::{Handlebars, Helper, HelperDef, RenderContext, RenderError};
+use serde_json::Value as Json;
+
+use super::super::RenderResult;
+use super::json_to_string;
+
+#[derive(Clone, Copy)]
+pub struct DefaultHelper;
+
+impl HelperDef for DefaultHelper {
+    fn call(&self, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult<()> {
+        let (value, fallback) = match (h.param(0), h.param(1)) {
+            (Some(value), Some(fallback)) => (value.value(), fallback.value()),
+            _ => return Err(RenderError::new("Expected 2 parameters for \"default\"")),
+        };
+        let rendered = match *value {
+            Json::Null => json_to_string(fallback),
+            ref value => json_to_string(value),
+        };
+        rc.writer.write(rendered.into_bytes().as_ref())?;
+        Ok(())
+    }
+}
+
+pub static DEFAULT: DefaultHelper = DefaultHelper;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_default_helper() {
+        let json = json!({
+            "cfg": {
+                "bind": "0.0.0.0",
+                "workers": 0,
+                "unset": null
+            }
+        });
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("default", Box::new(DEFAULT));
+        assert_eq!(
+            "0.0.0.0 0 127.0.0.1 8080",
+            handlebars
+                .template_render(
+                    "{{default cfg.bind \"127.0.0.1\"}} {{default cfg.workers 4}} \
+                     {{default cfg.unset \"127.0.0.1\"}} {{default cfg.port 8080}}",
+                    &json,
+                )
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,199 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use handlebars::{Handlebars, Helper, HelperDef, Renderable, RenderContext, RenderError};
+use serde_json::Value as Json;
+
+use super::super::RenderResult;
+use super::{json_to_f64, json_to_string, to_json};
+
+/// Iterates over a list, such as the members of a service group, in the order of the value found
+/// at the given dot-separated key of each item. Items missing the key come last.
+#[derive(Clone, Copy)]
+pub struct EachSortedByHelper;
+
+impl HelperDef for EachSortedByHelper {
+    fn call(&self, h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult<()> {
+        let list = h.param(0).and_then(|v| v.value().as_array()).ok_or_else(|| {
+            RenderError::new("Expected a list parameter for \"eachSortedBy\"")
+        })?;
+        let key = h.param(1).and_then(|v| v.value().as_str()).ok_or_else(|| {
+            RenderError::new("Expected a key parameter for \"eachSortedBy\"")
+        })?;
+        let template = match h.template() {
+            Some(template) => template,
+            None => return Ok(()),
+        };
+        if list.is_empty() {
+            if let Some(else_template) = h.inverse() {
+                else_template.render(r, rc)?;
+            }
+            return Ok(());
+        }
+
+        let mut sorted: Vec<&Json> = list.iter().collect();
+        sorted.sort_by(|a, b| compare_items(lookup(a, key), lookup(b, key)));
+
+        rc.promote_local_vars();
+        let len = sorted.len();
+        for (i, item) in sorted.into_iter().enumerate() {
+            let mut local_rc = rc.derive();
+            local_rc.set_local_var("@first".to_string(), to_json(&(i == 0usize)));
+            local_rc.set_local_var("@last".to_string(), to_json(&(i == len - 1)));
+            local_rc.set_local_var("@index".to_string(), to_json(&i));
+
+            if let Some(block_param) = h.block_param() {
+                let mut map = BTreeMap::new();
+                map.insert(block_param.to_string(), item.clone());
+                local_rc.push_block_context(&map);
+            }
+
+            template.render(r, &mut local_rc)?;
+
+            if h.block_param().is_some() {
+                local_rc.pop_block_context();
+            }
+        }
+        rc.demote_local_vars();
+        Ok(())
+    }
+}
+
+pub static EACH_SORTED_BY: EachSortedByHelper = EachSortedByHelper;
+
+/// Returns the value at a dot-separated key of the given item, if there is one.
+fn lookup<'a>(item: &'a Json, key: &str) -> Option<&'a Json> {
+    key.split('.').fold(Some(item), |value, part| {
+        value.and_then(|v| v.get(part))
+    })
+}
+
+/// Orders items by their values: numbers first in numeric order, then strings in lexicographic
+/// order, then any other values by their JSON text, and lastly items missing the value. Keeping
+/// each kind in its own rank makes this a total order, which `sort_by` relies on.
+fn compare_items(a: Option<&Json>, b: Option<&Json>) -> Ordering {
+    match (a.map(rank), b.map(rank)) {
+        (Some(Rank::Number(a)), Some(Rank::Number(b))) => a.partial_cmp(&b).unwrap(),
+        (Some(Rank::String(a)), Some(Rank::String(b))) => a.cmp(b),
+        (Some(Rank::Other(a)), Some(Rank::Other(b))) => a.cmp(&b),
+        (Some(a), Some(b)) => a.ordinal().cmp(&b.ordinal()),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+enum Rank<'a> {
+    Number(f64),
+    String(&'a str),
+    Other(String),
+}
+
+impl<'a> Rank<'a> {
+    fn ordinal(&self) -> u8 {
+        match *self {
+            Rank::Number(_) => 0,
+            Rank::String(_) => 1,
+            Rank::Other(_) => 2,
+        }
+    }
+}
+
+fn rank(value: &Json) -> Rank {
+    match (json_to_f64(value), value) {
+        (Some(n), _) if !n.is_nan() => Rank::Number(n),
+        (_, &Json::String(ref s)) => Rank::String(s),
+        (_, other) => Rank::Other(json_to_string(other)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn render(template: &str) -> String {
+        let json = json!({
+            "members": [
+                { "member_id": "c", "sys": { "hostname": "web-2" }, "cfg": { "weight": 10 } },
+                { "member_id": "a", "sys": { "hostname": "web-3" }, "cfg": { "weight": 2 } },
+                { "member_id": "b", "sys": { "hostname": "web-1" }, "cfg": {} }
+            ],
+            "empty": []
+        });
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("eachSortedBy", Box::new(EACH_SORTED_BY));
+        handlebars.template_render(template, &json).unwrap()
+    }
+
+    #[test]
+    fn test_each_sorted_by_helper() {
+        assert_eq!(
+            "web-1,web-2,web-3,",
+            render(
+                "{{#eachSortedBy members \"sys.hostname\" as |m|}}\
+                 {{m.sys.hostname}},{{/eachSortedBy}}"
+            )
+        );
+        assert_eq!(
+            "a c b ",
+            render(
+                "{{#eachSortedBy members \"cfg.weight\" as |m|}}\
+                 {{m.member_id}} {{/eachSortedBy}}"
+            )
+        );
+    }
+
+    #[test]
+    fn test_each_sorted_by_helper_ranks_numbers_before_strings() {
+        let json = json!({
+            "items": [
+                { "v": "b" }, { "v": 10 }, { "v": true }, { "v": "2" }, {},
+                { "v": "a" }, { "v": 1 }, { "v": "nan" }
+            ]
+        });
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("eachSortedBy", Box::new(EACH_SORTED_BY));
+        assert_eq!(
+            "1,2,10,a,b,nan,true,,",
+            handlebars
+                .template_render(
+                    "{{#eachSortedBy items \"v\" as |i|}}{{i.v}},{{/eachSortedBy}}",
+                    &json,
+                )
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_each_sorted_by_helper_sets_local_vars() {
+        assert_eq!(
+            "0:a:true 1:b:false 2:c:false ",
+            render(
+                "{{#eachSortedBy members \"member_id\" as |m|}}\
+                 {{@index}}:{{m.member_id}}:{{@first}} {{/eachSortedBy}}"
+            )
+        );
+    }
+
+    #[test]
+    fn test_each_sorted_by_helper_renders_else_for_empty_lists() {
+        assert_eq!(
+            "none",
+            render("{{#eachSortedBy empty \"member_id\"}}some{{else}}none{{/eachSortedBy}}")
+        );
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::env;
+
+use handlebars::{Handlebars, Helper, HelperDef, RenderContext, RenderError};
+
+use super::super::RenderResult;
+use super::json_to_string;
+
+#[derive(Clone, Copy)]
+pub struct EnvVarHelper;
+
+impl HelperDef for EnvVarHelper {
+    fn call(&self, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult<()> {
+        let name = h.param(0).and_then(|v| v.value().as_str()).ok_or_else(|| {
+            RenderError::new("Expected a variable name parameter for \"envVar\"")
+        })?;
+        let value = match env::var(name) {
+            Ok(value) => value,
+            Err(_) => h.param(1).map(|v| json_to_string(v.value())).unwrap_or_default(),
+        };
+        rc.writer.write(value.into_bytes().as_ref())?;
+        Ok(())
+    }
+}
+
+pub static ENV_VAR: EnvVarHelper = EnvVarHelper;
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_env_var_helper() {
+        env::set_var("HAB_TEST_ENV_VAR_HELPER", "from-env");
+        let json = json!({});
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("envVar", Box::new(ENV_VAR));
+        assert_eq!(
+            "from-env",
+            handlebars
+                .template_render("{{envVar \"HAB_TEST_ENV_VAR_HELPER\" \"fallback\"}}", &json)
+                .unwrap()
+        );
+        assert_eq!(
+            "fallback",
+            handlebars
+                .template_render("{{envVar \"HAB_TEST_ENV_VAR_HELPER_UNSET\" \"fallback\"}}", &json)
+                .unwrap()
+        );
+        assert_eq!(
+            "",
+            handlebars
+                .template_render("{{envVar \"HAB_TEST_ENV_VAR_HELPER_UNSET\"}}", &json)
+                .unwrap()
+        );
+    }
+}
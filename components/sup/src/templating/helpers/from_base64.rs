@@ -0,0 +1,71 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64;
+use handlebars::{Handlebars, Helper, HelperDef, RenderContext, RenderError};
+
+use super::super::RenderResult;
+
+#[derive(Clone, Copy)]
+pub struct FromBase64Helper;
+
+impl HelperDef for FromBase64Helper {
+    fn call(&self, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult<()> {
+        let param = h.param(0).and_then(|v| v.value().as_str()).ok_or_else(|| {
+            RenderError::new("Expected a string parameter for \"fromBase64\"")
+        })?;
+        let bytes = base64::decode(param.trim()).map_err(|e| {
+            RenderError::new(format!("Can't decode base64 parameter: {}", e))
+        })?;
+        let decoded = String::from_utf8(bytes).map_err(|e| {
+            RenderError::new(format!("Decoded base64 parameter isn't UTF-8: {}", e))
+        })?;
+        rc.writer.write(decoded.into_bytes().as_ref())?;
+        Ok(())
+    }
+}
+
+pub static FROM_BASE64: FromBase64Helper = FromBase64Helper;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_base64_helper() {
+        let json = json!({
+            "password": "aHVudGVyMjpzZWNyZXQ="
+        });
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("fromBase64", Box::new(FROM_BASE64));
+        assert_eq!(
+            "hunter2:secret",
+            handlebars.template_render("{{fromBase64 password}}", &json).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_base64_helper_errors_on_invalid_input() {
+        let json = json!({
+            "password": "not base64!"
+        });
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("fromBase64", Box::new(FROM_BASE64));
+        assert!(
+            handlebars
+                .template_render("{{fromBase64 password}}", &json)
+                .is_err()
+        );
+    }
+}
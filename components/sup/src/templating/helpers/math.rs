@@ -0,0 +1,181 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use handlebars::{Handlebars, Helper, HelperDef, RenderContext, RenderError};
+use serde_json::Value as Json;
+
+use super::super::RenderResult;
+use super::json_to_f64;
+
+#[derive(Clone, Copy)]
+enum MathOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+}
+
+/// A number given to an arithmetic helper. Integers stay integers unless they are combined with
+/// a float.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Number {
+    Int(i64),
+    Float(f64),
+}
+
+impl Number {
+    fn from_json(value: &Json) -> Option<Number> {
+        match *value {
+            Json::Number(ref n) if n.is_i64() => n.as_i64().map(Number::Int),
+            Json::String(ref s) if s.trim().parse::<i64>().is_ok() => {
+                s.trim().parse::<i64>().ok().map(Number::Int)
+            }
+            _ => json_to_f64(value).map(Number::Float),
+        }
+    }
+
+    fn as_f64(&self) -> f64 {
+        match *self {
+            Number::Int(i) => i as f64,
+            Number::Float(f) => f,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct MathHelper(MathOp);
+
+impl MathHelper {
+    fn name(&self) -> &'static str {
+        match self.0 {
+            MathOp::Add => "add",
+            MathOp::Sub => "sub",
+            MathOp::Mul => "mul",
+            MathOp::Div => "div",
+            MathOp::Mod => "mod",
+        }
+    }
+
+    fn apply(&self, lhs: Number, rhs: Number) -> RenderResult<Number> {
+        match (self.0, lhs, rhs) {
+            (MathOp::Div, _, rhs) |
+            (MathOp::Mod, _, rhs) if rhs.as_f64() == 0.0 => {
+                Err(RenderError::new(
+                    format!("Division by zero in \"{}\"", self.name()),
+                ))
+            }
+            (op, Number::Int(lhs), Number::Int(rhs)) => {
+                let result = match op {
+                    MathOp::Add => lhs.checked_add(rhs),
+                    MathOp::Sub => lhs.checked_sub(rhs),
+                    MathOp::Mul => lhs.checked_mul(rhs),
+                    MathOp::Div => lhs.checked_div(rhs),
+                    MathOp::Mod => lhs.checked_rem(rhs),
+                };
+                result.map(Number::Int).ok_or_else(|| {
+                    RenderError::new(format!("Integer overflow in \"{}\"", self.name()))
+                })
+            }
+            (op, lhs, rhs) => {
+                let (lhs, rhs) = (lhs.as_f64(), rhs.as_f64());
+                let result = match op {
+                    MathOp::Add => lhs + rhs,
+                    MathOp::Sub => lhs - rhs,
+                    MathOp::Mul => lhs * rhs,
+                    MathOp::Div => lhs / rhs,
+                    MathOp::Mod => lhs % rhs,
+                };
+                Ok(Number::Float(result))
+            }
+        }
+    }
+}
+
+impl HelperDef for MathHelper {
+    fn call(&self, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult<()> {
+        let lhs = h.param(0).and_then(|v| Number::from_json(v.value()));
+        let rhs = h.param(1).and_then(|v| Number::from_json(v.value()));
+        let (lhs, rhs) = match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => (lhs, rhs),
+            _ => {
+                return Err(RenderError::new(format!(
+                    "Expected 2 number parameters for \"{}\"",
+                    self.name()
+                )))
+            }
+        };
+        let result = match self.apply(lhs, rhs)? {
+            Number::Int(i) => i.to_string(),
+            Number::Float(f) => f.to_string(),
+        };
+        rc.writer.write(result.into_bytes().as_ref())?;
+        Ok(())
+    }
+}
+
+pub static ADD: MathHelper = MathHelper(MathOp::Add);
+pub static SUB: MathHelper = MathHelper(MathOp::Sub);
+pub static MUL: MathHelper = MathHelper(MathOp::Mul);
+pub static DIV: MathHelper = MathHelper(MathOp::Div);
+pub static MOD: MathHelper = MathHelper(MathOp::Mod);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn render(template: &str) -> RenderResult<String> {
+        let json = json!({
+            "cfg": {
+                "workers": 4,
+                "ratio": 1.5,
+                "memory": "1024"
+            }
+        });
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("add", Box::new(ADD));
+        handlebars.register_helper("sub", Box::new(SUB));
+        handlebars.register_helper("mul", Box::new(MUL));
+        handlebars.register_helper("div", Box::new(DIV));
+        handlebars.register_helper("mod", Box::new(MOD));
+        handlebars.template_render(template, &json)
+    }
+
+    #[test]
+    fn test_math_helpers_with_integers() {
+        assert_eq!("5", render("{{add cfg.workers 1}}").unwrap());
+        assert_eq!("3", render("{{sub cfg.workers 1}}").unwrap());
+        assert_eq!("4096", render("{{mul cfg.workers cfg.memory}}").unwrap());
+        assert_eq!("256", render("{{div cfg.memory cfg.workers}}").unwrap());
+        assert_eq!("1", render("{{mod 9 cfg.workers}}").unwrap());
+    }
+
+    #[test]
+    fn test_math_helpers_with_floats() {
+        assert_eq!("6", render("{{mul cfg.workers cfg.ratio}}").unwrap());
+        assert_eq!("2.5", render("{{add cfg.ratio 1}}").unwrap());
+    }
+
+    #[test]
+    fn test_math_helpers_nest() {
+        assert_eq!("10", render("{{mul (add cfg.workers 1) 2}}").unwrap());
+    }
+
+    #[test]
+    fn test_math_helpers_errors() {
+        assert!(render("{{div cfg.workers 0}}").is_err());
+        assert!(render("{{add cfg.workers \"lots\"}}").is_err());
+        assert!(render("{{add cfg.workers}}").is_err());
+    }
+}
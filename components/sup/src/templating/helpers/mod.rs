@@ -12,26 +12,43 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod compare;
+mod default;
 mod each_alive;
+mod each_sorted_by;
+mod env_var;
+mod from_base64;
+mod math;
 mod pkg_path_for;
+mod sha256;
 mod str_concat;
 mod str_join;
 mod str_replace;
+mod to_base64;
 mod to_json;
 mod to_lowercase;
 mod to_toml;
 mod to_uppercase;
 mod to_yaml;
 
+use std::cmp::Ordering;
 
 use serde::Serialize;
 use serde_json::{self, Value as Json};
 
+pub use self::compare::{EQ, GE, GT, LE, LT, NE};
+pub use self::default::DEFAULT;
 pub use self::each_alive::EACH_ALIVE;
+pub use self::each_sorted_by::EACH_SORTED_BY;
+pub use self::env_var::ENV_VAR;
+pub use self::from_base64::FROM_BASE64;
+pub use self::math::{ADD, DIV, MOD, MUL, SUB};
 pub use self::pkg_path_for::PKG_PATH_FOR;
+pub use self::sha256::SHA256;
 pub use self::str_concat::STR_CONCAT;
 pub use self::str_join::STR_JOIN;
 pub use self::str_replace::STR_REPLACE;
+pub use self::to_base64::TO_BASE64;
 pub use self::to_json::TO_JSON;
 pub use self::to_lowercase::TO_LOWERCASE;
 pub use self::to_toml::TO_TOML;
@@ -64,3 +81,37 @@ where
 {
     serde_json::to_value(src).unwrap_or(Json::Null)
 }
+
+/// Helper which renders a JSON value the way Handlebars renders a value in a template: strings
+/// without quotes, `null` as nothing and everything else as JSON
+fn json_to_string(value: &Json) -> String {
+    match *value {
+        Json::String(ref s) => s.clone(),
+        Json::Null => String::new(),
+        ref other => other.to_string(),
+    }
+}
+
+/// Helper which returns the number held by a JSON value, including a number written as a string
+/// such as the output of another helper
+fn json_to_f64(value: &Json) -> Option<f64> {
+    match *value {
+        Json::Number(ref n) => n.as_f64(),
+        Json::String(ref s) => s.trim().parse::<f64>().ok(),
+        _ => None,
+    }
+}
+
+/// Helper which orders two JSON values, comparing numbers numerically and strings
+/// lexicographically. Returns `None` for values which can't be ordered against each other.
+fn compare_json(a: &Json, b: &Json) -> Option<Ordering> {
+    if let (Some(a), Some(b)) = (json_to_f64(a), json_to_f64(b)) {
+        return a.partial_cmp(&b);
+    }
+    match (a, b) {
+        (&Json::String(ref a), &Json::String(ref b)) => Some(a.cmp(b)),
+        (&Json::Bool(ref a), &Json::Bool(ref b)) => Some(a.cmp(b)),
+        (a, b) if a == b => Some(Ordering::Equal),
+        _ => None,
+    }
+}
@@ -45,6 +45,23 @@ impl TemplateRenderer {
         handlebars.register_helper("toJson", Box::new(helpers::TO_JSON));
         handlebars.register_helper("toToml", Box::new(helpers::TO_TOML));
         handlebars.register_helper("toYaml", Box::new(helpers::TO_YAML));
+        handlebars.register_helper("toBase64", Box::new(helpers::TO_BASE64));
+        handlebars.register_helper("fromBase64", Box::new(helpers::FROM_BASE64));
+        handlebars.register_helper("sha256", Box::new(helpers::SHA256));
+        handlebars.register_helper("add", Box::new(helpers::ADD));
+        handlebars.register_helper("sub", Box::new(helpers::SUB));
+        handlebars.register_helper("mul", Box::new(helpers::MUL));
+        handlebars.register_helper("div", Box::new(helpers::DIV));
+        handlebars.register_helper("mod", Box::new(helpers::MOD));
+        handlebars.register_helper("eq", Box::new(helpers::EQ));
+        handlebars.register_helper("ne", Box::new(helpers::NE));
+        handlebars.register_helper("lt", Box::new(helpers::LT));
+        handlebars.register_helper("le", Box::new(helpers::LE));
+        handlebars.register_helper("gt", Box::new(helpers::GT));
+        handlebars.register_helper("ge", Box::new(helpers::GE));
+        handlebars.register_helper("default", Box::new(helpers::DEFAULT));
+        handlebars.register_helper("eachSortedBy", Box::new(helpers::EACH_SORTED_BY));
+        handlebars.register_helper("envVar", Box::new(helpers::ENV_VAR));
 
         handlebars.register_escape_fn(never_escape);
         TemplateRenderer(handlebars)
@@ -57,7 +74,7 @@ impl TemplateRenderer {
         let raw = serde_json::to_value(ctx).map_err(|e| {
             sup_error!(Error::RenderContextSerialization(e))
         })?;
-        debug!("Rendering template with context, {}, {}", template, raw);
+        debug!("Rendering template, {}", template);
         self.0.render(template, &raw).map_err(|e| {
             sup_error!(Error::TemplateRenderError(e))
         })
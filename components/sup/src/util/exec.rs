@@ -12,4 +12,98 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::result;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
 pub use sys::exec::*;
+
+/// How often `Watchdog::wait` checks whether the child has exited.
+const WAIT_INTERVAL_MS: u64 = 50;
+
+/// Kills the process tree of a child started by `run` or `run_command` which has not finished
+/// before its timeout.
+///
+/// The child must only be reaped through `wait`. A reaped child's pid may be reused, so once the
+/// child is being waited for, it is killed by `wait` itself and only if it hasn't exited.
+pub struct Watchdog {
+    pid: u32,
+    done: Sender<()>,
+    handle: JoinHandle<()>,
+    watch: Arc<Mutex<Watch>>,
+}
+
+#[derive(Default)]
+struct Watch {
+    waiting: bool,
+    expired: bool,
+    killed: bool,
+}
+
+impl Watchdog {
+    pub fn start(pid: u32, timeout: Duration) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let watch = Arc::new(Mutex::new(Watch::default()));
+        let timer_watch = watch.clone();
+        let handle = thread::spawn(move || {
+            if let Err(RecvTimeoutError::Timeout) = rx.recv_timeout(timeout) {
+                let mut watch = timer_watch.lock().expect("Watchdog lock poisoned");
+                watch.expired = true;
+                if !watch.waiting {
+                    kill(pid, &mut watch);
+                }
+            }
+        });
+        Watchdog {
+            pid: pid,
+            done: tx,
+            handle: handle,
+            watch: watch,
+        }
+    }
+
+    /// Wait for the child to exit, checking on it with `try_wait`, which must reap the child once
+    /// it has exited and return `None` until then. Returns the child's exit status and whether
+    /// it was killed for running too long; a child which exited before its timeout never is.
+    pub fn wait<F, S, E>(self, mut try_wait: F) -> (result::Result<S, E>, bool)
+    where
+        F: FnMut() -> result::Result<Option<S>, E>,
+    {
+        self.watch.lock().expect("Watchdog lock poisoned").waiting = true;
+        let status = self.reap(&mut try_wait);
+        let _ = self.done.send(());
+        let _ = self.handle.join();
+        let killed = self.watch.lock().expect("Watchdog lock poisoned").killed;
+        (status, killed)
+    }
+
+    fn reap<F, S, E>(&self, try_wait: &mut F) -> result::Result<S, E>
+    where
+        F: FnMut() -> result::Result<Option<S>, E>,
+    {
+        loop {
+            {
+                let mut watch = self.watch.lock().expect("Watchdog lock poisoned");
+                match try_wait() {
+                    Ok(Some(status)) => return Ok(status),
+                    Err(err) => return Err(err),
+                    Ok(None) => {
+                        if watch.expired && !watch.killed {
+                            kill(self.pid, &mut watch);
+                        }
+                    }
+                }
+            }
+            thread::sleep(Duration::from_millis(WAIT_INTERVAL_MS));
+        }
+    }
+}
+
+fn kill(pid: u32, watch: &mut Watch) {
+    if let Err(err) = kill_tree(pid) {
+        warn!("Unable to kill timed out process, pid {}, {}", pid, err);
+    }
+    watch.killed = true;
+}
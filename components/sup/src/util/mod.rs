@@ -16,4 +16,5 @@ pub mod convert;
 pub mod exec;
 pub mod path;
 pub mod pkg;
+pub mod tail;
 pub mod users;
@@ -17,13 +17,16 @@ use std::path::Path;
 use common;
 use common::command::package::install::InstallSource;
 use common::ui::UI;
+use depot_client::Client;
 use hcore::env as henv;
 use hcore::AUTH_TOKEN_ENVVAR;
 use hcore::fs::{self, FS_ROOT_PATH};
-use hcore::package::{PackageIdent, PackageInstall};
+use hcore::package::{Identifiable, PackageIdent, PackageInstall, VersionReq};
 
 use {PRODUCT, VERSION};
-use error::{Result, SupError};
+use error::{Error, Result, SupError};
+
+static LOGKEY: &'static str = "PK";
 
 /// Helper function for use in the Supervisor to handle lower-level
 /// arguments needed for installing a package.
@@ -57,8 +60,83 @@ pub fn install(
     ).map_err(SupError::from)
 }
 
+/// Like `install`, but when given a package identifier which isn't fully qualified, install the
+/// newest release in the channel whose version satisfies the requirement, if one is given.
+pub fn install_matching(
+    ui: &mut UI,
+    url: &str,
+    install_source: &InstallSource,
+    version_req: Option<&VersionReq>,
+    channel: &str,
+) -> Result<PackageInstall> {
+    match (install_source, version_req) {
+        (&InstallSource::Ident(ref ident), Some(version_req)) if !ident.fully_qualified() => {
+            let latest = latest_matching(url, ident, version_req, channel)?;
+            install(ui, url, &latest.into(), channel)
+        }
+        _ => install(ui, url, install_source, channel),
+    }
+}
+
+/// Returns the newest release of a package in a channel whose version satisfies the
+/// requirement.
+pub fn latest_matching(
+    url: &str,
+    ident: &PackageIdent,
+    version_req: &VersionReq,
+    channel: &str,
+) -> Result<PackageIdent> {
+    let fs_root_path = Path::new(&*FS_ROOT_PATH);
+    let auth_token = henv::var(AUTH_TOKEN_ENVVAR).ok();
+    let client = Client::new(url, PRODUCT, VERSION, Some(fs_root_path))?;
+    let mut latest: Option<PackageIdent> = None;
+    let mut range = 0;
+    loop {
+        let page = client.list_channel_packages(
+            ident,
+            channel,
+            range,
+            auth_token.as_ref().map(String::as_str),
+        )?;
+        for candidate in page.data {
+            if !version_req.matches_ident(&candidate) {
+                continue;
+            }
+            if latest.as_ref().map_or(true, |latest| *latest < candidate) {
+                latest = Some(candidate);
+            }
+        }
+        if page.range_end + 1 >= page.total_count || page.range_end < range {
+            break;
+        }
+        range = page.range_end + 1;
+    }
+    latest.ok_or_else(|| {
+        sup_error!(Error::NoMatchingRelease(
+            ident.clone(),
+            version_req.clone(),
+            channel.to_string(),
+        ))
+    })
+}
+
 /// Returns an installed package for the given ident, if one is present.
 pub fn installed(ident: &PackageIdent) -> Option<PackageInstall> {
     let fs_root_path = Path::new(&*FS_ROOT_PATH);
     PackageInstall::load(ident, Some(fs_root_path)).ok()
 }
+
+/// Like `installed`, but returns the newest installed package whose version satisfies the
+/// requirement, if one is given.
+pub fn installed_matching(
+    ident: &PackageIdent,
+    version_req: Option<&VersionReq>,
+) -> Option<PackageInstall> {
+    match version_req {
+        Some(version_req) => {
+            let fs_root_path = Path::new(&*FS_ROOT_PATH);
+            PackageInstall::load_matching(ident, version_req, Some(fs_root_path)).ok()
+        }
+        None => installed(ident),
+    }
+}
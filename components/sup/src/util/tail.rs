@@ -0,0 +1,111 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::{self, Read, Seek, SeekFrom};
+
+/// How much of a file is read at a time while searching backwards for line endings.
+const CHUNK_SIZE: u64 = 8 * 1024;
+
+/// Read the last `count` lines of the given reader, without reading the whole of it.
+///
+/// Returns the bytes of those lines along with the length of the reader, which is the offset
+/// to continue reading from when following a file that is still being written to.
+pub fn last_lines<R>(reader: &mut R, count: usize) -> io::Result<(Vec<u8>, u64)>
+where
+    R: Read + Seek,
+{
+    let len = reader.seek(SeekFrom::End(0))?;
+    let start = if count == 0 {
+        len
+    } else {
+        start_of_last_lines(reader, len, count)?
+    };
+    reader.seek(SeekFrom::Start(start))?;
+    let mut buf = Vec::with_capacity((len - start) as usize);
+    reader.take(len - start).read_to_end(&mut buf)?;
+    Ok((buf, len))
+}
+
+fn start_of_last_lines<R>(reader: &mut R, len: u64, count: usize) -> io::Result<u64>
+where
+    R: Read + Seek,
+{
+    let mut chunk = vec![0; CHUNK_SIZE as usize];
+    let mut newlines = 0;
+    let mut end = len;
+    while end > 0 {
+        let start = end.saturating_sub(CHUNK_SIZE);
+        let size = (end - start) as usize;
+        reader.seek(SeekFrom::Start(start))?;
+        reader.read_exact(&mut chunk[..size])?;
+        for i in (0..size).rev() {
+            let offset = start + i as u64;
+            // A newline at the very end of the file terminates the last line rather than
+            // separating it from the next one.
+            if chunk[i] == b'\n' && offset != len - 1 {
+                newlines += 1;
+                if newlines == count {
+                    return Ok(offset + 1);
+                }
+            }
+        }
+        end = start;
+    }
+    Ok(0)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn tail(content: &str, count: usize) -> String {
+        let (lines, len) = last_lines(&mut Cursor::new(content.as_bytes()), count).unwrap();
+        assert_eq!(len, content.len() as u64);
+        String::from_utf8(lines).unwrap()
+    }
+
+    #[test]
+    fn last_lines_fewer_than_requested() {
+        assert_eq!(tail("one\ntwo\n", 10), "one\ntwo\n");
+    }
+
+    #[test]
+    fn last_lines_with_trailing_newline() {
+        assert_eq!(tail("one\ntwo\nthree\n", 2), "two\nthree\n");
+    }
+
+    #[test]
+    fn last_lines_without_trailing_newline() {
+        assert_eq!(tail("one\ntwo\nthree", 2), "two\nthree");
+    }
+
+    #[test]
+    fn last_lines_none_requested() {
+        assert_eq!(tail("one\ntwo\n", 0), "");
+    }
+
+    #[test]
+    fn last_lines_empty() {
+        assert_eq!(tail("", 5), "");
+    }
+
+    #[test]
+    fn last_lines_across_chunks() {
+        let line = format!("{}\n", "x".repeat(1000));
+        let content = line.repeat(20);
+        assert_eq!(tail(&content, 12), line.repeat(12));
+    }
+}